@@ -0,0 +1,26 @@
+//! Builds and writes a coordinate-sorted index (CSI) from a BAM file.
+//!
+//! The input BAM must be coordinate-sorted, i.e., `SO:coordinate`.
+//!
+//! This writes the output to stdout rather than `<src>.csi`.
+//!
+//! The output is similar to the output of `samtools index -c <src>`.
+
+use std::{env, io};
+
+use noodles_bam as bam;
+use noodles_csi as csi;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let index = bam::index_csi(src)?;
+
+    let stdout = io::stdout();
+    let handle = stdout.lock();
+    let mut writer = csi::Writer::new(handle);
+
+    writer.write_index(&index)?;
+
+    Ok(())
+}
@@ -0,0 +1,321 @@
+//! Indexed BAM writer.
+
+use std::io::{self, Write};
+
+use noodles_bgzf as bgzf;
+use noodles_csi::{self as csi, index::reference_sequence::bin::Chunk};
+use noodles_sam::{self as sam, alignment::Record, header::ReferenceSequences};
+
+use super::{
+    bai,
+    indexer::{add_record, build_indexer},
+    Writer,
+};
+
+// The max position a BAM index (BAI) can address, i.e., 2^29 - 1.
+const BAI_MAX_POSITION: usize = (1 << 29) - 1;
+
+/// An index built by an indexed BAM writer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Index {
+    /// A BAM index (BAI).
+    Bai(bai::Index),
+    /// A coordinate-sorted index (CSI).
+    Csi(csi::Index),
+}
+
+enum Indexer {
+    Bai(bai::index::Builder),
+    Csi(csi::index::Indexer),
+}
+
+/// A BAM writer that builds an index from the records it writes.
+///
+/// The index is a BAM index (BAI) unless a reference sequence is longer than what a BAI can
+/// address (2^29 - 1 bases), in which case a coordinate-sorted index (CSI) is built instead.
+///
+/// Records must be written in coordinate-sorted order. This is similar to
+/// `samtools sort --write-index`.
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::Writer<W>>,
+    indexer: Indexer,
+    reference_sequence_count: usize,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed BAM writer with a default compression level.
+    ///
+    /// The given stream is wrapped in a BGZF encoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let writer = bam::IndexedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self::from(Writer::new(inner))
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let writer = bam::IndexedWriter::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        self.inner.get_ref().get_ref()
+    }
+
+    /// Writes a SAM header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = bam::IndexedWriter::new(Vec::new());
+    ///
+    /// let header = sam::Header::builder().add_comment("noodles-bam").build();
+    /// writer.write_header(&header)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        self.inner.write_header(header)
+    }
+
+    /// Writes SAM reference sequences.
+    ///
+    /// The number of reference sequences determines the number of reference sequences in the
+    /// built index. If any reference sequence is longer than 2^29 - 1 bases, the writer builds a
+    /// coordinate-sorted index (CSI).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = bam::IndexedWriter::new(Vec::new());
+    ///
+    /// let header = sam::Header::default();
+    /// writer.write_header(&header)?;
+    /// writer.write_reference_sequences(header.reference_sequences())?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_reference_sequences(
+        &mut self,
+        reference_sequences: &ReferenceSequences,
+    ) -> io::Result<()> {
+        self.inner.write_reference_sequences(reference_sequences)?;
+
+        let needs_csi = reference_sequences
+            .values()
+            .any(|reference_sequence| reference_sequence.len().get() > BAI_MAX_POSITION);
+
+        if needs_csi {
+            self.indexer = Indexer::Csi(build_indexer(reference_sequences));
+        }
+
+        self.reference_sequence_count = reference_sequences.len();
+
+        Ok(())
+    }
+
+    /// Writes a BAM record and adds it to the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// use noodles_sam::{self as sam, alignment::Record};
+    ///
+    /// let mut writer = bam::IndexedWriter::new(Vec::new());
+    ///
+    /// let header = sam::Header::default();
+    /// writer.write_header(&header)?;
+    /// writer.write_reference_sequences(header.reference_sequences())?;
+    ///
+    /// let record = Record::default();
+    /// writer.write_record(&header, &record)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_record(&mut self, header: &sam::Header, record: &Record) -> io::Result<()> {
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_record(header, record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let chunk = Chunk::new(start_position, end_position);
+
+        match &mut self.indexer {
+            Indexer::Bai(indexer) => indexer.add_record(record, chunk),
+            Indexer::Csi(indexer) => add_record(indexer, record, chunk),
+        }
+    }
+
+    /// Finishes the output stream and returns the underlying writer and the built index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = bam::IndexedWriter::new(Vec::new());
+    ///
+    /// let header = sam::Header::default();
+    /// writer.write_header(&header)?;
+    /// writer.write_reference_sequences(header.reference_sequences())?;
+    ///
+    /// let (_, index) = writer.finish()?;
+    /// assert!(matches!(index, bam::indexed_writer::Index::Bai(_)));
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn finish(self) -> io::Result<(W, Index)> {
+        let inner = self.inner.into_inner().finish()?;
+
+        let index = match self.indexer {
+            Indexer::Bai(indexer) => Index::Bai(indexer.build(self.reference_sequence_count)),
+            Indexer::Csi(indexer) => Index::Csi(indexer.build(self.reference_sequence_count)),
+        };
+
+        Ok((inner, index))
+    }
+}
+
+impl<W> From<Writer<bgzf::Writer<W>>> for IndexedWriter<W>
+where
+    W: Write,
+{
+    fn from(inner: Writer<bgzf::Writer<W>>) -> Self {
+        Self {
+            inner,
+            indexer: Indexer::Bai(bai::Index::builder()),
+            reference_sequence_count: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_csi::BinningIndex;
+    use noodles_sam::header::{reference_sequence, ReferenceSequence};
+
+    use super::*;
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new(
+                "sq0".parse::<reference_sequence::Name>()?,
+                8,
+            )?)
+            .add_reference_sequence(ReferenceSequence::new(
+                "sq1".parse::<reference_sequence::Name>()?,
+                13,
+            )?)
+            .build();
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        let record = Record::builder()
+            .set_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(2)?)
+            .set_cigar("4M".parse()?)
+            .build();
+
+        writer.write_record(&header, &record)?;
+        writer.write_record(&header, &Record::default())?;
+
+        let (buf, index) = match writer.finish()? {
+            (buf, Index::Bai(index)) => (buf, index),
+            (_, Index::Csi(_)) => panic!("expected BAI"),
+        };
+
+        let reference_sequences = index.reference_sequences();
+        assert_eq!(reference_sequences.len(), 2);
+        assert!(!reference_sequences[0].bins().is_empty());
+        assert!(reference_sequences[1].bins().is_empty());
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(1));
+
+        let mut reader = crate::Reader::new(&buf[..]);
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let start_position = reader.virtual_position();
+        let mut actual = Record::default();
+        reader.read_record(&mut actual)?;
+        let end_position = reader.virtual_position();
+
+        assert_eq!(actual, record);
+
+        let chunks = index.query(0, ..)?;
+        assert_eq!(chunks, [Chunk::new(start_position, end_position)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_long_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new(
+                "sq0".parse::<reference_sequence::Name>()?,
+                1 << 30,
+            )?)
+            .build();
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        let record = Record::builder()
+            .set_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from((1 << 29) + 8)?)
+            .set_cigar("4M".parse()?)
+            .build();
+
+        writer.write_record(&header, &record)?;
+
+        let (buf, index) = match writer.finish()? {
+            (buf, Index::Csi(index)) => (buf, index),
+            (_, Index::Bai(_)) => panic!("expected CSI"),
+        };
+
+        assert_eq!(index.depth(), 6);
+
+        let mut reader = crate::Reader::new(&buf[..]);
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let start_position = reader.virtual_position();
+        let mut actual = Record::default();
+        reader.read_record(&mut actual)?;
+
+        assert_eq!(actual, record);
+
+        let chunks = index.query(0, ..)?;
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].start(), start_position);
+
+        Ok(())
+    }
+}
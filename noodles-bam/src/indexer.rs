@@ -0,0 +1,145 @@
+use std::{cmp, fs::File, io, path::Path};
+
+use noodles_csi::{
+    self as csi,
+    index::{indexer::AlignmentContext, reference_sequence::bin::Chunk, Indexer},
+};
+use noodles_sam::{alignment::Record, header::ReferenceSequences};
+
+use super::Reader;
+
+const MIN_SHIFT: u8 = 14;
+
+/// Indexes a coordinate-sorted BAM file using a coordinate-sorted index (CSI).
+///
+/// Unlike a BAM index (BAI), a CSI can index reference sequences longer than 2^29 - 1 bases. The
+/// depth of the index is increased when a reference sequence is longer than what the default
+/// depth can address.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_bam as bam;
+/// let index = bam::index_csi("sample.bam")?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn index_csi<P>(src: P) -> io::Result<csi::Index>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src).map(Reader::new)?;
+    reader.read_header()?;
+    let reference_sequences = reader.read_reference_sequences()?;
+
+    let mut indexer = build_indexer(&reference_sequences);
+
+    let mut record = Record::default();
+    let mut start_position = reader.virtual_position();
+
+    while reader.read_record(&mut record)? != 0 {
+        let end_position = reader.virtual_position();
+        let chunk = Chunk::new(start_position, end_position);
+
+        add_record(&mut indexer, &record, chunk)?;
+
+        start_position = end_position;
+    }
+
+    Ok(indexer.build(reference_sequences.len()))
+}
+
+pub(crate) fn build_indexer(reference_sequences: &ReferenceSequences) -> Indexer {
+    let max_reference_sequence_length = reference_sequences
+        .values()
+        .map(|reference_sequence| reference_sequence.len().get())
+        .max()
+        .unwrap_or_default();
+
+    Indexer::with_max_reference_sequence_length(MIN_SHIFT, max_reference_sequence_length)
+}
+
+pub(crate) fn add_record(indexer: &mut Indexer, record: &Record, chunk: Chunk) -> io::Result<()> {
+    let alignment_context = match (record.reference_sequence_id(), record.alignment_start()) {
+        (Some(id), Some(start)) => {
+            let end = record
+                .alignment_end()
+                .map(|end| cmp::max(start, end))
+                .unwrap_or(start);
+
+            let is_mapped = !record.flags().is_unmapped();
+
+            Some(AlignmentContext::new(id, start, end, is_mapped))
+        }
+        _ => None,
+    };
+
+    indexer.add_record(alignment_context, chunk)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use noodles_core::Position;
+    use noodles_csi::BinningIndex;
+    use noodles_sam::{
+        self as sam,
+        header::{reference_sequence, ReferenceSequence},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_index_csi() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new(
+                "sq0".parse::<reference_sequence::Name>()?,
+                1 << 30,
+            )?)
+            .build();
+
+        let record = Record::builder()
+            .set_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from((1 << 29) + 8)?)
+            .set_cigar("4M".parse()?)
+            .build();
+
+        let src = env::temp_dir().join(format!(
+            "noodles-bam-test_index_csi-{}.bam",
+            std::process::id()
+        ));
+
+        let mut writer = File::create(&src).map(crate::Writer::new)?;
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+        writer.write_record(&header, &record)?;
+        writer.try_finish()?;
+        drop(writer);
+
+        let result = index_csi(&src);
+        let data = fs::read(&src);
+        fs::remove_file(&src)?;
+        let (index, data) = (result?, data?);
+
+        assert_eq!(index.depth(), 6);
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+        let start_position = reader.virtual_position();
+
+        let start = Position::try_from(1 << 29)?;
+        let end = Position::try_from((1 << 29) + 16)?;
+        let chunks = index.query(0, start..=end)?;
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].start(), start_position);
+
+        let start = Position::try_from(1)?;
+        let end = Position::try_from(1 << 20)?;
+        assert!(index.query(0, start..=end)?.is_empty());
+
+        Ok(())
+    }
+}
@@ -53,12 +53,14 @@
 mod r#async;
 
 pub mod bai;
+pub mod indexed_writer;
+mod indexer;
 pub mod lazy;
 pub mod reader;
 pub mod record;
 pub mod writer;
 
-pub use self::{reader::Reader, writer::Writer};
+pub use self::{indexed_writer::IndexedWriter, indexer::index_csi, reader::Reader, writer::Writer};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
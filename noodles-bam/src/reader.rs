@@ -431,6 +431,54 @@ where
     }
 }
 
+impl<R> Reader<bgzf::MultithreadedReader<R>>
+where
+    R: Read,
+{
+    /// Returns the current virtual position of the underlying multithreaded BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_bam as bam;
+    ///
+    /// let data = Vec::new();
+    /// let reader = bam::Reader::from(bgzf::MultithreadedReader::new(&data[..]));
+    /// assert_eq!(reader.virtual_position(), bgzf::VirtualPosition::default());
+    /// ```
+    pub fn virtual_position(&self) -> bgzf::VirtualPosition {
+        self.inner.virtual_position()
+    }
+}
+
+impl<R> Reader<bgzf::MultithreadedReader<R>>
+where
+    R: Read + Seek,
+{
+    /// Seeks the underlying multithreaded BGZF reader to the given virtual position.
+    ///
+    /// Virtual positions typically come from the associated BAM index file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_bam as bam;
+    ///
+    /// let data = Cursor::new(Vec::new());
+    /// let mut reader = bam::Reader::from(bgzf::MultithreadedReader::new(data));
+    ///
+    /// let virtual_position = bgzf::VirtualPosition::default();
+    /// reader.seek(virtual_position)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn seek(&mut self, pos: bgzf::VirtualPosition) -> io::Result<bgzf::VirtualPosition> {
+        self.inner.seek(pos)
+    }
+}
+
 impl<R> From<R> for Reader<R> {
     fn from(inner: R) -> Self {
         Self {
@@ -199,6 +199,29 @@ where
     }
 }
 
+impl<W> Writer<bgzf::MultithreadedWriter<W>>
+where
+    W: Write,
+{
+    /// Attempts to finish the output stream.
+    ///
+    /// This waits for all pending blocks to be compressed and written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// use noodles_bgzf as bgzf;
+    /// let mut writer = bam::Writer::from(bgzf::MultithreadedWriter::new(Vec::new()));
+    /// writer.try_finish()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn try_finish(&mut self) -> io::Result<()> {
+        self.inner.try_finish()
+    }
+}
+
 impl<W> From<W> for Writer<W> {
     fn from(inner: W) -> Self {
         Self {
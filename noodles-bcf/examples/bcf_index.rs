@@ -0,0 +1,24 @@
+//! Builds and writes a coordinate-sorted index (CSI) from a BCF file.
+//!
+//! This writes the output to stdout rather than `<src>.csi`.
+//!
+//! The output is similar to the output of `bcftools index <src>`.
+
+use std::{env, io};
+
+use noodles_bcf as bcf;
+use noodles_csi as csi;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let index = bcf::index(src)?;
+
+    let stdout = io::stdout();
+    let handle = stdout.lock();
+    let mut writer = csi::Writer::new(handle);
+
+    writer.write_index(&index)?;
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! Creates a new BCF file.
+//!
+//! This writes a BCF file format, VCF header, and a single VCF record to stdout.
+//!
+//! Verify the output by piping to `bcftools view --no-version`.
+
+use noodles_bcf::{self as bcf, header::StringMaps};
+use noodles_vcf::{
+    self as vcf,
+    header::record::value::{
+        map::{Contig, Filter},
+        Map,
+    },
+    record::Position,
+};
+use tokio::io;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut writer = bcf::AsyncWriter::new(io::stdout());
+    writer.write_file_format().await?;
+
+    let header = vcf::Header::builder()
+        .add_filter(Map::<Filter>::pass())
+        .add_contig(Map::<Contig>::new("sq0".parse()?))
+        .build();
+
+    writer.write_header(&header).await?;
+
+    let string_maps = StringMaps::from(&header);
+
+    let record = vcf::Record::builder()
+        .set_chromosome("sq0".parse()?)
+        .set_position(Position::from(1))
+        .set_reference_bases("A".parse()?)
+        .build()?;
+
+    writer
+        .write_vcf_record(&header, &string_maps, &record)
+        .await?;
+
+    writer.shutdown().await?;
+
+    Ok(())
+}
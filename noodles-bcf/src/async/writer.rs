@@ -0,0 +1,281 @@
+mod builder;
+
+pub use self::builder::Builder;
+
+use noodles_bgzf as bgzf;
+use noodles_vcf as vcf;
+use tokio::io::{self, AsyncWrite, AsyncWriteExt};
+
+use crate::{header::StringMaps, Record};
+
+/// An async BCF writer.
+pub struct Writer<W> {
+    inner: W,
+    buf: Vec<u8>,
+}
+
+impl<W> Writer<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let writer = bcf::AsyncWriter::from(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let mut writer = bcf::AsyncWriter::from(Vec::new());
+    /// assert!(writer.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let writer = bcf::AsyncWriter::from(Vec::new());
+    /// assert!(writer.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Shuts down the output stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bcf as bcf;
+    /// let mut writer = bcf::AsyncWriter::new(Vec::new());
+    /// writer.shutdown().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(&mut self) -> io::Result<()> {
+        self.inner.shutdown().await
+    }
+
+    /// Writes a BCF file format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bcf as bcf;
+    /// let mut writer = bcf::AsyncWriter::new(Vec::new());
+    /// writer.write_file_format().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_file_format(&mut self) -> io::Result<()> {
+        use crate::writer::write_file_format;
+
+        self.buf.clear();
+        write_file_format(&mut self.buf)?;
+        self.inner.write_all(&self.buf).await
+    }
+
+    /// Writes a VCF header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = bcf::AsyncWriter::new(Vec::new());
+    ///
+    /// let header = vcf::Header::default();
+    /// writer.write_header(&header).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_header(&mut self, header: &vcf::Header) -> io::Result<()> {
+        use crate::writer::write_header;
+
+        self.buf.clear();
+        write_header(&mut self.buf, header)?;
+        self.inner.write_all(&self.buf).await
+    }
+
+    /// Writes a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bcf as bcf;
+    /// let mut writer = bcf::AsyncWriter::new(Vec::new());
+    /// let record = bcf::Record::default();
+    /// writer.write_record(&record).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        use crate::writer::record::write_record;
+
+        self.buf.clear();
+        write_record(&mut self.buf, record)?;
+        self.inner.write_all(&self.buf).await
+    }
+
+    /// Writes a VCF record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use noodles_bcf::{self as bcf, header::StringMaps};
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map::Contig, Map},
+    ///     record::Position,
+    /// };
+    ///
+    /// let mut writer = bcf::AsyncWriter::new(Vec::new());
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_contig(Map::<Contig>::new("sq0".parse()?))
+    ///     .build();
+    ///
+    /// writer.write_header(&header).await?;
+    ///
+    /// let string_maps = StringMaps::from(&header);
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(8))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// writer.write_vcf_record(&header, &string_maps, &record).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_vcf_record(
+        &mut self,
+        header: &vcf::Header,
+        string_maps: &StringMaps,
+        record: &vcf::Record,
+    ) -> io::Result<()> {
+        use crate::writer::vcf_record::write_vcf_record;
+
+        self.buf.clear();
+        write_vcf_record(&mut self.buf, header, string_maps, record)?;
+        self.inner.write_all(&self.buf).await
+    }
+}
+
+impl<W> Writer<bgzf::AsyncWriter<W>>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Creates an async BCF writer builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let builder = bcf::AsyncWriter::builder(Vec::new());
+    /// let writer = builder.build();
+    /// ```
+    pub fn builder(inner: W) -> Builder<W> {
+        Builder::new(inner)
+    }
+
+    /// Creates an async BCF writer with a default compression level.
+    ///
+    /// The given stream is wrapped in a BGZF encoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let writer = bcf::AsyncWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self::from(bgzf::AsyncWriter::new(inner))
+    }
+}
+
+impl<W> From<W> for Writer<W> {
+    fn from(inner: W) -> Self {
+        Self {
+            inner,
+            buf: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_vcf_record() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::{
+            header::record::value::{map::Contig, Map},
+            record::Position,
+        };
+
+        let header = vcf::Header::builder()
+            .add_contig(Map::<Contig>::new("sq0".parse()?))
+            .build();
+
+        let string_maps = StringMaps::from(&header);
+
+        let record = vcf::Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(8))
+            .set_reference_bases("A".parse()?)
+            .build()?;
+
+        let mut writer = Writer::from(Vec::new());
+        writer.write_file_format().await?;
+        writer.write_header(&header).await?;
+        writer
+            .write_vcf_record(&header, &string_maps, &record)
+            .await?;
+
+        let mut expected = crate::Writer::from(Vec::new());
+        expected.write_file_format()?;
+        expected.write_header(&header)?;
+        expected.write_vcf_record(&header, &string_maps, &record)?;
+
+        assert_eq!(writer.get_ref(), expected.get_ref());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,287 @@
+use std::io::{self, Write};
+
+use noodles_bgzf as bgzf;
+use noodles_csi::{self as csi, index::reference_sequence::bin::Chunk};
+use noodles_vcf as vcf;
+
+use super::{
+    header::StringMaps,
+    indexer::{add_record, build_indexer},
+    Record, Writer,
+};
+
+/// A BCF writer that builds a coordinate-sorted index (CSI) from the records it writes.
+///
+/// Records must be written in coordinate-sorted order. This is similar to
+/// `bcftools view --write-index`.
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::Writer<W>>,
+    indexer: csi::index::Indexer,
+    reference_sequence_count: usize,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed BCF writer with a default compression level.
+    ///
+    /// The given stream is wrapped in a BGZF encoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let writer = bcf::IndexedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self::from(Writer::new(inner))
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let writer = bcf::IndexedWriter::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        self.inner.get_ref().get_ref()
+    }
+
+    /// Writes a BCF file format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// let mut writer = bcf::IndexedWriter::new(Vec::new());
+    /// writer.write_file_format()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_file_format(&mut self) -> io::Result<()> {
+        self.inner.write_file_format()
+    }
+
+    /// Writes a VCF header.
+    ///
+    /// The contigs in the header determine the depth of the built index and its number of
+    /// reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = bcf::IndexedWriter::new(Vec::new());
+    /// writer.write_file_format()?;
+    ///
+    /// let header = vcf::Header::default();
+    /// writer.write_header(&header)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_header(&mut self, header: &vcf::Header) -> io::Result<()> {
+        self.inner.write_header(header)?;
+        self.indexer = build_indexer(header);
+        self.reference_sequence_count = header.contigs().len();
+        Ok(())
+    }
+
+    /// Writes a record and adds it to the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = bcf::IndexedWriter::new(Vec::new());
+    /// writer.write_file_format()?;
+    ///
+    /// let header = vcf::Header::default();
+    /// writer.write_header(&header)?;
+    ///
+    /// let record = bcf::Record::default();
+    /// writer.write_record(&record)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_record(record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        add_record(
+            &mut self.indexer,
+            record.chromosome_id(),
+            usize::from(record.position()),
+            usize::from(record.end()?),
+            Chunk::new(start_position, end_position),
+        )
+    }
+
+    /// Writes a VCF record and adds it to the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::{self as bcf, header::StringMaps};
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map::Contig, Map},
+    ///     record::Position,
+    /// };
+    ///
+    /// let mut writer = bcf::IndexedWriter::new(Vec::new());
+    /// writer.write_file_format()?;
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_contig(Map::<Contig>::new("sq0".parse()?))
+    ///     .build();
+    ///
+    /// writer.write_header(&header)?;
+    ///
+    /// let string_maps = StringMaps::from(&header);
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(8))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// writer.write_vcf_record(&header, &string_maps, &record)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_vcf_record(
+        &mut self,
+        header: &vcf::Header,
+        string_maps: &StringMaps,
+        record: &vcf::Record,
+    ) -> io::Result<()> {
+        use vcf::record::Chromosome;
+
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_vcf_record(header, string_maps, record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let chromosome_id = match record.chromosome() {
+            Chromosome::Name(name) => string_maps.contigs().get_index_of(name),
+            Chromosome::Symbol(_) => None,
+        }
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("chromosome not in string map: {}", record.chromosome()),
+            )
+        })?;
+
+        let end = record
+            .end()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        add_record(
+            &mut self.indexer,
+            chromosome_id,
+            usize::from(record.position()),
+            usize::from(end),
+            Chunk::new(start_position, end_position),
+        )
+    }
+
+    /// Finishes the output stream and returns the underlying writer and the built index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = bcf::IndexedWriter::new(Vec::new());
+    /// writer.write_file_format()?;
+    ///
+    /// let header = vcf::Header::default();
+    /// writer.write_header(&header)?;
+    ///
+    /// let (_, index) = writer.finish()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn finish(self) -> io::Result<(W, csi::Index)> {
+        let inner = self.inner.into_inner().finish()?;
+        Ok((inner, self.indexer.build(self.reference_sequence_count)))
+    }
+}
+
+impl<W> From<Writer<bgzf::Writer<W>>> for IndexedWriter<W>
+where
+    W: Write,
+{
+    fn from(inner: Writer<bgzf::Writer<W>>) -> Self {
+        Self {
+            inner,
+            indexer: csi::Index::indexer(),
+            reference_sequence_count: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_csi::BinningIndex;
+    use vcf::{
+        header::record::value::{map::Contig, Map},
+        record::Position,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        let header = vcf::Header::builder()
+            .add_contig(Map::<Contig>::new("sq0".parse()?))
+            .add_contig(Map::<Contig>::new("sq1".parse()?))
+            .build();
+
+        let string_maps = StringMaps::from(&header);
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        for position in [8, 13] {
+            let record = vcf::Record::builder()
+                .set_chromosome("sq1".parse()?)
+                .set_position(Position::from(position))
+                .set_reference_bases("A".parse()?)
+                .build()?;
+
+            writer.write_vcf_record(&header, &string_maps, &record)?;
+        }
+
+        let (buf, index) = writer.finish()?;
+
+        let reference_sequences = index.reference_sequences();
+        assert_eq!(reference_sequences.len(), 2);
+        assert!(reference_sequences[0].bins().is_empty());
+        assert_eq!(reference_sequences[1].bins().len(), 1);
+
+        let mut reader = crate::Reader::new(&buf[..]);
+        reader.read_file_format()?;
+        reader.read_header()?;
+        let start_position = reader.virtual_position();
+
+        let chunks = index.query(1, ..)?;
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].start(), start_position);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use std::{cmp, fs::File, io, path::Path};
+
+use noodles_core::Position;
+use noodles_csi::{
+    self as csi,
+    index::{indexer::AlignmentContext, reference_sequence::bin::Chunk},
+};
+use noodles_vcf as vcf;
+
+use super::{Reader, Record};
+
+const MIN_SHIFT: u8 = 14;
+
+/// Indexes a BCF file.
+///
+/// The depth of the index is increased when a contig is longer than what the default depth can
+/// address.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_bcf as bcf;
+/// let index = bcf::index("sample.bcf")?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn index<P>(src: P) -> io::Result<csi::Index>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src).map(Reader::new)?;
+    reader.read_file_format()?;
+
+    let header: vcf::Header = reader
+        .read_header()?
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let mut indexer = build_indexer(&header);
+
+    let mut record = Record::default();
+    let mut start_position = reader.virtual_position();
+
+    while reader.read_record(&mut record)? != 0 {
+        let end_position = reader.virtual_position();
+        let chunk = Chunk::new(start_position, end_position);
+
+        let position = usize::from(record.position());
+        let end = usize::from(record.end()?);
+        add_record(&mut indexer, record.chromosome_id(), position, end, chunk)?;
+
+        start_position = end_position;
+    }
+
+    Ok(indexer.build(header.contigs().len()))
+}
+
+pub(crate) fn build_indexer(header: &vcf::Header) -> csi::index::Indexer {
+    let max_contig_length = header
+        .contigs()
+        .values()
+        .filter_map(|contig| contig.length())
+        .max()
+        .unwrap_or_default();
+
+    csi::index::Indexer::with_max_reference_sequence_length(MIN_SHIFT, max_contig_length)
+}
+
+// The positions are 1-based and can be 0, i.e., a telomere.
+pub(crate) fn add_record(
+    indexer: &mut csi::index::Indexer,
+    chromosome_id: usize,
+    position: usize,
+    end: usize,
+    chunk: Chunk,
+) -> io::Result<()> {
+    let start = Position::new(position).unwrap_or(Position::MIN);
+    let end = Position::new(end).unwrap_or(Position::MIN);
+    let alignment_context = AlignmentContext::new(chromosome_id, start, cmp::max(start, end), true);
+    indexer.add_record(Some(alignment_context), chunk)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use noodles_csi::BinningIndex;
+    use vcf::header::record::value::{
+        map::{contig, Contig},
+        Map,
+    };
+
+    use super::*;
+    use crate::header::StringMaps;
+
+    #[test]
+    fn test_index() -> Result<(), Box<dyn std::error::Error>> {
+        let header = vcf::Header::builder()
+            .add_contig(
+                Map::<Contig>::builder()
+                    .set_id("sq0".parse::<contig::Name>()?)
+                    .set_length(1 << 30)
+                    .build()?,
+            )
+            .build();
+
+        let string_maps = StringMaps::from(&header);
+
+        let record = vcf::Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(vcf::record::Position::from((1 << 29) + 8))
+            .set_reference_bases("A".parse()?)
+            .build()?;
+
+        let src =
+            env::temp_dir().join(format!("noodles-bcf-test_index-{}.bcf", std::process::id()));
+
+        let mut writer = File::create(&src).map(crate::Writer::new)?;
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+        writer.write_vcf_record(&header, &string_maps, &record)?;
+        writer.try_finish()?;
+        drop(writer);
+
+        let result = index(&src);
+        let data = fs::read(&src);
+        fs::remove_file(&src)?;
+        let (index, data) = (result?, data?);
+
+        assert_eq!(index.depth(), 6);
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_format()?;
+        reader.read_header()?;
+        let start_position = reader.virtual_position();
+
+        let start = Position::try_from(1 << 29)?;
+        let end = Position::try_from((1 << 29) + 16)?;
+        let chunks = index.query(0, start..=end)?;
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].start(), start_position);
+
+        let start = Position::try_from(1)?;
+        let end = Position::try_from(1 << 20)?;
+        assert!(index.query(0, start..=end)?.is_empty());
+
+        Ok(())
+    }
+}
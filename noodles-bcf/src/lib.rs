@@ -6,13 +6,17 @@
 mod r#async;
 
 pub mod header;
+mod indexed_writer;
+mod indexer;
 pub mod reader;
 pub mod record;
 mod writer;
 
-pub use self::{reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    indexed_writer::IndexedWriter, indexer::index, reader::Reader, record::Record, writer::Writer,
+};
 
 #[cfg(feature = "async")]
-pub use self::r#async::Reader as AsyncReader;
+pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
 
 static MAGIC_NUMBER: &[u8] = b"BCF";
@@ -1,13 +1,14 @@
 //! BCF record and fields.
 
+pub mod builder;
 mod convert;
 mod filters;
-mod genotypes;
+pub mod genotypes;
 mod info;
 pub(crate) mod value;
 
 pub(crate) use self::value::Value;
-pub use self::{filters::Filters, genotypes::Genotypes, info::Info};
+pub use self::{builder::Builder, filters::Filters, genotypes::Genotypes, info::Info};
 
 use std::io;
 
@@ -32,6 +33,18 @@ pub struct Record {
 }
 
 impl Record {
+    /// Returns a builder to create a record from each of its fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let builder = bcf::Record::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
     /// Returns the chromosome ID of the record.
     ///
     /// The chromosome ID represents an index in the contig string map, which associates an ID (by
@@ -50,7 +63,17 @@ impl Record {
         self.chrom
     }
 
-    pub(crate) fn chromosome_id_mut(&mut self) -> &mut ChromosomeId {
+    /// Returns a mutable reference to the chromosome ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let mut record = bcf::Record::default();
+    /// *record.chromosome_id_mut() = 2;
+    /// assert_eq!(record.chromosome_id(), 2);
+    /// ```
+    pub fn chromosome_id_mut(&mut self) -> &mut ChromosomeId {
         &mut self.chrom
     }
 
@@ -181,11 +204,29 @@ impl Record {
         &mut self.id
     }
 
-    pub(crate) fn reference_bases(&self) -> &vcf::record::ReferenceBases {
+    /// Returns the reference bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let record = bcf::Record::default();
+    /// assert_eq!(record.reference_bases().to_string(), "A");
+    /// ```
+    pub fn reference_bases(&self) -> &vcf::record::ReferenceBases {
         &self.r#ref
     }
 
-    pub(crate) fn alternate_bases(&self) -> &vcf::record::AlternateBases {
+    /// Returns the alternate bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let record = bcf::Record::default();
+    /// assert!(record.alternate_bases().is_empty());
+    /// ```
+    pub fn alternate_bases(&self) -> &vcf::record::AlternateBases {
         &self.alt
     }
 
@@ -202,7 +243,23 @@ impl Record {
         &self.filter
     }
 
-    pub(crate) fn filters_mut(&mut self) -> &mut Filters {
+    /// Returns a mutable reference to the filters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::{self as bcf, header::StringMaps};
+    ///
+    /// let string_maps = StringMaps::default();
+    ///
+    /// let mut record = bcf::Record::default();
+    /// record.filters_mut().insert(string_maps.strings(), "PASS")?;
+    ///
+    /// assert_eq!(record.filters().as_ref(), [0]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn filters_mut(&mut self) -> &mut Filters {
         &mut self.filter
     }
 
@@ -219,7 +276,35 @@ impl Record {
         &self.info
     }
 
-    pub(crate) fn info_mut(&mut self) -> &mut Info {
+    /// Returns a mutable reference to the info.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::{self as bcf, header::StringMaps};
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{info::Key, record::value::{map, Map}},
+    ///     record::info::{field::Value, Field},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(Map::<map::Info>::from(Key::TotalDepth))
+    ///     .build();
+    ///
+    /// let string_maps = StringMaps::from(&header);
+    ///
+    /// let mut record = bcf::Record::default();
+    /// record.info_mut().insert(
+    ///     string_maps.strings(),
+    ///     Field::new(Key::TotalDepth, Some(Value::Integer(8))),
+    /// )?;
+    ///
+    /// assert_eq!(record.info().len(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn info_mut(&mut self) -> &mut Info {
         &mut self.info
     }
 
@@ -236,7 +321,37 @@ impl Record {
         &self.genotypes
     }
 
-    pub(crate) fn genotypes_mut(&mut self) -> &mut Genotypes {
+    /// Returns a mutable reference to the genotypes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::{self as bcf, header::StringMaps};
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{format::Key, record::value::{map::Format, Map}},
+    ///     record::genotypes::genotype::field::Value,
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(Map::<Format>::from(Key::Genotype))
+    ///     .build();
+    ///
+    /// let string_maps = StringMaps::from(&header);
+    ///
+    /// let mut record = bcf::Record::default();
+    /// record.genotypes_mut().insert(
+    ///     &header,
+    ///     string_maps.strings(),
+    ///     &Key::Genotype,
+    ///     &[Some(Value::String(String::from("0/0")))],
+    /// )?;
+    ///
+    /// assert_eq!(record.genotypes().len(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn genotypes_mut(&mut self) -> &mut Genotypes {
         &mut self.genotypes
     }
 }
@@ -0,0 +1,415 @@
+//! BCF record builder.
+
+use std::{error, fmt};
+
+use noodles_vcf as vcf;
+
+use super::{ChromosomeId, Filters, Genotypes, Info, Record};
+
+/// A BCF record builder.
+#[derive(Debug, Default, PartialEq)]
+pub struct Builder {
+    chromosome_id: Option<ChromosomeId>,
+    position: Option<vcf::record::Position>,
+    end: Option<vcf::record::Position>,
+    quality_score: Option<vcf::record::QualityScore>,
+    ids: vcf::record::Ids,
+    reference_bases: Option<vcf::record::ReferenceBases>,
+    alternate_bases: vcf::record::AlternateBases,
+    filters: Filters,
+    info: Info,
+    genotypes: Genotypes,
+}
+
+/// An error returned when a BCF record fails to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// The chromosome ID is missing.
+    MissingChromosomeId,
+    /// The position is missing.
+    MissingPosition,
+    /// The reference bases are missing.
+    MissingReferenceBases,
+    /// The end position is before the start position.
+    InvalidEnd,
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingChromosomeId => f.write_str("missing chromosome ID"),
+            Self::MissingPosition => f.write_str("missing position"),
+            Self::MissingReferenceBases => f.write_str("missing reference bases"),
+            Self::InvalidEnd => f.write_str("invalid end"),
+        }
+    }
+}
+
+impl Builder {
+    /// Sets the chromosome ID.
+    ///
+    /// This is an index in the contig string map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::Position;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(2)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.chromosome_id(), 2);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_chromosome_id(mut self, chromosome_id: ChromosomeId) -> Self {
+        self.chromosome_id = Some(chromosome_id);
+        self
+    }
+
+    /// Sets the start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::Position;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::from(8))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(usize::from(record.position()), 8);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_position(mut self, position: vcf::record::Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Sets the end position.
+    ///
+    /// By default, the end position is calculated from the length of the reference bases. This is
+    /// typically only set for records with symbolic alternate alleles, i.e., records with an
+    /// `END` info field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::Position;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::from(8))
+    ///     .set_end(Position::from(13))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("<DEL>".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.end().map(usize::from)?, 13);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_end(mut self, end: vcf::record::Position) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Sets the quality score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::{Position, QualityScore};
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_quality_score(QualityScore::try_from(13.0)?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.quality_score().map(f32::from), Some(13.0));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_quality_score(mut self, quality_score: vcf::record::QualityScore) -> Self {
+        self.quality_score = Some(quality_score);
+        self
+    }
+
+    /// Sets the IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::{Ids, Position};
+    ///
+    /// let ids: Ids = "nd0".parse()?;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::from(1))
+    ///     .set_ids(ids.clone())
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.ids(), &ids);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_ids(mut self, ids: vcf::record::Ids) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Sets the reference bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::{Position, ReferenceBases};
+    ///
+    /// let reference_bases: ReferenceBases = "AC".parse()?;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases(reference_bases.clone())
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.reference_bases(), &reference_bases);
+    /// assert_eq!(record.end().map(usize::from)?, 2);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_reference_bases(mut self, reference_bases: vcf::record::ReferenceBases) -> Self {
+        self.reference_bases = Some(reference_bases);
+        self
+    }
+
+    /// Sets the alternate bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::{AlternateBases, Position};
+    ///
+    /// let alternate_bases: AlternateBases = "C".parse()?;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases(alternate_bases.clone())
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.alternate_bases(), &alternate_bases);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_alternate_bases(mut self, alternate_bases: vcf::record::AlternateBases) -> Self {
+        self.alternate_bases = alternate_bases;
+        self
+    }
+
+    /// Sets the filters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::{self as bcf, header::StringMaps, record::Filters};
+    /// use noodles_vcf::record::Position;
+    ///
+    /// let string_maps = StringMaps::default();
+    ///
+    /// let mut filters = Filters::default();
+    /// filters.insert(string_maps.strings(), "PASS")?;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_filters(filters.clone())
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.filters(), &filters);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_filters(mut self, filters: Filters) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    /// Sets the info.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::{self as bcf, record::Info};
+    /// use noodles_vcf::record::Position;
+    ///
+    /// let info = Info::new(vec![0x11, 0x01, 0x11, 0x08], 1);
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_info(info.clone())
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.info(), &info);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_info(mut self, info: Info) -> Self {
+        self.info = info;
+        self
+    }
+
+    /// Sets the genotypes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::{self as bcf, record::Genotypes};
+    /// use noodles_vcf::record::Position;
+    ///
+    /// let genotypes = Genotypes::new(vec![0x11, 0x01, 0x11, 0x02], 1, 1);
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_genotypes(genotypes.clone())
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.genotypes(), &genotypes);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_genotypes(mut self, genotypes: Genotypes) -> Self {
+        self.genotypes = genotypes;
+        self
+    }
+
+    /// Builds a BCF record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::Position;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build(self) -> Result<Record, BuildError> {
+        let chrom = self.chromosome_id.ok_or(BuildError::MissingChromosomeId)?;
+        let pos = self.position.ok_or(BuildError::MissingPosition)?;
+        let r#ref = self
+            .reference_bases
+            .ok_or(BuildError::MissingReferenceBases)?;
+
+        let rlen = match self.end {
+            Some(end) => usize::from(end)
+                .checked_sub(usize::from(pos))
+                .map(|n| n + 1)
+                .ok_or(BuildError::InvalidEnd)?,
+            None => r#ref.len(),
+        };
+
+        Ok(Record {
+            chrom,
+            pos,
+            rlen,
+            qual: self.quality_score,
+            id: self.ids,
+            r#ref,
+            alt: self.alternate_bases,
+            filter: self.filters,
+            info: self.info,
+            genotypes: self.genotypes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let builder = Builder::default();
+
+        assert!(builder.chromosome_id.is_none());
+        assert!(builder.position.is_none());
+        assert!(builder.end.is_none());
+        assert!(builder.quality_score.is_none());
+        assert!(builder.ids.is_empty());
+        assert!(builder.reference_bases.is_none());
+        assert!(builder.alternate_bases.is_empty());
+        assert!(builder.filters.is_empty());
+        assert!(builder.info.is_empty());
+        assert!(builder.genotypes.is_empty());
+    }
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::record::Position;
+
+        assert_eq!(
+            Builder::default()
+                .set_position(Position::from(1))
+                .set_reference_bases("A".parse()?)
+                .build(),
+            Err(BuildError::MissingChromosomeId)
+        );
+
+        assert_eq!(
+            Builder::default()
+                .set_chromosome_id(0)
+                .set_reference_bases("A".parse()?)
+                .build(),
+            Err(BuildError::MissingPosition)
+        );
+
+        assert_eq!(
+            Builder::default()
+                .set_chromosome_id(0)
+                .set_position(Position::from(1))
+                .build(),
+            Err(BuildError::MissingReferenceBases)
+        );
+
+        assert_eq!(
+            Builder::default()
+                .set_chromosome_id(0)
+                .set_position(Position::from(8))
+                .set_end(Position::from(5))
+                .set_reference_bases("A".parse()?)
+                .build(),
+            Err(BuildError::InvalidEnd)
+        );
+
+        let record = Builder::default()
+            .set_chromosome_id(0)
+            .set_position(Position::from(1))
+            .set_reference_bases("A".parse()?)
+            .build()?;
+
+        assert_eq!(record, Record::default());
+
+        Ok(())
+    }
+}
@@ -91,6 +91,72 @@ impl Filters {
     pub fn clear(&mut self) {
         self.0.clear();
     }
+
+    /// Adds a filter by ID.
+    ///
+    /// The filter ID must be in the string map. This returns whether the filter was added, i.e.,
+    /// `false` if it is already in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::{header::StringMaps, record::Filters};
+    ///
+    /// let string_maps = StringMaps::default();
+    ///
+    /// let mut filters = Filters::default();
+    /// assert!(filters.insert(string_maps.strings(), "PASS")?);
+    /// assert!(!filters.insert(string_maps.strings(), "PASS")?);
+    /// assert_eq!(filters.as_ref(), [0]);
+    ///
+    /// assert!(filters.insert(string_maps.strings(), "q10").is_err());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn insert(&mut self, string_string_map: &StringStringMap, id: &str) -> io::Result<bool> {
+        let i = string_string_map.get_index_of(id).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("filter missing from string map: {}", id),
+            )
+        })?;
+
+        if self.0.contains(&i) {
+            Ok(false)
+        } else {
+            self.0.push(i);
+            Ok(true)
+        }
+    }
+
+    /// Removes a filter by ID.
+    ///
+    /// This returns whether the filter was in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::{header::StringMaps, record::Filters};
+    ///
+    /// let string_maps = StringMaps::default();
+    ///
+    /// let mut filters = Filters::default();
+    /// filters.insert(string_maps.strings(), "PASS")?;
+    ///
+    /// assert!(filters.remove(string_maps.strings(), "PASS"));
+    /// assert!(filters.is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn remove(&mut self, string_string_map: &StringStringMap, id: &str) -> bool {
+        let len = self.0.len();
+
+        if let Some(i) = string_string_map.get_index_of(id) {
+            self.0.retain(|&j| j != i);
+        }
+
+        self.0.len() < len
+    }
 }
 
 impl AsRef<[usize]> for Filters {
@@ -1,4 +1,16 @@
-use std::io;
+//! BCF record genotypes.
+
+mod column;
+mod field;
+mod genotype_column;
+
+pub use self::{
+    column::{Column, ColumnValue, StringColumn, Values},
+    field::{Field, FieldValues, Fields},
+    genotype_column::{Allele, Alleles, GenotypeColumn},
+};
+
+use std::{io, ops::Range};
 
 use noodles_vcf as vcf;
 
@@ -13,6 +25,28 @@ pub struct Genotypes {
 }
 
 impl Genotypes {
+    /// Creates genotypes by wrapping the given buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::record::Genotypes;
+    ///
+    /// let data = vec![
+    ///     0x11, 0x01, 0x11, // GT
+    ///     0x02, // 0
+    /// ];
+    ///
+    /// let genotypes = Genotypes::new(data, 1, 1);
+    /// ```
+    pub fn new(buf: Vec<u8>, format_count: usize, sample_count: usize) -> Self {
+        Self {
+            buf,
+            format_count,
+            sample_count,
+        }
+    }
+
     /// Converts BCF record genotypes to VCF record genotypes.
     ///
     /// # Examples
@@ -112,6 +146,287 @@ impl Genotypes {
         self.format_count
     }
 
+    /// Returns an iterator over the fields.
+    ///
+    /// Each field holds the values of a FORMAT key for all samples. The values are not decoded
+    /// until they are accessed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::record::Genotypes;
+    ///
+    /// let data = vec![
+    ///     0x11, 0x01, 0x21, // GT
+    ///     0x02, 0x04, // 0/1
+    ///     0x11, 0x02, 0x11, // DP
+    ///     0x08, // 8
+    /// ];
+    ///
+    /// let genotypes = Genotypes::new(data, 2, 1);
+    ///
+    /// let keys = genotypes
+    ///     .fields()
+    ///     .map(|result| result.map(|field| field.key()))
+    ///     .collect::<io::Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(keys, [1, 2]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn fields(&self) -> Fields<'_> {
+        Fields::new(&self.buf, self.format_count, self.sample_count)
+    }
+
+    /// Returns the field with the given FORMAT key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::{header::StringMaps, record::Genotypes};
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{format::Key, record::value::{map::Format, Map}},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(Map::<Format>::from(Key::Genotype))
+    ///     .build();
+    ///
+    /// let string_maps = StringMaps::from(&header);
+    ///
+    /// let data = vec![
+    ///     0x11, 0x01, 0x21, // GT
+    ///     0x02, 0x04, // sample 0 = 0/1
+    ///     0x04, 0x05, // sample 1 = 1|1
+    /// ];
+    ///
+    /// let genotypes = Genotypes::new(data, 1, 2);
+    ///
+    /// let field = genotypes.get(string_maps.strings(), &Key::Genotype).transpose()?;
+    ///
+    /// let alleles: Vec<_> = field
+    ///     .and_then(|field| field.genotypes())
+    ///     .and_then(|column| column.get(1))
+    ///     .map(|alleles| alleles.map(|allele| allele.position()).collect())
+    ///     .unwrap_or_default();
+    ///
+    /// assert_eq!(alleles, [Some(1), Some(1)]);
+    ///
+    /// assert!(genotypes.get(string_maps.strings(), &Key::ReadDepth).is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn get(
+        &self,
+        string_string_map: &StringStringMap,
+        key: &vcf::header::format::Key,
+    ) -> Option<io::Result<Field<'_>>> {
+        let i = string_string_map.get_index_of(key.as_ref())?;
+
+        for result in self.fields() {
+            match result {
+                Ok(field) => {
+                    if field.key() == i {
+                        return Some(Ok(field));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
+        None
+    }
+
+    /// Inserts a field into the genotypes.
+    ///
+    /// There must be one value per sample. If there are no fields, the sample count is set to the
+    /// number of values.
+    ///
+    /// The field key must be in the string map and defined in the header. If a field with the
+    /// same key already exists, its values are replaced in place. A new `GT` field is inserted
+    /// first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::{header::StringMaps, record::Genotypes};
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{format::Key, record::value::{map::Format, Map}},
+    ///     record::genotypes::genotype::field::Value,
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(Map::<Format>::from(Key::Genotype))
+    ///     .add_format(Map::<Format>::from(Key::ReadDepth))
+    ///     .build();
+    ///
+    /// let string_maps = StringMaps::from(&header);
+    ///
+    /// let mut genotypes = Genotypes::default();
+    ///
+    /// genotypes.insert(
+    ///     &header,
+    ///     string_maps.strings(),
+    ///     &Key::ReadDepth,
+    ///     &[Some(Value::Integer(8)), None],
+    /// )?;
+    ///
+    /// genotypes.insert(
+    ///     &header,
+    ///     string_maps.strings(),
+    ///     &Key::Genotype,
+    ///     &[Some(Value::String(String::from("0|1"))), None],
+    /// )?;
+    ///
+    /// assert_eq!(genotypes.len(), 2);
+    /// assert_eq!(genotypes.format_count(), 2);
+    ///
+    /// let keys = genotypes
+    ///     .fields()
+    ///     .map(|result| result.map(|field| field.key()))
+    ///     .collect::<io::Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(keys, [1, 2]); // GT, DP
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn insert(
+        &mut self,
+        header: &vcf::Header,
+        string_string_map: &StringStringMap,
+        key: &vcf::header::format::Key,
+        values: &[Option<vcf::record::genotypes::genotype::field::Value>],
+    ) -> io::Result<()> {
+        use vcf::header::format::Key;
+
+        use crate::writer::vcf_record::genotypes::{
+            write_genotype_field_key, write_genotype_field_values,
+            write_genotype_genotype_field_values,
+        };
+
+        if self.format_count == 0 {
+            self.sample_count = values.len();
+        } else if values.len() != self.sample_count {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid value count: expected {}, got {}",
+                    self.sample_count,
+                    values.len()
+                ),
+            ));
+        }
+
+        let values: Vec<_> = values.iter().map(|value| value.as_ref()).collect();
+
+        let mut buf = Vec::new();
+        write_genotype_field_key(&mut buf, string_string_map, key)?;
+
+        if key == &Key::Genotype {
+            write_genotype_genotype_field_values(&mut buf, &values)?;
+        } else {
+            let format = header.formats().get(key).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "missing FORMAT header record")
+            })?;
+
+            write_genotype_field_values(&mut buf, format, &values)?;
+        }
+
+        let i = string_string_map
+            .get_index_of(key.as_ref())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing genotype key"))?;
+
+        match self.find(i)? {
+            Some(range) => {
+                self.buf.splice(range, buf);
+            }
+            None => {
+                if key == &Key::Genotype {
+                    self.buf.splice(0..0, buf);
+                } else {
+                    self.buf.extend(buf);
+                }
+
+                self.format_count += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes a field from the genotypes.
+    ///
+    /// This returns whether the field was present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::{header::StringMaps, record::Genotypes};
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{format::Key, record::value::{map::Format, Map}},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(Map::<Format>::from(Key::Genotype))
+    ///     .build();
+    ///
+    /// let string_maps = StringMaps::from(&header);
+    ///
+    /// let data = vec![
+    ///     0x11, 0x01, 0x21, // GT
+    ///     0x02, 0x04, // 0/1
+    /// ];
+    ///
+    /// let mut genotypes = Genotypes::new(data, 1, 1);
+    ///
+    /// assert!(genotypes.remove(string_maps.strings(), &Key::Genotype)?);
+    /// assert_eq!(genotypes.format_count(), 0);
+    /// assert!(genotypes.as_ref().is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn remove(
+        &mut self,
+        string_string_map: &StringStringMap,
+        key: &vcf::header::format::Key,
+    ) -> io::Result<bool> {
+        let i = match string_string_map.get_index_of(key.as_ref()) {
+            Some(i) => i,
+            None => return Ok(false),
+        };
+
+        match self.find(i)? {
+            Some(range) => {
+                self.buf.drain(range);
+                self.format_count -= 1;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    // Returns the byte range of the field with the given key string map index.
+    fn find(&self, i: usize) -> io::Result<Option<Range<usize>>> {
+        let mut fields = self.fields();
+        let mut start = fields.position();
+
+        while let Some(result) = fields.next() {
+            let field = result?;
+            let end = fields.position();
+
+            if field.key() == i {
+                return Ok(Some(start..end));
+            }
+
+            start = end;
+        }
+
+        Ok(None)
+    }
+
     pub(crate) fn set_format_count(&mut self, format_count: usize) {
         self.format_count = format_count;
     }
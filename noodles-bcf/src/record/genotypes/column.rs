@@ -0,0 +1,295 @@
+use std::{io, marker::PhantomData, slice, str};
+
+use crate::record::value::{Float, Int16, Int32, Int8};
+
+const NUL: u8 = 0x00;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for i8 {}
+    impl Sealed for i16 {}
+    impl Sealed for i32 {}
+    impl Sealed for f32 {}
+}
+
+/// A fixed-size BCF genotype field value type.
+///
+/// This is implemented for `i8`, `i16`, `i32`, and `f32`.
+pub trait ColumnValue: sealed::Sealed + Copy {
+    #[doc(hidden)]
+    const SIZE: usize;
+
+    /// Decodes a raw value.
+    ///
+    /// This returns `None` for an end-of-vector marker and `Some(None)` for a missing value.
+    #[doc(hidden)]
+    fn decode(buf: &[u8]) -> Option<Option<Self>>;
+}
+
+impl ColumnValue for i8 {
+    const SIZE: usize = 1;
+
+    fn decode(buf: &[u8]) -> Option<Option<Self>> {
+        match Int8::from(buf[0] as i8) {
+            Int8::Value(n) => Some(Some(n)),
+            Int8::EndOfVector => None,
+            _ => Some(None),
+        }
+    }
+}
+
+impl ColumnValue for i16 {
+    const SIZE: usize = 2;
+
+    fn decode(buf: &[u8]) -> Option<Option<Self>> {
+        match Int16::from(i16::from_le_bytes([buf[0], buf[1]])) {
+            Int16::Value(n) => Some(Some(n)),
+            Int16::EndOfVector => None,
+            _ => Some(None),
+        }
+    }
+}
+
+impl ColumnValue for i32 {
+    const SIZE: usize = 4;
+
+    fn decode(buf: &[u8]) -> Option<Option<Self>> {
+        match Int32::from(i32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]])) {
+            Int32::Value(n) => Some(Some(n)),
+            Int32::EndOfVector => None,
+            _ => Some(None),
+        }
+    }
+}
+
+impl ColumnValue for f32 {
+    const SIZE: usize = 4;
+
+    fn decode(buf: &[u8]) -> Option<Option<Self>> {
+        match Float::from(f32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]])) {
+            Float::Value(n) => Some(Some(n)),
+            Float::EndOfVector => None,
+            _ => Some(None),
+        }
+    }
+}
+
+/// A column of fixed-size values of a genotype field, with one vector of values per sample.
+///
+/// The values are decoded lazily from the record buffer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Column<'a, T> {
+    src: &'a [u8],
+    values_per_sample: usize,
+    sample_count: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T> Column<'a, T>
+where
+    T: ColumnValue,
+{
+    pub(super) fn new(src: &'a [u8], values_per_sample: usize, sample_count: usize) -> Self {
+        Self {
+            src,
+            values_per_sample,
+            sample_count,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of samples.
+    pub fn len(&self) -> usize {
+        self.sample_count
+    }
+
+    /// Returns whether there are any samples.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the max number of values per sample.
+    pub fn values_per_sample(&self) -> usize {
+        self.values_per_sample
+    }
+
+    /// Returns the values of the sample at the given index.
+    pub fn get(&self, i: usize) -> Option<Values<'a, T>> {
+        if i >= self.sample_count {
+            return None;
+        }
+
+        let sample_size = self.values_per_sample * T::SIZE;
+        let start = i * sample_size;
+        let buf = self.src.get(start..start + sample_size)?;
+
+        Some(Values::new(buf))
+    }
+
+    /// Returns an iterator over the values of each sample.
+    pub fn iter(&self) -> impl Iterator<Item = Values<'a, T>> + '_ {
+        (0..self.sample_count).filter_map(move |i| self.get(i))
+    }
+}
+
+/// An iterator over the values of a sample in a column.
+///
+/// A missing value is `None`. Reserved values are also treated as missing. The iterator ends at
+/// the end-of-vector marker, i.e., trailing padding is not included.
+#[derive(Clone, Debug)]
+pub struct Values<'a, T> {
+    chunks: slice::ChunksExact<'a, u8>,
+    is_done: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T> Values<'a, T>
+where
+    T: ColumnValue,
+{
+    fn new(buf: &'a [u8]) -> Self {
+        Self {
+            chunks: buf.chunks_exact(T::SIZE),
+            is_done: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Values<'a, T>
+where
+    T: ColumnValue,
+{
+    type Item = Option<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+
+        match self.chunks.next().and_then(T::decode) {
+            Some(value) => Some(value),
+            None => {
+                self.is_done = true;
+                None
+            }
+        }
+    }
+}
+
+/// A column of string values of a genotype field, with one string per sample.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StringColumn<'a> {
+    src: &'a [u8],
+    sample_len: usize,
+    sample_count: usize,
+}
+
+impl<'a> StringColumn<'a> {
+    pub(super) fn new(src: &'a [u8], sample_len: usize, sample_count: usize) -> Self {
+        Self {
+            src,
+            sample_len,
+            sample_count,
+        }
+    }
+
+    /// Returns the number of samples.
+    pub fn len(&self) -> usize {
+        self.sample_count
+    }
+
+    /// Returns whether there are any samples.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the string of the sample at the given index.
+    ///
+    /// Trailing NUL padding is removed.
+    pub fn get(&self, i: usize) -> Option<io::Result<&'a str>> {
+        if i >= self.sample_count {
+            return None;
+        }
+
+        let start = i * self.sample_len;
+        let buf = self.src.get(start..start + self.sample_len)?;
+
+        let data = match buf.iter().position(|&b| b == NUL) {
+            Some(j) => &buf[..j],
+            None => buf,
+        };
+
+        Some(str::from_utf8(data).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
+    }
+
+    /// Returns an iterator over the string of each sample.
+    pub fn iter(&self) -> impl Iterator<Item = io::Result<&'a str>> + '_ {
+        (0..self.sample_count).filter_map(move |i| self.get(i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let data = [
+            0x05, 0x08, // sample 0 = [5, 8]
+            0x0d, 0x81, // sample 1 = [13]
+            0x80, 0x81, // sample 2 = [.]
+        ];
+
+        let column: Column<'_, i8> = Column::new(&data, 2, 3);
+        assert_eq!(column.len(), 3);
+        assert_eq!(column.values_per_sample(), 2);
+
+        assert_eq!(
+            column.get(0).map(|values| values.collect::<Vec<_>>()),
+            Some(vec![Some(5), Some(8)])
+        );
+        assert_eq!(
+            column.get(1).map(|values| values.collect::<Vec<_>>()),
+            Some(vec![Some(13)])
+        );
+        assert_eq!(
+            column.get(2).map(|values| values.collect::<Vec<_>>()),
+            Some(vec![None])
+        );
+        assert!(column.get(3).is_none());
+    }
+
+    #[test]
+    fn test_get_with_wide_values() {
+        let data = [
+            0x00, 0x00, 0x00, 0x3f, // 0.5
+            0x01, 0x00, 0x80, 0x7f, // missing
+        ];
+
+        let column: Column<'_, f32> = Column::new(&data, 1, 2);
+        let actual: Vec<Vec<_>> = column.iter().map(|values| values.collect()).collect();
+        assert_eq!(actual, [vec![Some(0.5)], vec![None]]);
+
+        let data = [
+            0x00, 0x01, // 256
+            0x01, 0x80, // end of vector
+        ];
+
+        let column: Column<'_, i16> = Column::new(&data, 2, 1);
+        let actual: Vec<_> = column.get(0).map(|values| values.collect()).unwrap();
+        assert_eq!(actual, [Some(256)]);
+    }
+
+    #[test]
+    fn test_string_column_get() -> io::Result<()> {
+        let data = b"nd\x00ls";
+        let column = StringColumn::new(data, 3, 1);
+
+        assert_eq!(column.get(0).transpose()?, Some("nd"));
+        assert!(column.get(1).is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+use std::io;
+
+use super::{Column, GenotypeColumn, StringColumn};
+use crate::{
+    reader::{string_map::read_string_map_index, value::read_type},
+    record::value::Type,
+};
+
+/// A BCF record genotype field.
+///
+/// A field holds the values of a single FORMAT key for all samples.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Field<'a> {
+    key: usize,
+    values: FieldValues<'a>,
+}
+
+impl<'a> Field<'a> {
+    /// Returns the string map index of the FORMAT key.
+    ///
+    /// Use [`crate::header::string_maps::StringMap::get_index`] to look up the key name.
+    pub fn key(&self) -> usize {
+        self.key
+    }
+
+    /// Returns the typed values of all samples.
+    pub fn values(&self) -> FieldValues<'a> {
+        self.values
+    }
+
+    /// Returns the values as genotypes (`GT`).
+    ///
+    /// This is `None` if the field values are not integers.
+    pub fn genotypes(&self) -> Option<GenotypeColumn<'a>> {
+        match self.values {
+            FieldValues::Int8(column) => Some(GenotypeColumn::Int8(column)),
+            FieldValues::Int16(column) => Some(GenotypeColumn::Int16(column)),
+            FieldValues::Int32(column) => Some(GenotypeColumn::Int32(column)),
+            _ => None,
+        }
+    }
+}
+
+/// The typed values of a BCF record genotype field.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FieldValues<'a> {
+    /// 8-bit integer values.
+    Int8(Column<'a, i8>),
+    /// 16-bit integer values.
+    Int16(Column<'a, i16>),
+    /// 32-bit integer values.
+    Int32(Column<'a, i32>),
+    /// Single-precision floating-point values.
+    Float(Column<'a, f32>),
+    /// String values.
+    String(StringColumn<'a>),
+}
+
+/// An iterator over the fields of BCF record genotypes.
+pub struct Fields<'a> {
+    src: &'a [u8],
+    len: usize,
+    sample_count: usize,
+    remaining: usize,
+}
+
+impl<'a> Fields<'a> {
+    pub(super) fn new(src: &'a [u8], format_count: usize, sample_count: usize) -> Self {
+        Self {
+            src,
+            len: src.len(),
+            sample_count,
+            remaining: format_count,
+        }
+    }
+
+    // Returns the number of bytes read.
+    pub(super) fn position(&self) -> usize {
+        self.len - self.src.len()
+    }
+
+    fn read_field(&mut self) -> io::Result<Field<'a>> {
+        let key = read_string_map_index(&mut self.src)?;
+
+        let ty = read_type(&mut self.src)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing field type"))?;
+
+        let (len, size) = match ty {
+            Type::Int8(len) => (len, 1),
+            Type::Int16(len) => (len, 2),
+            Type::Int32(len) | Type::Float(len) => (len, 4),
+            Type::String(len) => (len, 1),
+        };
+
+        let data_len = self
+            .sample_count
+            .checked_mul(len)
+            .and_then(|n| n.checked_mul(size))
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "invalid genotype field length")
+            })?;
+
+        if self.src.len() < data_len {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+
+        let (buf, rest) = self.src.split_at(data_len);
+        self.src = rest;
+
+        let sample_count = self.sample_count;
+
+        let values = match ty {
+            Type::Int8(_) => FieldValues::Int8(Column::new(buf, len, sample_count)),
+            Type::Int16(_) => FieldValues::Int16(Column::new(buf, len, sample_count)),
+            Type::Int32(_) => FieldValues::Int32(Column::new(buf, len, sample_count)),
+            Type::Float(_) => FieldValues::Float(Column::new(buf, len, sample_count)),
+            Type::String(_) => FieldValues::String(StringColumn::new(buf, len, sample_count)),
+        };
+
+        Ok(Field { key, values })
+    }
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = io::Result<Field<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        match self.read_field() {
+            Ok(field) => {
+                self.remaining -= 1;
+                Some(Ok(field))
+            }
+            Err(e) => {
+                self.remaining = 0;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let data = [
+            0x11, 0x01, // key = 1
+            0x21, // type = Int8(2)
+            0x02, 0x04, 0x04, 0x05, // values
+            0x11, 0x02, // key = 2
+            0x17, // type = String(1)
+            b'n', b'd', // values
+        ];
+
+        let mut fields = Fields::new(&data, 2, 2);
+
+        let field = fields.next().transpose()?.expect("missing field");
+        assert_eq!(field.key(), 1);
+        assert!(matches!(field.values(), FieldValues::Int8(_)));
+        assert_eq!(field.genotypes().map(|column| column.len()), Some(2));
+
+        let field = fields.next().transpose()?.expect("missing field");
+        assert_eq!(field.key(), 2);
+        assert!(field.genotypes().is_none());
+
+        match field.values() {
+            FieldValues::String(column) => {
+                let actual = column.iter().collect::<io::Result<Vec<_>>>()?;
+                assert_eq!(actual, ["n", "d"]);
+            }
+            values => panic!("expected String, got {:?}", values),
+        }
+
+        assert!(fields.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_truncated_values() {
+        let data = [0x11, 0x01, 0x21, 0x02];
+        let mut fields = Fields::new(&data, 1, 2);
+
+        assert!(matches!(
+            fields.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+        assert!(fields.next().is_none());
+    }
+
+    #[test]
+    fn test_next_with_overflowing_length() {
+        let data = [0x11, 0x01, 0x23];
+        let mut fields = Fields::new(&data, 1, usize::MAX);
+
+        assert!(matches!(
+            fields.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -0,0 +1,138 @@
+use super::{column, Column};
+
+/// A genotype (`GT`) allele.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Allele {
+    position: Option<usize>,
+    is_phased: bool,
+}
+
+impl Allele {
+    /// Returns the allele index, where 0 is the reference allele.
+    ///
+    /// This is `None` if the allele is missing (`.`).
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Returns whether the allele is phased with the previous allele.
+    pub fn is_phased(&self) -> bool {
+        self.is_phased
+    }
+}
+
+impl From<i32> for Allele {
+    fn from(n: i32) -> Self {
+        Self {
+            position: usize::try_from((n >> 1) - 1).ok(),
+            is_phased: n & 0x01 == 1,
+        }
+    }
+}
+
+/// A column of genotype (`GT`) values, with one list of alleles per sample.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GenotypeColumn<'a> {
+    /// 8-bit encoded genotypes.
+    Int8(Column<'a, i8>),
+    /// 16-bit encoded genotypes.
+    Int16(Column<'a, i16>),
+    /// 32-bit encoded genotypes.
+    Int32(Column<'a, i32>),
+}
+
+impl<'a> GenotypeColumn<'a> {
+    /// Returns the number of samples.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Int8(column) => column.len(),
+            Self::Int16(column) => column.len(),
+            Self::Int32(column) => column.len(),
+        }
+    }
+
+    /// Returns whether there are any samples.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the alleles of the sample at the given index.
+    pub fn get(&self, i: usize) -> Option<Alleles<'a>> {
+        match self {
+            Self::Int8(column) => column.get(i).map(Alleles::Int8),
+            Self::Int16(column) => column.get(i).map(Alleles::Int16),
+            Self::Int32(column) => column.get(i).map(Alleles::Int32),
+        }
+    }
+
+    /// Returns an iterator over the alleles of each sample.
+    pub fn iter(&self) -> impl Iterator<Item = Alleles<'a>> + '_ {
+        (0..self.len()).filter_map(move |i| self.get(i))
+    }
+}
+
+/// An iterator over the alleles of a sample.
+#[derive(Clone, Debug)]
+pub enum Alleles<'a> {
+    #[doc(hidden)]
+    Int8(column::Values<'a, i8>),
+    #[doc(hidden)]
+    Int16(column::Values<'a, i16>),
+    #[doc(hidden)]
+    Int32(column::Values<'a, i32>),
+}
+
+impl<'a> Iterator for Alleles<'a> {
+    type Item = Allele;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = match self {
+            Self::Int8(values) => values.next()?.map(i32::from),
+            Self::Int16(values) => values.next()?.map(i32::from),
+            Self::Int32(values) => values.next()?,
+        };
+
+        // A missing value is treated as a missing allele.
+        Some(value.map(Allele::from).unwrap_or(Allele {
+            position: None,
+            is_phased: false,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let data = [
+            0x02, 0x04, // sample 0 = 0/1
+            0x04, 0x05, // sample 1 = 1|1
+            0x00, 0x81, // sample 2 = .
+        ];
+
+        let column = GenotypeColumn::Int8(Column::new(&data, 2, 3));
+        assert_eq!(column.len(), 3);
+
+        let actual: Vec<Vec<_>> = column
+            .iter()
+            .map(|alleles| {
+                alleles
+                    .map(|allele| (allele.position(), allele.is_phased()))
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(
+            actual,
+            [
+                vec![(Some(0), false), (Some(1), false)],
+                vec![(Some(1), false), (Some(1), true)],
+                vec![(None, false)],
+            ]
+        );
+
+        assert!(column.get(3).is_none());
+    }
+}
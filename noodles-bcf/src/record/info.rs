@@ -1,4 +1,4 @@
-use std::io;
+use std::{io, ops::Range};
 
 use noodles_vcf as vcf;
 
@@ -207,6 +207,141 @@ impl Info {
             .map(move |_| read_info_field(&mut reader, header.infos(), string_string_map))
     }
 
+    /// Inserts a field into the info map.
+    ///
+    /// The field key must be in the string map. If a field with the same key already exists, its
+    /// value is replaced in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::{header::StringMaps, record::Info};
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{info::Key, record::value::{map, Map}},
+    ///     record::info::{field::Value, Field},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(Map::<map::Info>::from(Key::TotalDepth))
+    ///     .build();
+    ///
+    /// let string_maps = StringMaps::from(&header);
+    ///
+    /// let mut info = Info::default();
+    /// info.insert(string_maps.strings(), Field::new(Key::TotalDepth, Some(Value::Integer(8))))?;
+    /// info.insert(string_maps.strings(), Field::new(Key::TotalDepth, Some(Value::Integer(13))))?;
+    ///
+    /// assert_eq!(info.len(), 1);
+    /// assert_eq!(
+    ///     info.get(&header, string_maps.strings(), &Key::TotalDepth).transpose()?,
+    ///     Some(Field::new(Key::TotalDepth, Some(Value::Integer(13))))
+    /// );
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn insert(
+        &mut self,
+        string_string_map: &StringStringMap,
+        field: vcf::record::info::Field,
+    ) -> io::Result<()> {
+        use crate::writer::vcf_record::site::info::write_info_field;
+
+        let mut buf = Vec::new();
+        write_info_field(&mut buf, string_string_map, &field)?;
+
+        let i = string_string_map
+            .get_index_of(field.key().as_ref())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing info key"))?;
+
+        match self.find(i)? {
+            Some(range) => {
+                self.buf.splice(range, buf);
+            }
+            None => {
+                self.buf.extend(buf);
+                self.field_count += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes a field from the info map.
+    ///
+    /// This returns whether the field was in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::{header::StringMaps, record::Info};
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{info::Key, record::value::{map, Map}},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(Map::<map::Info>::from(Key::AlleleCount))
+    ///     .add_info(Map::<map::Info>::from(Key::TotalDepth))
+    ///     .build();
+    ///
+    /// let string_maps = StringMaps::from(&header);
+    ///
+    /// let data = vec![
+    ///     0x11, 0x01, 0x11, 0x05, // AC=5
+    ///     0x11, 0x02, 0x11, 0x08, // DP=8
+    /// ];
+    ///
+    /// let mut info = Info::new(data, 2);
+    ///
+    /// assert!(info.remove(string_maps.strings(), &Key::AlleleCount)?);
+    /// assert!(!info.remove(string_maps.strings(), &Key::AlleleCount)?);
+    /// assert_eq!(info.len(), 1);
+    /// assert_eq!(info.as_ref(), [0x11, 0x02, 0x11, 0x08]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn remove(
+        &mut self,
+        string_string_map: &StringStringMap,
+        key: &vcf::header::info::Key,
+    ) -> io::Result<bool> {
+        let i = match string_string_map.get_index_of(key.as_ref()) {
+            Some(i) => i,
+            None => return Ok(false),
+        };
+
+        match self.find(i)? {
+            Some(range) => {
+                self.buf.drain(range);
+                self.field_count -= 1;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    // Returns the byte range of the field with the given key string map index.
+    fn find(&self, i: usize) -> io::Result<Option<Range<usize>>> {
+        use crate::reader::{string_map::read_string_map_index, value::read_value};
+
+        let mut reader = &self.buf[..];
+
+        for _ in 0..self.len() {
+            let start = self.buf.len() - reader.len();
+
+            let j = read_string_map_index(&mut reader)?;
+            read_value(&mut reader)?;
+
+            if j == i {
+                let end = self.buf.len() - reader.len();
+                return Ok(Some(start..end));
+            }
+        }
+
+        Ok(None)
+    }
+
     pub(crate) fn set_field_count(&mut self, field_count: usize) {
         self.field_count = field_count;
     }
@@ -1,7 +1,7 @@
-mod record;
+pub(crate) mod record;
 mod string_map;
 mod value;
-mod vcf_record;
+pub(crate) mod vcf_record;
 
 use std::{
     ffi::CString,
@@ -193,13 +193,36 @@ where
     }
 }
 
+impl<W> Writer<bgzf::MultithreadedWriter<W>>
+where
+    W: Write,
+{
+    /// Attempts to finish the output stream.
+    ///
+    /// This waits for all pending blocks to be compressed and written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// use noodles_bgzf as bgzf;
+    /// let mut writer = bcf::Writer::from(bgzf::MultithreadedWriter::new(Vec::new()));
+    /// writer.try_finish()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn try_finish(&mut self) -> io::Result<()> {
+        self.inner.try_finish()
+    }
+}
+
 impl<W> From<W> for Writer<W> {
     fn from(inner: W) -> Self {
         Self { inner }
     }
 }
 
-fn write_file_format<W>(writer: &mut W) -> io::Result<()>
+pub(crate) fn write_file_format<W>(writer: &mut W) -> io::Result<()>
 where
     W: Write,
 {
@@ -212,7 +235,7 @@ where
     Ok(())
 }
 
-fn write_header<W>(writer: &mut W, header: &vcf::Header) -> io::Result<()>
+pub(crate) fn write_header<W>(writer: &mut W, header: &vcf::Header) -> io::Result<()>
 where
     W: Write,
 {
@@ -7,7 +7,7 @@ use crate::{
     Record,
 };
 
-pub(super) fn write_record<W>(writer: &mut W, record: &Record) -> io::Result<()>
+pub(crate) fn write_record<W>(writer: &mut W, record: &Record) -> io::Result<()>
 where
     W: Write,
 {
@@ -114,4 +114,86 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_with_edited_fields() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_vcf::{
+            self as vcf,
+            header::{
+                format, info,
+                record::value::{
+                    map::{self, Contig, Filter, Format},
+                    Map,
+                },
+            },
+            record::{genotypes::genotype::field::Value as GenotypeFieldValue, Position},
+        };
+
+        use crate::{header::StringMaps, reader::record::read_record};
+
+        let header = vcf::Header::builder()
+            .add_contig(Map::<Contig>::new("sq0".parse()?))
+            .add_filter(Map::<Filter>::new("q10", "Quality below 10"))
+            .add_info(Map::<map::Info>::from(info::Key::TotalDepth))
+            .add_format(Map::<Format>::from(format::Key::Genotype))
+            .add_format(Map::<Format>::from(format::Key::ReadDepth))
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        let string_maps = StringMaps::from(&header);
+
+        let mut record = Record::builder()
+            .set_chromosome_id(0)
+            .set_position(Position::from(8))
+            .set_reference_bases("A".parse()?)
+            .set_alternate_bases("C".parse()?)
+            .build()?;
+
+        record.filters_mut().insert(string_maps.strings(), "q10")?;
+
+        record.info_mut().insert(
+            string_maps.strings(),
+            vcf::record::info::Field::new(
+                info::Key::TotalDepth,
+                Some(vcf::record::info::field::Value::Integer(13)),
+            ),
+        )?;
+
+        record.genotypes_mut().insert(
+            &header,
+            string_maps.strings(),
+            &format::Key::ReadDepth,
+            &[Some(GenotypeFieldValue::Integer(5)), None],
+        )?;
+
+        record.genotypes_mut().insert(
+            &header,
+            string_maps.strings(),
+            &format::Key::Genotype,
+            &[
+                Some(GenotypeFieldValue::String(String::from("0|1"))),
+                Some(GenotypeFieldValue::String(String::from("1/1"))),
+            ],
+        )?;
+
+        let mut buf = Vec::new();
+        write_record(&mut buf, &record)?;
+
+        let mut reader = &buf[..];
+        let mut actual = Record::default();
+        read_record(&mut reader, &mut Vec::new(), &mut actual)?;
+
+        assert_eq!(actual, record);
+
+        let actual = actual.try_into_vcf_record(&header, &string_maps)?;
+        let expected = vcf::Record::try_from_str(
+            "sq0\t8\t.\tA\tC\t.\tq10\tDP=13\tGT:DP\t0|1:5\t1/1:.",
+            &header,
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-mod genotypes;
+pub(crate) mod genotypes;
 pub(crate) mod site;
 
 use std::io::{self, Write};
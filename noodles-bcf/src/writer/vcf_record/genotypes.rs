@@ -49,11 +49,15 @@ where
             values.push(value);
         }
 
-        let format = header.formats().get(key).ok_or_else(|| {
-            io::Error::new(io::ErrorKind::InvalidData, "missing FORMAT header record")
-        })?;
+        if key == &Key::Genotype {
+            write_genotype_genotype_field_values(writer, &values)?;
+        } else {
+            let format = header.formats().get(key).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "missing FORMAT header record")
+            })?;
 
-        write_genotype_field_values(writer, format, &values)?;
+            write_genotype_field_values(writer, format, &values)?;
+        }
     }
 
     Ok(())
@@ -108,6 +112,157 @@ where
     }
 }
 
+pub fn write_genotype_genotype_field_values<W>(
+    writer: &mut W,
+    values: &[Option<&Value>],
+) -> io::Result<()>
+where
+    W: Write,
+{
+    let genotypes = values
+        .iter()
+        .map(|value| match value {
+            Some(Value::String(s)) => parse_genotype_genotype_field_value(s),
+            Some(v) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("type mismatch: expected String, got {:?}", v),
+            )),
+            None => Ok(Vec::new()),
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let max_len = genotypes.iter().map(|g| g.len()).max().unwrap_or_default();
+    let max_len = cmp::max(max_len, 1);
+
+    let max_value = genotypes
+        .iter()
+        .flatten()
+        .copied()
+        .max()
+        .unwrap_or_default();
+
+    if max_value <= i32::from(Int8::MAX_VALUE) {
+        write_type(writer, Some(Type::Int8(max_len)))?;
+        write_genotype_genotype_values(
+            writer,
+            &genotypes,
+            max_len,
+            i32::from(i8::from(Int8::Missing)),
+            i32::from(i8::from(Int8::EndOfVector)),
+            |writer, n| {
+                let n =
+                    i8::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+                writer.write_i8(n)
+            },
+        )
+    } else if max_value <= i32::from(Int16::MAX_VALUE) {
+        write_type(writer, Some(Type::Int16(max_len)))?;
+        write_genotype_genotype_values(
+            writer,
+            &genotypes,
+            max_len,
+            i32::from(i16::from(Int16::Missing)),
+            i32::from(i16::from(Int16::EndOfVector)),
+            |writer, n| {
+                let n =
+                    i16::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+                writer.write_i16::<LittleEndian>(n)
+            },
+        )
+    } else {
+        write_type(writer, Some(Type::Int32(max_len)))?;
+        write_genotype_genotype_values(
+            writer,
+            &genotypes,
+            max_len,
+            i32::from(Int32::Missing),
+            i32::from(Int32::EndOfVector),
+            |writer, n| writer.write_i32::<LittleEndian>(n),
+        )
+    }
+}
+
+fn write_genotype_genotype_values<W, F>(
+    writer: &mut W,
+    genotypes: &[Vec<i32>],
+    max_len: usize,
+    missing: i32,
+    end_of_vector: i32,
+    mut write_value: F,
+) -> io::Result<()>
+where
+    W: Write,
+    F: FnMut(&mut W, i32) -> io::Result<()>,
+{
+    for genotype in genotypes {
+        if genotype.is_empty() {
+            write_value(writer, missing)?;
+        } else {
+            for &n in genotype {
+                write_value(writer, n)?;
+            }
+        }
+
+        for _ in cmp::max(genotype.len(), 1)..max_len {
+            write_value(writer, end_of_vector)?;
+        }
+    }
+
+    Ok(())
+}
+
+// Alleles are encoded as `(i + 1) << 1 | phased`, where a missing allele is 0.
+fn parse_genotype_genotype_field_value(s: &str) -> io::Result<Vec<i32>> {
+    const MISSING_ALLELE: &str = ".";
+    const PHASED: char = '|';
+    const UNPHASED: char = '/';
+
+    let mut alleles = Vec::new();
+    let mut is_phased = false;
+    let mut start = 0;
+
+    for (i, c) in s.char_indices().chain([(s.len(), UNPHASED)]) {
+        if c != PHASED && c != UNPHASED {
+            continue;
+        }
+
+        let raw_allele = &s[start..i];
+
+        let j = if raw_allele == MISSING_ALLELE {
+            0
+        } else {
+            raw_allele
+                .parse::<i32>()
+                .ok()
+                .filter(|&j| j >= 0)
+                .and_then(|j| j.checked_add(1))
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("invalid genotype: {}", s),
+                    )
+                })?
+        };
+
+        let n = j
+            .checked_mul(2)
+            .map(|n| n | i32::from(is_phased))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid genotype: {}", s),
+                )
+            })?;
+
+        alleles.push(n);
+
+        is_phased = c == PHASED;
+        start = i + c.len_utf8();
+    }
+
+    Ok(alleles)
+}
+
 fn write_genotype_field_integer_values<W>(
     writer: &mut W,
     values: &[Option<&Value>],
@@ -1228,4 +1383,65 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_genotype_genotype_field_values() -> io::Result<()> {
+        let value_0 = Value::String(String::from("0/1"));
+        let value_1 = Value::String(String::from("1|2"));
+        let value_2 = Value::String(String::from("."));
+        let values = [Some(&value_0), Some(&value_1), Some(&value_2), None];
+
+        let mut buf = Vec::new();
+        write_genotype_genotype_field_values(&mut buf, &values)?;
+
+        let expected = [
+            0x21, // Some(Type::Int8(2))
+            0x02, 0x04, // 0/1
+            0x04, 0x07, // 1|2
+            0x00, 0x81, // .
+            0x80, 0x81, // None
+        ];
+
+        assert_eq!(buf, expected);
+
+        let value_0 = Value::String(String::from("0/63"));
+        let value_1 = Value::String(String::from("1"));
+        let values = [Some(&value_0), Some(&value_1), None];
+
+        buf.clear();
+        write_genotype_genotype_field_values(&mut buf, &values)?;
+
+        let expected = [
+            0x22, // Some(Type::Int16(2))
+            0x02, 0x00, 0x80, 0x00, // 0/63
+            0x04, 0x00, 0x01, 0x80, // 1
+            0x00, 0x80, 0x01, 0x80, // None
+        ];
+
+        assert_eq!(buf, expected);
+
+        let value = Value::String(String::from("0|16383"));
+        let values = [Some(&value)];
+
+        buf.clear();
+        write_genotype_genotype_field_values(&mut buf, &values)?;
+
+        let expected = [
+            0x23, // Some(Type::Int32(2))
+            0x02, 0x00, 0x00, 0x00, // 0
+            0x01, 0x80, 0x00, 0x00, // |16383
+        ];
+
+        assert_eq!(buf, expected);
+
+        let value = Value::String(String::from("0/x"));
+        let values = [Some(&value)];
+        buf.clear();
+        assert!(matches!(
+            write_genotype_genotype_field_values(&mut buf, &values),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
 }
@@ -29,7 +29,7 @@ where
     Ok(())
 }
 
-fn write_info_field<W>(
+pub fn write_info_field<W>(
     writer: &mut W,
     string_string_map: &StringStringMap,
     field: &vcf::record::info::Field,
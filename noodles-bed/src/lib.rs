@@ -2,7 +2,7 @@
 
 //! **noodles-bed** handles the reading and writing of the BED (Browser Extensible Data) format.
 
-mod reader;
+pub mod reader;
 pub mod record;
 mod writer;
 
@@ -14,7 +14,7 @@ use futures::{ready, stream::TryBuffered, Stream, TryStreamExt};
 use pin_project_lite::pin_project;
 use tokio::io::{AsyncBufRead, AsyncRead, AsyncSeek, ReadBuf};
 
-use crate::{Block, VirtualPosition};
+use crate::{gzi, Block, VirtualPosition};
 
 use self::inflater::Inflater;
 
@@ -126,6 +126,56 @@ where
 
         Ok(pos)
     }
+
+    /// Seeks the stream to the given uncompressed position.
+    ///
+    /// The given gzip index (GZI) is used to find the nearest block that starts at or before the
+    /// uncompressed position. The stream is then moved to that block, and data is discarded until
+    /// the uncompressed position is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bgzf as bgzf;
+    /// let mut reader = bgzf::AsyncReader::new(Cursor::new(Vec::new()));
+    /// let index = Vec::new();
+    /// reader.seek_by_uncompressed_position(&index, 0).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn seek_by_uncompressed_position(
+        &mut self,
+        index: &gzi::Index,
+        pos: u64,
+    ) -> io::Result<u64> {
+        use tokio::io::AsyncBufReadExt;
+
+        let (cpos, upos) = gzi::find_block_start(index, pos);
+
+        let virtual_position = VirtualPosition::try_from((cpos, 0))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        self.seek(virtual_position).await?;
+
+        let mut remaining = pos - upos;
+
+        while remaining > 0 {
+            let buf = self.fill_buf().await?;
+
+            if buf.is_empty() {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+            }
+
+            let amt = cmp::min(buf.len() as u64, remaining);
+            Pin::new(&mut *self).consume(amt as usize);
+            remaining -= amt;
+        }
+
+        Ok(pos)
+    }
 }
 
 impl<R> AsyncRead for Reader<R>
@@ -220,4 +270,43 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_seek_by_uncompressed_position() -> io::Result<()> {
+        use std::io::Write;
+
+        use crate::Writer;
+
+        let mut writer = Writer::builder(Vec::new()).build_gzi_index(true).build();
+
+        for i in 0..4 {
+            writer.write_all(format!("noodles-{}", i).as_bytes())?;
+            writer.flush()?;
+        }
+
+        writer.try_finish()?;
+
+        let index = writer.gzi_index().cloned().unwrap();
+        let data = writer.into_inner();
+        let mut reader = Reader::new(Cursor::new(data));
+
+        let mut buf = Vec::new();
+
+        for (pos, expected) in [
+            (3, &b"dles-0noodles-1noodles-2noodles-3"[..]),
+            (25, b"-2noodles-3"),
+            (0, b"noodles-0noodles-1noodles-2noodles-3"),
+        ] {
+            assert_eq!(
+                reader.seek_by_uncompressed_position(&index, pos).await?,
+                pos
+            );
+
+            buf.clear();
+            reader.read_to_end(&mut buf).await?;
+            assert_eq!(buf, expected);
+        }
+
+        Ok(())
+    }
 }
@@ -4,9 +4,91 @@
 //!
 //! [GZ index]: http://www.htslib.org/doc/bgzip.html#GZI_FORMAT
 
+mod indexer;
 mod reader;
+mod writer;
 
-pub use self::reader::Reader;
+pub use self::{reader::Reader, writer::Writer};
+
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    path::Path,
+};
 
 /// A gzip index.
 pub type Index = Vec<(u64, u64)>;
+
+/// Reads the entire contents of a gzip index.
+///
+/// This is a convenience function and is equivalent to opening the file at the given path and
+/// reading the index.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_bgzf::gzi;
+/// let index = gzi::read("reference.fa.gz.gzi")?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn read<P>(src: P) -> io::Result<Index>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src).map(BufReader::new).map(Reader::new)?;
+    reader.read_index()
+}
+
+/// Indexes a BGZF file.
+///
+/// Only block headers and trailers are read; the compressed data is not inflated.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_bgzf::gzi;
+/// let index = gzi::index("reference.fa.gz")?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn index<P>(src: P) -> io::Result<Index>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src).map(BufReader::new)?;
+    self::indexer::index(&mut reader)
+}
+
+// Returns the compressed and uncompressed positions of the last block that starts at or before the
+// given uncompressed position.
+//
+// The first block, which is implicitly at (0, 0), is not listed in a gzip index.
+pub(crate) fn find_block_start(index: &Index, pos: u64) -> (u64, u64) {
+    let i = index.partition_point(|&(_, uncompressed)| uncompressed <= pos);
+
+    if i == 0 {
+        (0, 0)
+    } else {
+        index[i - 1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_block_start() {
+        let index = vec![(4668, 21294), (23810, 86529)];
+
+        assert_eq!(find_block_start(&index, 0), (0, 0));
+        assert_eq!(find_block_start(&index, 21293), (0, 0));
+        assert_eq!(find_block_start(&index, 21294), (4668, 21294));
+        assert_eq!(find_block_start(&index, 86528), (4668, 21294));
+        assert_eq!(find_block_start(&index, 86529), (23810, 86529));
+        assert_eq!(find_block_start(&index, 102334155), (23810, 86529));
+
+        assert_eq!(find_block_start(&Vec::new(), 8), (0, 0));
+    }
+}
@@ -0,0 +1,83 @@
+use std::{
+    io::{self, Read},
+    mem,
+};
+
+use byteorder::{ByteOrder, LittleEndian};
+
+use super::Index;
+use crate::reader::block::read_frame;
+
+/// Builds a gzip index from a BGZF stream.
+///
+/// An entry is added for the start of each block that has uncompressed data, excluding a block at
+/// the start of the stream.
+pub(super) fn index<R>(reader: &mut R) -> io::Result<Index>
+where
+    R: Read,
+{
+    let mut index = Vec::new();
+    let mut buf = Vec::new();
+
+    let mut compressed_position = 0;
+    let mut uncompressed_position = 0;
+
+    while read_frame(reader, &mut buf)?.is_some() {
+        // ISIZE is the last field of the gzip trailer.
+        let (_, isize_buf) = buf.split_at(buf.len() - mem::size_of::<u32>());
+        let r#isize = u64::from(LittleEndian::read_u32(isize_buf));
+
+        if compressed_position > 0 && r#isize > 0 {
+            index.push((compressed_position, uncompressed_position));
+        }
+
+        compressed_position += buf.len() as u64;
+        uncompressed_position += r#isize;
+    }
+
+    Ok(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::Writer;
+
+    #[test]
+    fn test_index() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let position = writer.position();
+        writer.write_all(b"-bgzf")?;
+        let data = writer.finish()?;
+
+        let mut reader = &data[..];
+        assert_eq!(index(&mut reader)?, vec![(position, 7)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_matches_writer_gzi_index() -> io::Result<()> {
+        let mut writer = Writer::builder(Vec::new()).build_gzi_index(true).build();
+
+        for i in 0..8 {
+            let line = format!("noodles-bgzf-{}\n", i);
+            writer.write_all(line.repeat(4096).as_bytes())?;
+        }
+
+        writer.try_finish()?;
+
+        let expected = writer.gzi_index().cloned().unwrap();
+        assert!(expected.len() > 1);
+
+        let data = writer.into_inner();
+        let mut reader = &data[..];
+        assert_eq!(index(&mut reader)?, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,271 @@
+//! Indexed BGZF reader.
+
+use std::io::{self, BufRead, Read, Seek, SeekFrom};
+
+use super::{gzi, Reader, VirtualPosition};
+
+/// An indexed BGZF reader.
+///
+/// This wraps a [`Reader`] with its associated gzip index (GZI), which allows seeking by
+/// uncompressed positions. It implements [`std::io::Seek`], where positions are offsets in the
+/// uncompressed stream.
+///
+/// Seeking from the end of the stream ([`SeekFrom::End`]) is not supported.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io::{self, Read, Seek, SeekFrom}};
+/// use noodles_bgzf::{self as bgzf, gzi};
+///
+/// let index = gzi::read("reference.fa.gz.gzi")?;
+/// let mut reader = File::open("reference.fa.gz").map(|f| bgzf::IndexedReader::new(f, index))?;
+///
+/// reader.seek(SeekFrom::Start(8))?;
+///
+/// let mut buf = [0; 4];
+/// reader.read_exact(&mut buf)?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct IndexedReader<R> {
+    inner: Reader<R>,
+    index: gzi::Index,
+    position: u64,
+}
+
+impl<R> IndexedReader<R>
+where
+    R: Read,
+{
+    /// Creates an indexed BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::IndexedReader::new(&data[..], Vec::new());
+    /// ```
+    pub fn new(inner: R, index: gzi::Index) -> Self {
+        Self {
+            inner: Reader::new(inner),
+            index,
+            position: 0,
+        }
+    }
+
+    /// Returns a reference to the underlying BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::IndexedReader::new(&data[..], Vec::new());
+    /// assert!(reader.get_ref().get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &Reader<R> {
+        &self.inner
+    }
+
+    /// Returns the associated gzip index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::IndexedReader::new(&data[..], Vec::new());
+    /// assert!(reader.index().is_empty());
+    /// ```
+    pub fn index(&self) -> &gzi::Index {
+        &self.index
+    }
+
+    /// Unwraps and returns the underlying BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::IndexedReader::new(&data[..], Vec::new());
+    /// let inner = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> Reader<R> {
+        self.inner
+    }
+
+    /// Returns the current virtual position of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::IndexedReader::new(&data[..], Vec::new());
+    /// assert_eq!(reader.virtual_position(), bgzf::VirtualPosition::from(0));
+    /// ```
+    pub fn virtual_position(&self) -> VirtualPosition {
+        self.inner.virtual_position()
+    }
+}
+
+impl<R> Read for IndexedReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.position += n as u64;
+        Ok(n)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.inner.read_exact(buf)?;
+        self.position += buf.len() as u64;
+        Ok(())
+    }
+}
+
+impl<R> BufRead for IndexedReader<R>
+where
+    R: Read,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+        self.position += amt as u64;
+    }
+}
+
+impl<R> Seek for IndexedReader<R>
+where
+    R: Read + Seek,
+{
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(n) => n,
+            SeekFrom::Current(n) => if n >= 0 {
+                self.position.checked_add(n as u64)
+            } else {
+                self.position.checked_sub(n.unsigned_abs())
+            }
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid seek position"))?,
+            SeekFrom::End(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "seeking from the end of a BGZF stream is not supported",
+                ))
+            }
+        };
+
+        self.position = self.inner.seek_by_uncompressed_position(&self.index, pos)?;
+
+        Ok(self.position)
+    }
+
+    // The uncompressed position is tracked, so this does not need to seek (and discard the
+    // current block).
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use super::*;
+    use crate::Writer;
+
+    fn build_data() -> io::Result<(Vec<u8>, gzi::Index)> {
+        let mut writer = Writer::builder(Vec::new()).build_gzi_index(true).build();
+
+        for i in 0..4 {
+            writer.write_all(format!("noodles-{}", i).as_bytes())?;
+            writer.flush()?;
+        }
+
+        writer.try_finish()?;
+
+        let index = writer.gzi_index().cloned().unwrap();
+        Ok((writer.into_inner(), index))
+    }
+
+    #[test]
+    fn test_seek() -> io::Result<()> {
+        let (data, index) = build_data()?;
+        let mut reader = IndexedReader::new(Cursor::new(data), index);
+
+        let mut buf = [0; 4];
+
+        assert_eq!(reader.seek(SeekFrom::Start(20))?, 20);
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"odle");
+
+        assert_eq!(reader.stream_position()?, 24);
+
+        assert_eq!(reader.seek(SeekFrom::Current(-21))?, 3);
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"dles");
+
+        assert_eq!(reader.seek(SeekFrom::Current(4))?, 11);
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"odle");
+
+        assert!(matches!(
+            reader.seek(SeekFrom::End(0)),
+            Err(e) if e.kind() == io::ErrorKind::Unsupported
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_position() -> io::Result<()> {
+        struct SeekCounter<R> {
+            inner: R,
+            seek_count: usize,
+        }
+
+        impl<R: Read> Read for SeekCounter<R> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.inner.read(buf)
+            }
+        }
+
+        impl<R: Seek> Seek for SeekCounter<R> {
+            fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+                self.seek_count += 1;
+                self.inner.seek(pos)
+            }
+        }
+
+        let (data, index) = build_data()?;
+        let inner = SeekCounter {
+            inner: Cursor::new(data),
+            seek_count: 0,
+        };
+        let mut reader = IndexedReader::new(inner, index);
+
+        let mut buf = [0; 4];
+        reader.seek(SeekFrom::Start(20))?;
+        reader.read_exact(&mut buf)?;
+
+        let seek_count = reader.get_ref().get_ref().seek_count;
+        let virtual_position = reader.virtual_position();
+
+        assert_eq!(reader.stream_position()?, 24);
+        assert_eq!(reader.get_ref().get_ref().seek_count, seek_count);
+        assert_eq!(reader.virtual_position(), virtual_position);
+
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"s-2n");
+
+        Ok(())
+    }
+}
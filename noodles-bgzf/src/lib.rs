@@ -40,11 +40,18 @@ mod r#async;
 mod block;
 mod gz;
 pub mod gzi;
+pub mod indexed_reader;
+pub mod multithreaded_reader;
+pub mod multithreaded_writer;
 mod reader;
 pub mod virtual_position;
 pub mod writer;
 
-pub use self::{reader::Reader, virtual_position::VirtualPosition, writer::Writer};
+pub use self::{
+    indexed_reader::IndexedReader, multithreaded_reader::MultithreadedReader,
+    multithreaded_writer::MultithreadedWriter, reader::Reader, virtual_position::VirtualPosition,
+    writer::Writer,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
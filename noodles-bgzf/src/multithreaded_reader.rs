@@ -0,0 +1,436 @@
+//! Multithreaded BGZF reader.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead, Read, Seek, SeekFrom},
+    sync::{
+        mpsc::{self, Receiver, Sender, SyncSender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+use super::{
+    reader::block::{parse_frame, read_frame},
+    Block, VirtualPosition,
+};
+
+type BlockResult = io::Result<Block>;
+
+struct Job {
+    buf: Vec<u8>,
+    position: u64,
+    tx: SyncSender<BlockResult>,
+}
+
+/// A multithreaded BGZF reader.
+///
+/// This is a BGZF reader that decompresses blocks using a pool of worker threads. Compressed
+/// blocks are read ahead on the calling thread and inflated concurrently, but uncompressed data is
+/// still emitted in stream order.
+///
+/// Like [`crate::Reader`], this implements both [`std::io::Read`] and [`std::io::BufRead`] and
+/// tracks (virtual) positions, so it should not be double buffered.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io::{self, Read}};
+/// use noodles_bgzf as bgzf;
+/// let mut reader = File::open("data.gz").map(bgzf::MultithreadedReader::new)?;
+/// let mut data = Vec::new();
+/// reader.read_to_end(&mut data)?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct MultithreadedReader<R> {
+    inner: R,
+    position: u64,
+    block: Block,
+    is_eof: bool,
+    queue: VecDeque<Receiver<BlockResult>>,
+    queue_capacity: usize,
+    job_tx: Option<Sender<Job>>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl<R> MultithreadedReader<R>
+where
+    R: Read,
+{
+    /// Creates a multithreaded BGZF reader builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let builder = bgzf::MultithreadedReader::builder(&data[..]);
+    /// let reader = builder.build();
+    /// ```
+    pub fn builder(inner: R) -> Builder<R> {
+        Builder::new(inner)
+    }
+
+    /// Creates a multithreaded BGZF reader.
+    ///
+    /// The worker count is set to the number of available logical CPUs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::MultithreadedReader::new(&data[..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self::builder(inner).build()
+    }
+
+    pub(crate) fn with_worker_count(worker_count: usize, inner: R) -> Self {
+        let (job_tx, job_rx) = mpsc::channel();
+        let handles = spawn_workers(worker_count, job_rx);
+
+        Self {
+            inner,
+            position: 0,
+            block: Block::default(),
+            is_eof: false,
+            queue: VecDeque::new(),
+            // Keep at least one block queued per worker while the current block is consumed.
+            queue_capacity: worker_count * 2,
+            job_tx: Some(job_tx),
+            handles,
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::MultithreadedReader::new(&data[..]);
+    /// assert!(reader.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns the current virtual position of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::MultithreadedReader::new(&data[..]);
+    /// assert_eq!(reader.virtual_position(), bgzf::VirtualPosition::from(0));
+    /// ```
+    pub fn virtual_position(&self) -> VirtualPosition {
+        self.block.virtual_position()
+    }
+
+    fn fill_queue(&mut self) -> io::Result<()> {
+        while !self.is_eof && self.queue.len() < self.queue_capacity {
+            let mut buf = Vec::new();
+
+            match read_frame(&mut self.inner, &mut buf) {
+                Ok(Some(())) => {}
+                Ok(None) => {
+                    self.is_eof = true;
+                    break;
+                }
+                Err(e) => {
+                    // Defer the error until all preceding blocks are consumed.
+                    let (tx, rx) = mpsc::sync_channel(1);
+                    tx.send(Err(e)).ok();
+                    self.queue.push_back(rx);
+                    self.is_eof = true;
+                    break;
+                }
+            }
+
+            let position = self.position;
+            self.position += buf.len() as u64;
+
+            let (tx, rx) = mpsc::sync_channel(1);
+
+            self.job_tx
+                .as_ref()
+                .expect("missing job sender")
+                .send(Job { buf, position, tx })
+                .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "worker pool closed"))?;
+
+            self.queue.push_back(rx);
+        }
+
+        Ok(())
+    }
+
+    fn read_block(&mut self) -> io::Result<()> {
+        self.fill_queue()?;
+
+        if let Some(rx) = self.queue.pop_front() {
+            self.block = rx
+                .recv()
+                .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "worker disconnected"))??;
+        }
+
+        Ok(())
+    }
+}
+
+impl<R> MultithreadedReader<R>
+where
+    R: Read + Seek,
+{
+    /// Seeks the stream to the given virtual position.
+    ///
+    /// Any blocks that were read ahead are discarded. The underlying stream's cursor is moved to
+    /// the compressed position, and the first block read has its own cursor moved to the
+    /// uncompressed position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_bgzf as bgzf;
+    /// let mut reader = bgzf::MultithreadedReader::new(Cursor::new(Vec::new()));
+    /// let virtual_position = bgzf::VirtualPosition::default();
+    /// reader.seek(virtual_position)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn seek(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
+        let (cpos, upos) = pos.into();
+
+        self.queue.clear();
+        self.is_eof = false;
+
+        self.inner.seek(SeekFrom::Start(cpos))?;
+        self.position = cpos;
+
+        self.block = Block::default();
+        self.block.set_position(cpos);
+
+        self.read_block()?;
+
+        self.block.data_mut().set_position(usize::from(upos));
+
+        Ok(pos)
+    }
+}
+
+impl<R> Drop for MultithreadedReader<R> {
+    fn drop(&mut self) {
+        self.queue.clear();
+        self.job_tx.take();
+
+        for handle in self.handles.drain(..) {
+            handle.join().ok();
+        }
+    }
+}
+
+impl<R> Read for MultithreadedReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let bytes_read = {
+            let mut remaining = self.fill_buf()?;
+            remaining.read(buf)?
+        };
+
+        self.consume(bytes_read);
+
+        Ok(bytes_read)
+    }
+}
+
+impl<R> BufRead for MultithreadedReader<R>
+where
+    R: Read,
+{
+    fn consume(&mut self, amt: usize) {
+        self.block.data_mut().consume(amt)
+    }
+
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if !self.block.data().has_remaining() {
+            self.read_block()?;
+        }
+
+        Ok(self.block.data().as_ref())
+    }
+}
+
+fn spawn_workers(worker_count: usize, job_rx: Receiver<Job>) -> Vec<JoinHandle<()>> {
+    let job_rx = Arc::new(Mutex::new(job_rx));
+
+    (0..worker_count)
+        .map(|_| {
+            let job_rx = Arc::clone(&job_rx);
+
+            thread::spawn(move || loop {
+                let job = match job_rx.lock() {
+                    Ok(rx) => match rx.recv() {
+                        Ok(job) => job,
+                        Err(_) => break,
+                    },
+                    Err(_) => break,
+                };
+
+                let mut block = Block::default();
+
+                let result = parse_frame(&job.buf, &mut block).map(|_| {
+                    block.set_position(job.position);
+                    block
+                });
+
+                // The receiver is dropped when read-ahead blocks are discarded, e.g., on seek.
+                job.tx.send(result).ok();
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::{Reader, Writer};
+
+    fn build_data() -> io::Result<Vec<u8>> {
+        use std::io::Write;
+
+        let mut writer = Writer::new(Vec::new());
+
+        for i in 0..8 {
+            let line = format!("noodles-bgzf-{}\n", i);
+            writer.write_all(line.repeat(4096).as_bytes())?;
+            writer.flush()?;
+        }
+
+        writer.finish()
+    }
+
+    #[test]
+    fn test_read_to_end() -> io::Result<()> {
+        let data = build_data()?;
+
+        let mut expected = Vec::new();
+        Reader::new(&data[..]).read_to_end(&mut expected)?;
+
+        for worker_count in [1, 2, 4] {
+            let mut reader = MultithreadedReader::builder(&data[..])
+                .set_worker_count(worker_count)
+                .build();
+
+            let mut actual = Vec::new();
+            reader.read_to_end(&mut actual)?;
+
+            assert_eq!(actual, expected);
+            assert_eq!(
+                reader.virtual_position(),
+                VirtualPosition::try_from((data.len() as u64, 0)).unwrap()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_virtual_position() -> io::Result<()> {
+        let data = build_data()?;
+
+        let mut reader = Reader::new(&data[..]);
+        let mut multithreaded_reader = MultithreadedReader::builder(&data[..])
+            .set_worker_count(2)
+            .build();
+
+        let mut expected_line = String::new();
+        let mut actual_line = String::new();
+
+        loop {
+            assert_eq!(
+                multithreaded_reader.virtual_position(),
+                reader.virtual_position()
+            );
+
+            expected_line.clear();
+            actual_line.clear();
+
+            let n = reader.read_line(&mut expected_line)?;
+            let m = multithreaded_reader.read_line(&mut actual_line)?;
+
+            assert_eq!(m, n);
+            assert_eq!(actual_line, expected_line);
+
+            if n == 0 {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek() -> Result<(), Box<dyn std::error::Error>> {
+        let data = build_data()?;
+
+        let mut reader = Reader::new(Cursor::new(&data));
+        let mut multithreaded_reader = MultithreadedReader::builder(Cursor::new(&data))
+            .set_worker_count(2)
+            .build();
+
+        let mut buf = Vec::new();
+        multithreaded_reader.read_to_end(&mut buf)?;
+
+        let mut line = String::new();
+
+        for _ in 0..3 {
+            reader.read_line(&mut line)?;
+        }
+
+        let pos = reader.virtual_position();
+
+        let mut expected = Vec::new();
+        reader.read_to_end(&mut expected)?;
+
+        multithreaded_reader.seek(pos)?;
+        assert_eq!(multithreaded_reader.virtual_position(), pos);
+
+        let mut actual = Vec::new();
+        multithreaded_reader.read_to_end(&mut actual)?;
+
+        assert_eq!(actual, expected);
+        assert_eq!(
+            multithreaded_reader.virtual_position(),
+            reader.virtual_position()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_invalid_block() -> io::Result<()> {
+        let mut data = build_data()?;
+        // Truncate the final (EOF) block.
+        data.truncate(data.len() - 4);
+
+        let mut reader = MultithreadedReader::builder(&data[..])
+            .set_worker_count(2)
+            .build();
+
+        let mut buf = Vec::new();
+        assert!(reader.read_to_end(&mut buf).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use std::io::Read;
+
+use super::MultithreadedReader;
+
+/// A multithreaded BGZF reader builder.
+pub struct Builder<R> {
+    inner: R,
+    worker_count: Option<usize>,
+}
+
+impl<R> Builder<R>
+where
+    R: Read,
+{
+    pub(crate) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            worker_count: None,
+        }
+    }
+
+    /// Sets a worker count.
+    ///
+    /// By default, the worker count is set to the number of available logical CPUs. A worker
+    /// count of 0 is treated as 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let builder = bgzf::MultithreadedReader::builder(&data[..]).set_worker_count(8);
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: usize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
+    /// Builds a multithreaded BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::MultithreadedReader::builder(&data[..]).build();
+    /// ```
+    pub fn build(self) -> MultithreadedReader<R> {
+        let worker_count = self.worker_count.unwrap_or_else(num_cpus::get).max(1);
+        MultithreadedReader::with_worker_count(worker_count, self.inner)
+    }
+}
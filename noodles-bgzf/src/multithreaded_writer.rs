@@ -0,0 +1,425 @@
+//! Multithreaded BGZF writer.
+
+mod builder;
+mod deferred_virtual_position;
+
+pub use self::{builder::Builder, deferred_virtual_position::DeferredVirtualPosition};
+
+use std::{
+    cmp,
+    collections::VecDeque,
+    io::{self, Write},
+    mem,
+    sync::{
+        mpsc::{self, Receiver, Sender, SyncSender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+use self::deferred_virtual_position::BlockPosition;
+use super::{
+    gz,
+    writer::{
+        deflate_data, write_header, write_trailer, CompressionLevel, BGZF_EOF, DEFAULT_BUF_SIZE,
+    },
+    BGZF_HEADER_SIZE,
+};
+
+type FrameResult = io::Result<Vec<u8>>;
+
+struct Job {
+    buf: Vec<u8>,
+    tx: SyncSender<FrameResult>,
+}
+
+/// A multithreaded BGZF writer.
+///
+/// This is a BGZF writer that compresses blocks using a pool of worker threads. Blocks are
+/// written to the underlying stream in the order they were staged.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::MultithreadedWriter::new(Vec::new());
+/// writer.write_all(b"noodles-bgzf")?;
+///
+/// let data = writer.finish()?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct MultithreadedWriter<W>
+where
+    W: Write,
+{
+    inner: Option<W>,
+    position: u64,
+    block_position: Arc<BlockPosition>,
+    buf: Vec<u8>,
+    queue: VecDeque<(Receiver<FrameResult>, Arc<BlockPosition>)>,
+    queue_capacity: usize,
+    job_tx: Option<Sender<Job>>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl<W> MultithreadedWriter<W>
+where
+    W: Write,
+{
+    /// Creates a multithreaded BGZF writer builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let builder = bgzf::MultithreadedWriter::builder(Vec::new());
+    /// let writer = builder.build();
+    /// ```
+    pub fn builder(inner: W) -> Builder<W> {
+        Builder::new(inner)
+    }
+
+    /// Creates a multithreaded BGZF writer with a default compression level.
+    ///
+    /// The worker count is set to the number of available logical CPUs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let writer = bgzf::MultithreadedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self::builder(inner).build()
+    }
+
+    pub(crate) fn with_worker_count(
+        worker_count: usize,
+        compression_level: CompressionLevel,
+        inner: W,
+    ) -> Self {
+        let (job_tx, job_rx) = mpsc::channel();
+        let handles = spawn_workers(worker_count, compression_level, job_rx);
+
+        Self {
+            inner: Some(inner),
+            position: 0,
+            block_position: Arc::new(BlockPosition::resolved(0)),
+            buf: Vec::with_capacity(DEFAULT_BUF_SIZE),
+            queue: VecDeque::new(),
+            // Allow each worker to have one block waiting while another is being written.
+            queue_capacity: worker_count * 2,
+            job_tx: Some(job_tx),
+            handles,
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// Blocks that are still being compressed are not yet written to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let writer = bgzf::MultithreadedWriter::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        self.inner.as_ref().unwrap()
+    }
+
+    /// Returns the current virtual position of the stream.
+    ///
+    /// The compressed position depends on the sizes of all previously staged blocks, which may
+    /// still be compressing. The returned position is resolved once those blocks are written to
+    /// the underlying writer, e.g., after a flush.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let writer = bgzf::MultithreadedWriter::new(Vec::new());
+    /// let position = writer.virtual_position();
+    /// assert_eq!(position.get(), Some(bgzf::VirtualPosition::from(0)));
+    /// ```
+    pub fn virtual_position(&self) -> DeferredVirtualPosition {
+        // SAFETY: The uncompressed buffer is guaranteed to be <= `MAX_UNCOMPRESSED_POSITION`.
+        let uncompressed_position = self.buf.len() as u16;
+        DeferredVirtualPosition::new(Arc::clone(&self.block_position), uncompressed_position)
+    }
+
+    fn send_block(&mut self) -> io::Result<()> {
+        let buf = mem::replace(&mut self.buf, Vec::with_capacity(DEFAULT_BUF_SIZE));
+        let (tx, rx) = mpsc::sync_channel(1);
+
+        self.job_tx
+            .as_ref()
+            .expect("missing job sender")
+            .send(Job { buf, tx })
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "worker pool closed"))?;
+
+        let block_position = mem::replace(
+            &mut self.block_position,
+            Arc::new(BlockPosition::unresolved()),
+        );
+
+        self.queue.push_back((rx, block_position));
+
+        self.write_pending_blocks(self.queue_capacity)
+    }
+
+    // Writes compressed blocks, in order, until at most `len` blocks are pending.
+    fn write_pending_blocks(&mut self, len: usize) -> io::Result<()> {
+        while self.queue.len() > len {
+            let (rx, block_position) = self.queue.pop_front().unwrap();
+
+            let frame = rx
+                .recv()
+                .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "worker disconnected"))??;
+
+            let inner = self.inner.as_mut().unwrap();
+            inner.write_all(&frame)?;
+
+            block_position.resolve(self.position);
+            self.position += frame.len() as u64;
+        }
+
+        // The block being staged starts after the last written block.
+        if self.queue.is_empty() {
+            self.block_position.resolve(self.position);
+        }
+
+        Ok(())
+    }
+
+    /// Attempts to finish the output stream by flushing any remaining buffers.
+    ///
+    /// This then appends the final BGZF EOF block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::MultithreadedWriter::new(Vec::new());
+    /// writer.write_all(b"noodles-bgzf")?;
+    ///
+    /// writer.try_finish()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn try_finish(&mut self) -> io::Result<()> {
+        self.flush()?;
+
+        let inner = self.inner.as_mut().unwrap();
+        let result = inner.write_all(BGZF_EOF);
+
+        self.position += BGZF_EOF.len() as u64;
+        self.block_position = Arc::new(BlockPosition::resolved(self.position));
+
+        result
+    }
+
+    /// Returns the underlying writer after finishing the output stream.
+    ///
+    /// This method can only be called once. Any further usage of the writer may result in a panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::MultithreadedWriter::new(Vec::new());
+    /// writer.write_all(b"noodles-bgzf")?;
+    ///
+    /// let data = writer.finish()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn finish(mut self) -> io::Result<W> {
+        self.try_finish()?;
+        let inner = self.inner.take().unwrap();
+        Ok(inner)
+    }
+}
+
+impl<W> Drop for MultithreadedWriter<W>
+where
+    W: Write,
+{
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            let _ = self.try_finish();
+        }
+
+        self.queue.clear();
+        self.job_tx.take();
+
+        for handle in self.handles.drain(..) {
+            handle.join().ok();
+        }
+    }
+}
+
+impl<W> Write for MultithreadedWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let max_write_len = cmp::min(DEFAULT_BUF_SIZE - self.buf.len(), buf.len());
+
+        self.buf.extend_from_slice(&buf[..max_write_len]);
+
+        if self.buf.len() >= DEFAULT_BUF_SIZE {
+            self.send_block()?;
+        }
+
+        Ok(max_write_len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.buf.is_empty() {
+            self.send_block()?;
+        }
+
+        self.write_pending_blocks(0)
+    }
+}
+
+fn spawn_workers(
+    worker_count: usize,
+    compression_level: CompressionLevel,
+    job_rx: Receiver<Job>,
+) -> Vec<JoinHandle<()>> {
+    let job_rx = Arc::new(Mutex::new(job_rx));
+
+    (0..worker_count)
+        .map(|_| {
+            let job_rx = Arc::clone(&job_rx);
+
+            thread::spawn(move || loop {
+                let job = match job_rx.lock() {
+                    Ok(rx) => match rx.recv() {
+                        Ok(job) => job,
+                        Err(_) => break,
+                    },
+                    Err(_) => break,
+                };
+
+                let result = build_frame(&job.buf, compression_level);
+                job.tx.send(result).ok();
+            })
+        })
+        .collect()
+}
+
+fn build_frame(buf: &[u8], compression_level: CompressionLevel) -> FrameResult {
+    let (cdata, crc32, r#isize) = deflate_data(buf, compression_level.into())?;
+
+    let block_size = BGZF_HEADER_SIZE + cdata.len() + gz::TRAILER_SIZE;
+    let mut frame = Vec::with_capacity(block_size);
+
+    write_header(&mut frame, block_size)?;
+    frame.extend_from_slice(&cdata);
+    write_trailer(&mut frame, crc32, r#isize)?;
+
+    Ok(frame)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+    use crate::{Reader, Writer};
+
+    fn build_data() -> Vec<u8> {
+        (0..8)
+            .flat_map(|i| format!("noodles-bgzf-{}\n", i).repeat(4096).into_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn test_self() -> io::Result<()> {
+        let data = build_data();
+
+        let mut expected_writer = Writer::new(Vec::new());
+        expected_writer.write_all(&data)?;
+        let expected = expected_writer.finish()?;
+
+        for worker_count in [1, 2, 4] {
+            let mut writer = MultithreadedWriter::builder(Vec::new())
+                .set_worker_count(worker_count)
+                .build();
+
+            writer.write_all(&data)?;
+            let actual = writer.finish()?;
+
+            assert_eq!(actual, expected);
+
+            let mut reader = Reader::new(&actual[..]);
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf)?;
+
+            assert_eq!(buf, data);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_virtual_position() -> io::Result<()> {
+        let mut expected_writer = Writer::new(Vec::new());
+        let mut writer = MultithreadedWriter::builder(Vec::new())
+            .set_worker_count(2)
+            .build();
+
+        let mut positions = Vec::new();
+
+        for i in 0..8192 {
+            positions.push((
+                writer.virtual_position(),
+                expected_writer.virtual_position(),
+            ));
+
+            let line = format!("noodles-bgzf-{}\n", i);
+            expected_writer.write_all(line.as_bytes())?;
+            writer.write_all(line.as_bytes())?;
+        }
+
+        // The current block still has buffered data, so its position is unresolved.
+        assert!(positions
+            .iter()
+            .any(|(position, _)| position.get().is_none()));
+
+        writer.flush()?;
+        expected_writer.flush()?;
+
+        positions.push((
+            writer.virtual_position(),
+            expected_writer.virtual_position(),
+        ));
+
+        for (actual, expected) in positions {
+            assert_eq!(actual.get(), Some(expected));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish() -> io::Result<()> {
+        let mut writer = MultithreadedWriter::new(Vec::new());
+        writer.write_all(b"noodles")?;
+
+        let data = writer.finish()?;
+        let eof_start = data.len() - BGZF_EOF.len();
+
+        assert_eq!(&data[eof_start..], BGZF_EOF);
+
+        Ok(())
+    }
+}
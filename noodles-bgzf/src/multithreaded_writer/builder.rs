@@ -0,0 +1,72 @@
+use std::io::Write;
+
+use super::MultithreadedWriter;
+use crate::writer::CompressionLevel;
+
+/// A multithreaded BGZF writer builder.
+#[derive(Debug)]
+pub struct Builder<W> {
+    inner: W,
+    compression_level: Option<CompressionLevel>,
+    worker_count: Option<usize>,
+}
+
+impl<W> Builder<W>
+where
+    W: Write,
+{
+    pub(crate) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            compression_level: None,
+            worker_count: None,
+        }
+    }
+
+    /// Sets a compression level.
+    ///
+    /// By default, the compression level is set to level 6.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, writer::CompressionLevel};
+    ///
+    /// let builder = bgzf::MultithreadedWriter::builder(Vec::new())
+    ///     .set_compression_level(CompressionLevel::best());
+    /// ```
+    pub fn set_compression_level(mut self, compression_level: CompressionLevel) -> Self {
+        self.compression_level = Some(compression_level);
+        self
+    }
+
+    /// Sets a worker count.
+    ///
+    /// By default, the worker count is set to the number of available logical CPUs. A worker
+    /// count of 0 is treated as 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let builder = bgzf::MultithreadedWriter::builder(Vec::new()).set_worker_count(8);
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: usize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
+    /// Builds a multithreaded BGZF writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let writer = bgzf::MultithreadedWriter::builder(Vec::new()).build();
+    /// ```
+    pub fn build(self) -> MultithreadedWriter<W> {
+        let compression_level = self.compression_level.unwrap_or_default();
+        let worker_count = self.worker_count.unwrap_or_else(num_cpus::get).max(1);
+        MultithreadedWriter::with_worker_count(worker_count, compression_level, self.inner)
+    }
+}
@@ -0,0 +1,95 @@
+//! Deferred BGZF virtual position.
+
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+use crate::VirtualPosition;
+
+const UNRESOLVED: u64 = u64::MAX;
+
+// The compressed position of the start of a block, set once all preceding blocks are written.
+#[derive(Debug)]
+pub(super) struct BlockPosition(AtomicU64);
+
+impl BlockPosition {
+    pub(super) fn unresolved() -> Self {
+        Self(AtomicU64::new(UNRESOLVED))
+    }
+
+    pub(super) fn resolved(position: u64) -> Self {
+        Self(AtomicU64::new(position))
+    }
+
+    pub(super) fn resolve(&self, position: u64) {
+        self.0.store(position, Ordering::Release);
+    }
+
+    fn get(&self) -> Option<u64> {
+        match self.0.load(Ordering::Acquire) {
+            UNRESOLVED => None,
+            n => Some(n),
+        }
+    }
+}
+
+/// A deferred BGZF virtual position.
+///
+/// This is a virtual position of a multithreaded BGZF writer. It is a reference to a block and
+/// an uncompressed position in that block. The compressed position of the block is only known
+/// once all blocks staged before it are compressed and written, at which point the virtual
+/// position is resolved.
+#[derive(Clone, Debug)]
+pub struct DeferredVirtualPosition {
+    block_position: Arc<BlockPosition>,
+    uncompressed_position: u16,
+}
+
+impl DeferredVirtualPosition {
+    pub(super) fn new(block_position: Arc<BlockPosition>, uncompressed_position: u16) -> Self {
+        Self {
+            block_position,
+            uncompressed_position,
+        }
+    }
+
+    /// Returns the uncompressed position in the block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let writer = bgzf::MultithreadedWriter::new(Vec::new());
+    /// assert_eq!(writer.virtual_position().uncompressed(), 0);
+    /// ```
+    pub fn uncompressed(&self) -> u16 {
+        self.uncompressed_position
+    }
+
+    /// Returns the virtual position, if it is resolved.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the stream flushed >= 256 TiB of compressed data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::MultithreadedWriter::new(Vec::new());
+    /// writer.write_all(b"noodles")?;
+    /// let position = writer.virtual_position();
+    ///
+    /// writer.flush()?;
+    /// assert_eq!(position.get(), Some(bgzf::VirtualPosition::from(7)));
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn get(&self) -> Option<VirtualPosition> {
+        self.block_position.get().map(|compressed_position| {
+            VirtualPosition::try_from((compressed_position, self.uncompressed_position)).unwrap()
+        })
+    }
+}
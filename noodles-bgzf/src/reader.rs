@@ -1,8 +1,11 @@
 pub(crate) mod block;
 
-use std::io::{self, BufRead, Read, Seek, SeekFrom};
+use std::{
+    cmp,
+    io::{self, BufRead, Read, Seek, SeekFrom},
+};
 
-use super::{Block, VirtualPosition, BGZF_MAX_ISIZE};
+use super::{gzi, Block, VirtualPosition, BGZF_MAX_ISIZE};
 
 /// A BGZF reader.
 ///
@@ -166,6 +169,60 @@ where
 
         Ok(pos)
     }
+
+    /// Seeks the stream to the given uncompressed position.
+    ///
+    /// The given gzip index (GZI) is used to find the nearest block that starts at or before the
+    /// uncompressed position. The stream is then moved to that block, and data is discarded until
+    /// the uncompressed position is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor, Read, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"noodles-bgzf")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let index = Vec::new();
+    ///
+    /// let mut reader = bgzf::Reader::new(Cursor::new(data));
+    /// reader.seek_by_uncompressed_position(&index, 8)?;
+    ///
+    /// let mut buf = Vec::new();
+    /// reader.read_to_end(&mut buf)?;
+    /// assert_eq!(buf, b"bgzf");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn seek_by_uncompressed_position(
+        &mut self,
+        index: &gzi::Index,
+        pos: u64,
+    ) -> io::Result<u64> {
+        let (cpos, upos) = gzi::find_block_start(index, pos);
+
+        let virtual_position = VirtualPosition::try_from((cpos, 0))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        self.seek(virtual_position)?;
+
+        let mut remaining = pos - upos;
+
+        while remaining > 0 {
+            let buf = self.fill_buf()?;
+
+            if buf.is_empty() {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+            }
+
+            let amt = cmp::min(buf.len() as u64, remaining);
+            self.consume(amt as usize);
+            remaining -= amt;
+        }
+
+        Ok(pos)
+    }
 }
 
 impl<R> Read for Reader<R>
@@ -307,4 +364,49 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_seek_by_uncompressed_position() -> io::Result<()> {
+        use std::io::Write;
+
+        use crate::Writer;
+
+        let mut writer = Writer::builder(Vec::new()).build_gzi_index(true).build();
+
+        for i in 0..4 {
+            writer.write_all(format!("noodles-{}", i).as_bytes())?;
+            writer.flush()?;
+        }
+
+        writer.try_finish()?;
+
+        let index = writer.gzi_index().cloned().unwrap();
+        assert_eq!(index.len(), 3);
+
+        let data = writer.into_inner();
+        let mut reader = Reader::new(Cursor::new(data));
+
+        let mut buf = Vec::new();
+
+        for (pos, expected) in [
+            (0, &b"noodles-0noodles-1noodles-2noodles-3"[..]),
+            (3, b"dles-0noodles-1noodles-2noodles-3"),
+            (9, b"noodles-1noodles-2noodles-3"),
+            (25, b"-2noodles-3"),
+            (36, b""),
+        ] {
+            assert_eq!(reader.seek_by_uncompressed_position(&index, pos)?, pos);
+
+            buf.clear();
+            reader.read_to_end(&mut buf)?;
+            assert_eq!(buf, expected);
+        }
+
+        assert!(matches!(
+            reader.seek_by_uncompressed_position(&index, 37),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@ use flate2::Crc;
 
 use crate::{gz, Block, BGZF_HEADER_SIZE};
 
-fn read_frame<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<Option<()>>
+pub(crate) fn read_frame<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<Option<()>>
 where
     R: Read,
 {
@@ -13,7 +13,7 @@ use std::{
 use byteorder::{LittleEndian, WriteBytesExt};
 use flate2::Crc;
 
-use super::{gz, VirtualPosition, BGZF_HEADER_SIZE, BGZF_MAX_ISIZE};
+use super::{gz, gzi, VirtualPosition, BGZF_HEADER_SIZE, BGZF_MAX_ISIZE};
 
 // The max DEFLATE overhead for 65536 bytes of data at compression level 0.
 //
@@ -83,6 +83,8 @@ where
     position: u64,
     buf: Vec<u8>,
     compression_level: CompressionLevelImpl,
+    uncompressed_position: u64,
+    gzi_index: Option<gzi::Index>,
 }
 
 impl<W> Writer<W>
@@ -172,6 +174,31 @@ where
         VirtualPosition::try_from((self.position, uncompressed_position)).unwrap()
     }
 
+    /// Returns the gzip index (GZI) built from the blocks written so far.
+    ///
+    /// This is only available when the writer is built with [`Builder::build_gzi_index`]. To
+    /// index all blocks, call [`Self::try_finish`] first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::builder(Vec::new())
+    ///     .build_gzi_index(true)
+    ///     .build();
+    ///
+    /// writer.write_all(b"noodles-bgzf")?;
+    /// writer.try_finish()?;
+    ///
+    /// assert_eq!(writer.gzi_index(), Some(&Vec::new()));
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn gzi_index(&self) -> Option<&gzi::Index> {
+        self.gzi_index.as_ref()
+    }
+
     fn flush_block(&mut self) -> io::Result<()> {
         let (cdata, crc32, r#isize) = deflate_data(&self.buf, self.compression_level)?;
 
@@ -182,7 +209,14 @@ where
         inner.write_all(&cdata[..])?;
         write_trailer(inner, crc32, r#isize)?;
 
+        if let Some(index) = self.gzi_index.as_mut() {
+            if self.position > 0 {
+                index.push((self.position, self.uncompressed_position));
+            }
+        }
+
         self.position += block_size as u64;
+        self.uncompressed_position += self.buf.len() as u64;
 
         self.buf.clear();
 
@@ -275,7 +309,7 @@ where
     }
 }
 
-fn write_header<W>(writer: &mut W, block_size: usize) -> io::Result<()>
+pub(crate) fn write_header<W>(writer: &mut W, block_size: usize) -> io::Result<()>
 where
     W: Write,
 {
@@ -298,7 +332,11 @@ where
     Ok(())
 }
 
-fn write_trailer<W>(writer: &mut W, checksum: u32, uncompressed_size: u32) -> io::Result<()>
+pub(crate) fn write_trailer<W>(
+    writer: &mut W,
+    checksum: u32,
+    uncompressed_size: u32,
+) -> io::Result<()>
 where
     W: Write,
 {
@@ -377,6 +415,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_gzi_index() -> io::Result<()> {
+        let mut writer = Writer::builder(Vec::new()).build_gzi_index(true).build();
+
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let position = writer.position();
+        writer.write_all(b"-bgzf")?;
+        writer.try_finish()?;
+
+        assert_eq!(writer.gzi_index(), Some(&vec![(position, 7)]));
+
+        let writer = Writer::new(Vec::new());
+        assert!(writer.gzi_index().is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_finish() -> io::Result<()> {
         let mut writer = Writer::new(Vec::new());
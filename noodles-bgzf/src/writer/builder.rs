@@ -7,6 +7,7 @@ use super::{CompressionLevel, Writer, DEFAULT_BUF_SIZE};
 pub struct Builder<W> {
     inner: W,
     compression_level: Option<CompressionLevel>,
+    build_gzi_index: bool,
 }
 
 impl<W> Builder<W>
@@ -17,6 +18,7 @@ where
         Self {
             inner,
             compression_level: None,
+            build_gzi_index: false,
         }
     }
 
@@ -37,6 +39,23 @@ where
         self
     }
 
+    /// Sets whether to build a gzip index (GZI) while writing.
+    ///
+    /// The index can be retrieved using [`Writer::gzi_index`].
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let builder = bgzf::Writer::builder(Vec::new()).build_gzi_index(true);
+    /// ```
+    pub fn build_gzi_index(mut self, value: bool) -> Self {
+        self.build_gzi_index = value;
+        self
+    }
+
     /// Builds an async BGZF writer.
     ///
     /// # Examples
@@ -53,6 +72,8 @@ where
             position: 0,
             buf: Vec::with_capacity(DEFAULT_BUF_SIZE),
             compression_level: compression_level.into(),
+            uncompressed_position: 0,
+            gzi_index: self.build_gzi_index.then(Vec::new),
         }
     }
 }
@@ -1,5 +1,8 @@
 pub mod aac;
+mod encoder;
 pub mod fqzcomp;
 pub mod name_tokenizer;
 pub mod rans;
 pub mod rans_nx16;
+
+pub use self::encoder::Encoder;
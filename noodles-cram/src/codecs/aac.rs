@@ -1,14 +1,14 @@
+mod encode;
 mod flags;
 mod model;
 mod range_coder;
 
-pub use self::{model::Model, range_coder::RangeCoder};
+pub use self::{encode::arith_encode, flags::Flags, model::Model, range_coder::RangeCoder};
 
 use std::io::{self, Read};
 
 use byteorder::ReadBytesExt;
 
-use self::flags::Flags;
 use super::rans_nx16::{decode_pack, decode_pack_meta};
 use crate::reader::num::read_uint7;
 
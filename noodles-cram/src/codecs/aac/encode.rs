@@ -0,0 +1,331 @@
+use std::{
+    borrow::Cow,
+    io::{self, Write},
+};
+
+use byteorder::WriteBytesExt;
+
+use super::{Flags, Model, RangeCoder};
+use crate::{
+    codecs::rans_nx16::{encode_pack, write_pack_meta},
+    writer::num::write_uint7,
+};
+
+const STRIPE_COUNT: usize = 4;
+
+pub fn arith_encode(flags: Flags, src: &[u8]) -> io::Result<Vec<u8>> {
+    let mut dst = Vec::new();
+
+    if flags.contains(Flags::STRIPE) && !src.is_empty() {
+        encode_stripe(&mut dst, flags, src)?;
+    } else {
+        let (flags, body) = encode_body(flags, src)?;
+        write_header(&mut dst, flags, src.len())?;
+        dst.extend(body);
+    }
+
+    Ok(dst)
+}
+
+fn write_header<W>(writer: &mut W, flags: Flags, len: usize) -> io::Result<()>
+where
+    W: Write,
+{
+    writer.write_u8(flags.bits())?;
+
+    if !flags.contains(Flags::NO_SIZE) {
+        let n = u32::try_from(len).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        write_uint7(writer, n)?;
+    }
+
+    Ok(())
+}
+
+fn encode_body(mut flags: Flags, src: &[u8]) -> io::Result<(Flags, Vec<u8>)> {
+    flags.remove(Flags::STRIPE);
+
+    if flags.contains(Flags::EXT) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "external compression is not supported",
+        ));
+    }
+
+    if src.is_empty() {
+        return Ok((uncompressed_flags(flags), Vec::new()));
+    }
+
+    let mut body = Vec::new();
+    let mut data = Cow::from(src);
+
+    if flags.contains(Flags::PACK) {
+        match encode_pack(&data) {
+            Some((symbols, packed_data)) => {
+                write_pack_meta(&mut body, &symbols, packed_data.len())?;
+
+                if packed_data.is_empty() {
+                    flags.insert(Flags::CAT);
+                }
+
+                data = Cow::from(packed_data);
+            }
+            None => flags.remove(Flags::PACK),
+        }
+    }
+
+    if flags.contains(Flags::CAT) {
+        body.extend(data.iter());
+    } else if flags.contains(Flags::RLE) {
+        if flags.contains(Flags::ORDER) {
+            encode_rle_1(&mut body, &data)?;
+        } else {
+            encode_rle_0(&mut body, &data)?;
+        }
+    } else if flags.contains(Flags::ORDER) {
+        encode_order_1(&mut body, &data)?;
+    } else {
+        encode_order_0(&mut body, &data)?;
+    }
+
+    if body.len() > src.len() {
+        Ok((uncompressed_flags(flags), src.to_vec()))
+    } else {
+        Ok((flags, body))
+    }
+}
+
+fn uncompressed_flags(flags: Flags) -> Flags {
+    (flags & Flags::NO_SIZE) | Flags::CAT
+}
+
+fn encode_stripe(dst: &mut Vec<u8>, flags: Flags, src: &[u8]) -> io::Result<()> {
+    let mut chunks = vec![Vec::new(); STRIPE_COUNT];
+
+    for (i, &b) in src.iter().enumerate() {
+        chunks[i % STRIPE_COUNT].push(b);
+    }
+
+    let chunk_flags = (flags - Flags::STRIPE) | Flags::NO_SIZE;
+
+    let compressed_chunks = chunks
+        .iter()
+        .map(|chunk| arith_encode(chunk_flags, chunk))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    write_header(dst, flags, src.len())?;
+
+    dst.write_u8(STRIPE_COUNT as u8)?;
+
+    for chunk in &compressed_chunks {
+        let clen = u32::try_from(chunk.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        write_uint7(dst, clen)?;
+    }
+
+    for chunk in compressed_chunks {
+        dst.extend(chunk);
+    }
+
+    Ok(())
+}
+
+fn write_max_sym<W>(writer: &mut W, src: &[u8]) -> io::Result<u8>
+where
+    W: Write,
+{
+    let max_sym = src.iter().copied().max().unwrap_or(0);
+    // A symbol count of 256 overflows to 0.
+    writer.write_u8(max_sym.wrapping_add(1))?;
+    Ok(max_sym)
+}
+
+fn encode_rle_0<W>(writer: &mut W, src: &[u8]) -> io::Result<()>
+where
+    W: Write,
+{
+    let max_sym = write_max_sym(writer, src)?;
+
+    let mut model_lit = Model::new(max_sym);
+    let mut model_run = vec![Model::new(3); 258];
+
+    let mut range_coder = RangeCoder::default();
+
+    let mut i = 0;
+
+    while i < src.len() {
+        let b = src[i];
+        model_lit.encode(writer, &mut range_coder, b)?;
+
+        let run = src[i + 1..].iter().take_while(|&&c| c == b).count();
+        encode_run(
+            writer,
+            &mut range_coder,
+            &mut model_run,
+            usize::from(b),
+            run,
+        )?;
+
+        i += run + 1;
+    }
+
+    range_coder.range_encode_end(writer)
+}
+
+fn encode_rle_1<W>(writer: &mut W, src: &[u8]) -> io::Result<()>
+where
+    W: Write,
+{
+    let max_sym = write_max_sym(writer, src)?;
+
+    let mut model_lit = vec![Model::new(max_sym); usize::from(max_sym) + 1];
+    let mut model_run = vec![Model::new(3); 258];
+
+    let mut range_coder = RangeCoder::default();
+
+    let mut i = 0;
+    let mut last = 0;
+
+    while i < src.len() {
+        let b = src[i];
+        model_lit[last].encode(writer, &mut range_coder, b)?;
+        last = usize::from(b);
+
+        let run = src[i + 1..].iter().take_while(|&&c| c == b).count();
+        encode_run(writer, &mut range_coder, &mut model_run, last, run)?;
+
+        i += run + 1;
+    }
+
+    range_coder.range_encode_end(writer)
+}
+
+fn encode_run<W>(
+    writer: &mut W,
+    range_coder: &mut RangeCoder,
+    model_run: &mut [Model],
+    sym: usize,
+    mut run: usize,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut rctx = sym;
+
+    loop {
+        let part = run.min(3);
+        model_run[rctx].encode(writer, range_coder, part as u8)?;
+        run -= part;
+
+        if part < 3 {
+            break;
+        }
+
+        rctx = if rctx == sym { 256 } else { 257 };
+    }
+
+    Ok(())
+}
+
+fn encode_order_0<W>(writer: &mut W, src: &[u8]) -> io::Result<()>
+where
+    W: Write,
+{
+    let max_sym = write_max_sym(writer, src)?;
+
+    let mut model = Model::new(max_sym);
+
+    let mut range_coder = RangeCoder::default();
+
+    for &b in src {
+        model.encode(writer, &mut range_coder, b)?;
+    }
+
+    range_coder.range_encode_end(writer)
+}
+
+fn encode_order_1<W>(writer: &mut W, src: &[u8]) -> io::Result<()>
+where
+    W: Write,
+{
+    let max_sym = write_max_sym(writer, src)?;
+
+    let mut models = vec![Model::new(max_sym); usize::from(max_sym) + 1];
+
+    let mut range_coder = RangeCoder::default();
+
+    let mut last = 0;
+
+    for &b in src {
+        models[last].encode(writer, &mut range_coder, b)?;
+        last = usize::from(b);
+    }
+
+    range_coder.range_encode_end(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codecs::aac::arith_decode;
+
+    #[test]
+    fn test_self() -> io::Result<()> {
+        let srcs: [&[u8]; 5] = [
+            b"",
+            b"n",
+            b"noodles",
+            b"noooooooodles",
+            b"AAAAAAAAAAAACCCCCCCCGGGGGGGGGGGGTTTTTTTTTTTTTTTTTT",
+        ];
+
+        let flag_sets = [
+            Flags::empty(),
+            Flags::ORDER,
+            Flags::RLE,
+            Flags::ORDER | Flags::RLE,
+            Flags::CAT,
+            Flags::PACK,
+            Flags::PACK | Flags::ORDER,
+            Flags::STRIPE,
+            Flags::STRIPE | Flags::ORDER,
+        ];
+
+        for src in srcs {
+            for flags in flag_sets {
+                let data = arith_encode(flags, src)?;
+                let mut reader = &data[..];
+                assert_eq!(arith_decode(&mut reader, 0)?, src, "{:?}", flags);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_order_0() -> io::Result<()> {
+        let mut actual = Vec::new();
+        encode_order_0(&mut actual, b"noodles")?;
+
+        let expected = [
+            0x74, 0x00, 0xf4, 0xe5, 0xb7, 0x4e, 0x50, 0x0f, 0x2e, 0x97, 0x00,
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_order_1() -> io::Result<()> {
+        let mut actual = Vec::new();
+        encode_order_1(&mut actual, b"noodles")?;
+
+        let expected = [
+            0x74, 0x00, 0xf4, 0xe3, 0x83, 0x41, 0xe2, 0x9a, 0xef, 0x53, 0x50, 0x00,
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use std::io::{self, Write};
+
+use bzip2::write::BzEncoder;
+use flate2::write::GzEncoder;
+use xz2::write::XzEncoder;
+
+use super::{
+    aac::{self, arith_encode},
+    fqzcomp::fqz_encode,
+    name_tokenizer::encode_names,
+    rans::{self, rans_encode},
+    rans_nx16::{self, rans_encode_nx16},
+};
+use crate::container::block::CompressionMethod;
+
+/// A block data encoder.
+#[derive(Clone, Debug)]
+pub enum Encoder {
+    Gzip(flate2::Compression),
+    Bzip2(bzip2::Compression),
+    Lzma(u32),
+    Rans4x8(rans::Order),
+    RansNx16(rans_nx16::Flags),
+    AdaptiveArithmeticCoding(aac::Flags),
+    Fqzcomp,
+    NameTokenizer,
+}
+
+impl Encoder {
+    pub fn compression_method(&self) -> CompressionMethod {
+        match self {
+            Self::Gzip(_) => CompressionMethod::Gzip,
+            Self::Bzip2(_) => CompressionMethod::Bzip2,
+            Self::Lzma(_) => CompressionMethod::Lzma,
+            Self::Rans4x8(_) => CompressionMethod::Rans4x8,
+            Self::RansNx16(_) => CompressionMethod::RansNx16,
+            Self::AdaptiveArithmeticCoding(_) => CompressionMethod::AdaptiveArithmeticCoding,
+            Self::Fqzcomp => CompressionMethod::Fqzcomp,
+            Self::NameTokenizer => CompressionMethod::NameTokenizer,
+        }
+    }
+
+    /// Encodes the given data.
+    ///
+    /// fqzcomp treats the input as a single record. Use [`fqz_encode`] directly to set record
+    /// boundaries.
+    pub fn encode(&self, src: &[u8]) -> io::Result<Vec<u8>> {
+        match self {
+            Self::Gzip(compression_level) => {
+                let mut encoder = GzEncoder::new(Vec::new(), *compression_level);
+                encoder.write_all(src)?;
+                encoder.finish()
+            }
+            Self::Bzip2(compression_level) => {
+                let mut encoder = BzEncoder::new(Vec::new(), *compression_level);
+                encoder.write_all(src)?;
+                encoder.finish()
+            }
+            Self::Lzma(compression_level) => {
+                let mut encoder = XzEncoder::new(Vec::new(), *compression_level);
+                encoder.write_all(src)?;
+                encoder.finish()
+            }
+            Self::Rans4x8(order) => rans_encode(*order, src),
+            Self::RansNx16(flags) => rans_encode_nx16(*flags, src),
+            Self::AdaptiveArithmeticCoding(flags) => arith_encode(*flags, src),
+            Self::Fqzcomp => fqz_encode(&[src.len()], src),
+            Self::NameTokenizer => encode_names(src),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container::Block;
+
+    #[test]
+    fn test_encode() -> io::Result<()> {
+        let src = b"noodles\0";
+
+        let encoders = [
+            Encoder::Gzip(flate2::Compression::default()),
+            Encoder::Bzip2(bzip2::Compression::default()),
+            Encoder::Lzma(6),
+            Encoder::Rans4x8(rans::Order::One),
+            Encoder::RansNx16(rans_nx16::Flags::ORDER),
+            Encoder::AdaptiveArithmeticCoding(aac::Flags::ORDER),
+            Encoder::Fqzcomp,
+            Encoder::NameTokenizer,
+        ];
+
+        for encoder in encoders {
+            let data = encoder.encode(src)?;
+
+            let block = Block::builder()
+                .set_compression_method(encoder.compression_method())
+                .set_content_type(crate::container::block::ContentType::ExternalData)
+                .set_uncompressed_len(src.len())
+                .set_data(data.into())
+                .build();
+
+            assert_eq!(&block.decompressed_data()?[..], &src[..], "{:?}", encoder);
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,9 @@
+mod encode;
 mod parameter;
 mod parameters;
 
+pub use self::encode::fqz_encode;
+
 use std::{
     cmp,
     io::{self, Read},
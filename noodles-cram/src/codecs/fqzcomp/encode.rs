@@ -0,0 +1,326 @@
+use std::io::{self, Write};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use super::{
+    fqz_update_context,
+    parameter::{self, Parameter},
+    parameters, Record,
+};
+use crate::{
+    codecs::aac::{Model, RangeCoder},
+    writer::num::write_uint7,
+};
+
+const VERSION: u8 = 5;
+
+const Q_BITS: u8 = 10;
+const Q_SHIFT: u8 = 5;
+const Q_LOC: u8 = 0;
+const P_LOC: u8 = 10;
+const D_LOC: u8 = 14;
+const S_LOC: u8 = 15;
+
+pub fn fqz_encode(lens: &[usize], src: &[u8]) -> io::Result<Vec<u8>> {
+    let lens: Vec<_> = lens.iter().copied().filter(|&len| len > 0).collect();
+
+    if lens.iter().sum::<usize>() != src.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "record lengths do not match the quality scores length",
+        ));
+    }
+
+    let mut dst = Vec::new();
+
+    let len =
+        u32::try_from(src.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    write_uint7(&mut dst, len)?;
+
+    let mut param = build_parameter(&lens, src);
+    fqz_encode_params(&mut dst, &param)?;
+
+    let mut models = Models::new(&param);
+    let mut range_coder = RangeCoder::default();
+
+    let mut record = Record::default();
+    let mut i = 0;
+
+    for &len in &lens {
+        if param.flags.contains(parameter::Flags::DO_LEN) || param.first_len > 0 {
+            encode_length(&mut dst, &mut range_coder, &mut models, len)?;
+
+            if !param.flags.contains(parameter::Flags::DO_LEN) {
+                param.first_len = 0;
+            }
+        }
+
+        record.rec += 1;
+        record.rec_len = len;
+        record.pos = len;
+        record.qctx = 0;
+        record.delta = 0;
+        record.prevq = 0;
+
+        let mut ctx = param.context;
+
+        for &q in &src[i..i + len] {
+            models.qual[usize::from(ctx)].encode(&mut dst, &mut range_coder, q)?;
+            ctx = fqz_update_context(&mut param, q, &mut record);
+            record.pos -= 1;
+        }
+
+        i += len;
+    }
+
+    range_coder.range_encode_end(&mut dst)?;
+
+    Ok(dst)
+}
+
+struct Models {
+    len: Vec<Model>,
+    qual: Vec<Model>,
+}
+
+impl Models {
+    fn new(param: &Parameter) -> Self {
+        Self {
+            len: vec![Model::new(u8::MAX); 4],
+            qual: vec![Model::new(param.max_sym); max_context(param) + 1],
+        }
+    }
+}
+
+// Returns the largest context `fqz_update_context` can produce with the given parameter.
+fn max_context(param: &Parameter) -> usize {
+    const MAX_CONTEXT: usize = (1 << 16) - 1;
+
+    fn max_value(table: &[u8]) -> usize {
+        table.iter().copied().max().map(usize::from).unwrap_or(0)
+    }
+
+    // The selector is not bounded by the parameter.
+    if param.flags.contains(parameter::Flags::DO_SEL) {
+        return MAX_CONTEXT;
+    }
+
+    let mut ctx = usize::from(param.context);
+    ctx += ((1 << param.q_bits) - 1) << param.q_loc;
+
+    if param.flags.contains(parameter::Flags::HAVE_PTAB) {
+        ctx += max_value(&param.p_tab) << param.p_loc;
+    }
+
+    if param.flags.contains(parameter::Flags::HAVE_DTAB) {
+        ctx += max_value(&param.d_tab) << param.d_loc;
+    }
+
+    ctx.min(MAX_CONTEXT)
+}
+
+fn build_parameter(lens: &[usize], src: &[u8]) -> Parameter {
+    let mut flags = parameter::Flags::HAVE_PTAB | parameter::Flags::HAVE_DTAB;
+
+    if lens.windows(2).any(|w| w[0] != w[1]) {
+        flags.insert(parameter::Flags::DO_LEN);
+    }
+
+    let max_sym = src.iter().copied().max().unwrap_or(0);
+
+    let q_tab = (0..=u8::MAX).collect();
+
+    // Positions are bucketed into 16 bins of 8 bases.
+    let p_tab = (0..1024).map(|i| (i / 8).min(15) as u8).collect();
+
+    let d_tab = (0..256)
+        .map(|i| match i {
+            0 => 0,
+            1 => 1,
+            2..=3 => 2,
+            _ => 3,
+        })
+        .collect();
+
+    Parameter {
+        context: 0,
+        flags,
+        max_sym,
+        first_len: 1,
+        last_len: 0,
+        q_bits: Q_BITS,
+        q_shift: Q_SHIFT,
+        q_loc: Q_LOC,
+        s_loc: S_LOC,
+        p_loc: P_LOC,
+        d_loc: D_LOC,
+        q_map: Vec::new(),
+        q_tab,
+        p_tab,
+        d_tab,
+    }
+}
+
+fn fqz_encode_params<W>(writer: &mut W, param: &Parameter) -> io::Result<()>
+where
+    W: Write,
+{
+    writer.write_u8(VERSION)?;
+    writer.write_u8(parameters::Flags::empty().bits())?;
+    fqz_encode_single_param(writer, param)
+}
+
+fn fqz_encode_single_param<W>(writer: &mut W, param: &Parameter) -> io::Result<()>
+where
+    W: Write,
+{
+    writer.write_u16::<LittleEndian>(param.context)?;
+    writer.write_u8(param.flags.bits())?;
+    writer.write_u8(param.max_sym)?;
+
+    writer.write_u8(param.q_bits << 4 | param.q_shift)?;
+    writer.write_u8(param.q_loc << 4 | param.s_loc)?;
+    writer.write_u8(param.p_loc << 4 | param.d_loc)?;
+
+    if param.flags.contains(parameter::Flags::HAVE_QMAP) {
+        writer.write_all(&param.q_map)?;
+    }
+
+    if param.flags.contains(parameter::Flags::HAVE_QTAB) {
+        write_array(writer, &param.q_tab)?;
+    }
+
+    if param.flags.contains(parameter::Flags::HAVE_PTAB) {
+        write_array(writer, &param.p_tab)?;
+    }
+
+    if param.flags.contains(parameter::Flags::HAVE_DTAB) {
+        write_array(writer, &param.d_tab)?;
+    }
+
+    Ok(())
+}
+
+fn write_array<W>(writer: &mut W, a: &[u8]) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut runs = Vec::new();
+    let mut i = 0;
+    let mut value = 0;
+
+    while i < a.len() {
+        let mut run_len = a[i..].iter().take_while(|&&b| b == value).count();
+        i += run_len;
+
+        loop {
+            let part = run_len.min(255);
+            runs.push(part as u8);
+            run_len -= part;
+
+            if part < 255 {
+                break;
+            }
+        }
+
+        value += 1;
+    }
+
+    let mut last = None;
+    let mut j = 0;
+
+    while j < runs.len() {
+        let run = runs[j];
+        writer.write_u8(run)?;
+        j += 1;
+
+        if last == Some(run) {
+            let copy = runs[j..].iter().take_while(|&&b| b == run).count().min(255);
+            writer.write_u8(copy as u8)?;
+            j += copy;
+        } else {
+            last = Some(run);
+        }
+    }
+
+    Ok(())
+}
+
+fn encode_length<W>(
+    writer: &mut W,
+    range_coder: &mut RangeCoder,
+    models: &mut Models,
+    len: usize,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    let n = u32::try_from(len).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    for (model, b) in models.len.iter_mut().zip(n.to_le_bytes()) {
+        model.encode(writer, range_coder, b)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codecs::fqzcomp::{fqz_decode, read_array};
+
+    #[test]
+    fn test_self() -> io::Result<()> {
+        let cases: [(&[usize], &[u8]); 4] = [
+            (&[], &[]),
+            (&[7], &[45, 46, 46, 35, 43, 36, 50]),
+            (&[4, 4, 4], &[30, 30, 31, 32, 33, 30, 30, 2, 40, 40, 40, 40]),
+            (&[2, 0, 5], &[10, 11, 12, 12, 12, 12, 13]),
+        ];
+
+        for (lens, src) in cases {
+            let data = fqz_encode(lens, src)?;
+            let mut reader = &data[..];
+            assert_eq!(fqz_decode(&mut reader)?, src);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_context() {
+        let mut param = build_parameter(&[4], &[30, 31, 32, 33]);
+        assert_eq!(max_context(&param), (1 << 16) - 1);
+
+        param.flags = parameter::Flags::empty();
+        assert_eq!(max_context(&param), (1 << Q_BITS) - 1);
+
+        param.flags = parameter::Flags::HAVE_PTAB;
+        assert_eq!(max_context(&param), ((1 << Q_BITS) - 1) + (15 << P_LOC));
+    }
+
+    #[test]
+    fn test_fqz_encode_with_invalid_lengths() {
+        assert!(matches!(
+            fqz_encode(&[2], &[0]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+
+    #[test]
+    fn test_write_array() -> io::Result<()> {
+        let mut a = vec![0; 4];
+        a.extend([1; 300]);
+        a.extend([2, 3, 4, 5, 5]);
+
+        let mut buf = Vec::new();
+        write_array(&mut buf, &a)?;
+
+        assert_eq!(buf, [0x04, 0xff, 0x2d, 0x01, 0x01, 0x01, 0x02]);
+
+        let mut reader = &buf[..];
+        assert_eq!(read_array(&mut reader, a.len())?, a);
+
+        Ok(())
+    }
+}
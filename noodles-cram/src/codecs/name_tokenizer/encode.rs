@@ -0,0 +1,398 @@
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use super::Type;
+use crate::{
+    codecs::rans_nx16::{rans_encode_nx16, Flags},
+    writer::num::write_uint7,
+};
+
+// The decoder reserves 128 token positions per name, including the first (the name type) and the
+// last (the end of the name).
+const MAX_TOKEN_COUNT: usize = 126;
+
+const TYPE_COUNT: usize = 13;
+
+// Unlike the decoder tokens, names are tokenized as bytes, so any byte can be encoded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Token {
+    Char(u8),
+    String(Vec<u8>),
+    Digits(u32),
+    PaddedDigits(u32, u8),
+}
+
+const TYPES: [Type; TYPE_COUNT] = [
+    Type::Type,
+    Type::String,
+    Type::Char,
+    Type::Digits0,
+    Type::DZLen,
+    Type::Dup,
+    Type::Diff,
+    Type::Digits,
+    Type::Delta,
+    Type::Delta0,
+    Type::Match,
+    Type::Nop,
+    Type::End,
+];
+
+const RANS_NX16_FLAG_SETS: [Flags; 6] = [
+    Flags::empty(),
+    Flags::ORDER,
+    Flags::PACK,
+    Flags::RLE,
+    Flags::STRIPE,
+    Flags::PACK.union(Flags::ORDER),
+];
+
+pub fn encode_names(src: &[u8]) -> io::Result<Vec<u8>> {
+    let mut names: Vec<_> = src.split(|&b| b == 0x00).collect();
+
+    if src.last() == Some(&0x00) {
+        names.pop();
+    }
+
+    let ulen = names.iter().map(|name| name.len() + 1).sum::<usize>();
+    let ulen = u32::try_from(ulen).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let n_names =
+        u32::try_from(names.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let b = encode_token_byte_streams(&names)?;
+
+    let mut dst = Vec::new();
+
+    dst.write_u32::<LittleEndian>(ulen)?;
+    dst.write_u32::<LittleEndian>(n_names)?;
+
+    let use_arith = false;
+    dst.write_u8(u8::from(use_arith))?;
+
+    write_token_byte_streams(&mut dst, &b)?;
+
+    Ok(dst)
+}
+
+#[derive(Clone, Debug)]
+struct TokenWriter {
+    bufs: Vec<Vec<u8>>,
+}
+
+impl Default for TokenWriter {
+    fn default() -> Self {
+        Self {
+            bufs: vec![Vec::new(); TYPE_COUNT],
+        }
+    }
+}
+
+impl TokenWriter {
+    fn get(&self, ty: Type) -> &[u8] {
+        &self.bufs[usize::from(ty)]
+    }
+
+    fn get_mut(&mut self, ty: Type) -> &mut Vec<u8> {
+        &mut self.bufs[usize::from(ty)]
+    }
+
+    fn write_type(&mut self, ty: Type) {
+        self.get_mut(Type::Type).push(u8::from(ty));
+    }
+
+    fn write_distance(&mut self, ty: Type, dist: usize) -> io::Result<()> {
+        assert!(matches!(ty, Type::Dup | Type::Diff));
+
+        let n = u32::try_from(dist).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        self.write_type(ty);
+        self.get_mut(ty).write_u32::<LittleEndian>(n)
+    }
+
+    fn write_token(&mut self, token: Option<&Token>, prev_token: Option<&Token>) -> io::Result<()> {
+        let token = match token {
+            Some(token) => token,
+            None => {
+                self.write_type(Type::End);
+                return Ok(());
+            }
+        };
+
+        if Some(token) == prev_token {
+            self.write_type(Type::Match);
+            return Ok(());
+        }
+
+        match (token, prev_token) {
+            (Token::Digits(n), Some(Token::Digits(m))) if delta(*n, *m).is_some() => {
+                self.write_type(Type::Delta);
+                self.get_mut(Type::Delta).extend(delta(*n, *m));
+            }
+            (Token::PaddedDigits(n, width), Some(Token::PaddedDigits(m, prev_width)))
+                if width == prev_width && delta(*n, *m).is_some() =>
+            {
+                self.write_type(Type::Delta0);
+                self.get_mut(Type::Delta0).extend(delta(*n, *m));
+            }
+            (Token::Char(c), _) => {
+                self.write_type(Type::Char);
+                self.get_mut(Type::Char).push(*c);
+            }
+            (Token::String(s), _) => {
+                self.write_type(Type::String);
+
+                let buf = self.get_mut(Type::String);
+                buf.extend(s);
+                buf.push(0x00);
+            }
+            (Token::Digits(n), _) => {
+                self.write_type(Type::Digits);
+                self.get_mut(Type::Digits).write_u32::<LittleEndian>(*n)?;
+            }
+            (Token::PaddedDigits(n, width), _) => {
+                self.write_type(Type::Digits0);
+                self.get_mut(Type::Digits0).write_u32::<LittleEndian>(*n)?;
+                self.get_mut(Type::DZLen).push(*width);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn delta(n: u32, prev_n: u32) -> Option<u8> {
+    n.checked_sub(prev_n).and_then(|d| u8::try_from(d).ok())
+}
+
+fn encode_token_byte_streams(names: &[&[u8]]) -> io::Result<Vec<TokenWriter>> {
+    let mut b = vec![TokenWriter::default()];
+
+    let mut tokens: Vec<Vec<Option<Token>>> = Vec::with_capacity(names.len());
+    let mut indices: HashMap<&[u8], usize> = HashMap::new();
+
+    for (n, &name) in names.iter().enumerate() {
+        if let Some(m) = indices.insert(name, n) {
+            b[0].write_distance(Type::Dup, n - m)?;
+            tokens.push(tokens[m].clone());
+            continue;
+        }
+
+        let dist = if n == 0 { 0 } else { 1 };
+        b[0].write_distance(Type::Diff, dist)?;
+
+        let m = n - dist;
+
+        let name_tokens = tokenize(name);
+        let mut current_tokens = vec![None; name_tokens.len() + 2];
+
+        for t in 1..=name_tokens.len() + 1 {
+            if t >= b.len() {
+                b.push(TokenWriter::default());
+            }
+
+            let token = name_tokens.get(t - 1);
+            let prev_token = tokens
+                .get(m)
+                .and_then(|ts| ts.get(t))
+                .and_then(|t| t.as_ref());
+
+            b[t].write_token(token, prev_token)?;
+
+            current_tokens[t] = token.cloned();
+        }
+
+        tokens.push(current_tokens);
+    }
+
+    Ok(b)
+}
+
+fn tokenize(name: &[u8]) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < name.len() {
+        if tokens.len() == MAX_TOKEN_COUNT - 1 {
+            tokens.push(Token::String(name[i..].to_vec()));
+            break;
+        }
+
+        let b = name[i];
+
+        let len = if b.is_ascii_digit() {
+            name[i..].iter().take_while(|b| b.is_ascii_digit()).count()
+        } else if b.is_ascii_alphabetic() {
+            name[i..]
+                .iter()
+                .take_while(|b| b.is_ascii_alphabetic())
+                .count()
+        } else {
+            1
+        };
+
+        let buf = &name[i..i + len];
+
+        let token = if b.is_ascii_digit() && len <= 9 {
+            // `buf` is at most 9 decimal digits, so this cannot overflow.
+            let n = buf.iter().fold(0, |n, &d| n * 10 + u32::from(d - b'0'));
+
+            if b == b'0' && len > 1 {
+                Token::PaddedDigits(n, len as u8)
+            } else {
+                Token::Digits(n)
+            }
+        } else if len == 1 {
+            Token::Char(b)
+        } else {
+            Token::String(buf.to_vec())
+        };
+
+        tokens.push(token);
+
+        i += len;
+    }
+
+    tokens
+}
+
+fn write_token_byte_streams<W>(writer: &mut W, b: &[TokenWriter]) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut written_streams: Vec<(usize, Type)> = Vec::new();
+
+    for (t, token_writer) in b.iter().enumerate() {
+        for (i, ty) in TYPES.into_iter().enumerate() {
+            let buf = token_writer.get(ty);
+
+            if buf.is_empty() {
+                continue;
+            }
+
+            let mut ttype = u8::from(ty);
+
+            if i == 0 {
+                ttype |= 0x80;
+            }
+
+            let dup = written_streams
+                .iter()
+                .find(|(dup_pos, dup_type)| b[*dup_pos].get(*dup_type) == buf);
+
+            if let Some(&(dup_pos, dup_type)) = dup {
+                writer.write_u8(ttype | 0x40)?;
+                writer.write_u8(dup_pos as u8)?;
+                writer.write_u8(u8::from(dup_type))?;
+            } else {
+                writer.write_u8(ttype)?;
+
+                let data = compress(buf)?;
+
+                let clen = u32::try_from(data.len())
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+                write_uint7(writer, clen)?;
+
+                writer.write_all(&data)?;
+            }
+
+            written_streams.push((t, ty));
+        }
+    }
+
+    Ok(())
+}
+
+fn compress(buf: &[u8]) -> io::Result<Vec<u8>> {
+    let mut best: Option<Vec<u8>> = None;
+
+    for flags in RANS_NX16_FLAG_SETS {
+        let data = rans_encode_nx16(flags, buf)?;
+
+        if best.as_ref().map(|b| data.len() < b.len()).unwrap_or(true) {
+            best = Some(data);
+        }
+    }
+
+    Ok(best.expect("RANS_NX16_FLAG_SETS is not empty"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codecs::name_tokenizer::decode_names;
+
+    #[test]
+    fn test_self() -> io::Result<()> {
+        let src = b"I17_08765:2:123:61541:01763#9\0\
+I17_08765:2:123:1636:08611#9\0\
+I17_08765:2:124:45613:16161#9\0\
+I17_08765:2:123:1636:08611#9\0\
+r0\0\
+r1\0\
+r1\0";
+
+        let data = encode_names(src)?;
+
+        let mut reader = &data[..];
+        let actual = decode_names(&mut reader)?;
+
+        let expected: Vec<_> = src
+            .split(|&b| b == 0x00)
+            .filter(|s| !s.is_empty())
+            .map(|s| String::from_utf8(s.to_vec()).unwrap())
+            .collect();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize(b"I17_08765:2"),
+            [
+                Token::Char(b'I'),
+                Token::Digits(17),
+                Token::Char(b'_'),
+                Token::PaddedDigits(8765, 5),
+                Token::Char(b':'),
+                Token::Digits(2),
+            ]
+        );
+
+        assert_eq!(
+            tokenize(b"SRR0123456789"),
+            [
+                Token::String(b"SRR".to_vec()),
+                Token::String(b"0123456789".to_vec()),
+            ]
+        );
+
+        assert_eq!(
+            tokenize("rñ".as_bytes()),
+            [Token::Char(b'r'), Token::Char(0xc3), Token::Char(0xb1)]
+        );
+    }
+
+    #[test]
+    fn test_encode_names_with_non_ascii_bytes() -> io::Result<()> {
+        let src = b"r\xff0\0r\xff1\0";
+
+        let data = encode_names(src)?;
+
+        let mut reader = &data[..];
+        let actual = decode_names(&mut reader)?;
+
+        // The decoder reads each char token byte as a `char`.
+        let expected = [String::from("r\u{ff}0"), String::from("r\u{ff}1")];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -37,4 +37,19 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_self_1_with_less_than_4_bytes() -> io::Result<()> {
+        let data = b"nd";
+
+        let compressed_data = rans_encode(Order::One, data)?;
+        assert_eq!(compressed_data[0], u8::from(Order::Zero));
+
+        let mut reader = &compressed_data[..];
+        let decompressed_data = rans_decode(&mut reader)?;
+
+        assert_eq!(decompressed_data, data);
+
+        Ok(())
+    }
 }
@@ -104,8 +104,9 @@ where
     Ok(())
 }
 
-pub fn build_cumulative_freqs_symbols_table_1(cumulative_freqs: &[Vec<u32>]) -> [[u8; 4096]; 256] {
-    let mut tables = [[0; 4096]; 256];
+// The tables are heap-allocated because they are too large (1 MiB) to keep on the stack.
+pub fn build_cumulative_freqs_symbols_table_1(cumulative_freqs: &[Vec<u32>]) -> Vec<[u8; 4096]> {
+    let mut tables = vec![[0; 4096]; 256];
 
     for (table, cumulative_freqs) in tables.iter_mut().zip(cumulative_freqs) {
         *table = order_0::build_cumulative_freqs_symbols_table_0(cumulative_freqs);
@@ -13,8 +13,12 @@ const BASE: usize = 256;
 // Lower bound `L`.
 const LOWER_BOUND: u32 = 0x800000;
 
-#[allow(dead_code)]
-pub fn rans_encode(order: Order, data: &[u8]) -> io::Result<Vec<u8>> {
+pub fn rans_encode(mut order: Order, data: &[u8]) -> io::Result<Vec<u8>> {
+    // Order-1 encoding does not support input smaller than 4 bytes.
+    if order == Order::One && data.len() < 4 {
+        order = Order::Zero;
+    }
+
     let compressed_blob = match order {
         Order::Zero => {
             let (normalized_frequencies, compressed_data) = order_0::encode(data)?;
@@ -1,10 +1,14 @@
+mod encode;
 mod flags;
 
+pub use self::{encode::rans_encode_nx16, flags::Flags};
+
+pub(crate) use self::encode::{encode_pack, write_pack_meta};
+
 use std::io::{self, Cursor, Read};
 
 use byteorder::{LittleEndian, ReadBytesExt};
 
-use self::flags::Flags;
 use crate::reader::num::read_uint7;
 
 pub fn rans_decode_nx16<R>(reader: &mut R, mut len: usize) -> io::Result<Vec<u8>>
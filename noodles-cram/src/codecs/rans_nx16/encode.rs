@@ -0,0 +1,693 @@
+use std::{
+    borrow::Cow,
+    io::{self, Write},
+};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use super::Flags;
+use crate::writer::num::write_uint7;
+
+// Lower bound `L`.
+const LOWER_BOUND: u32 = 1 << 15;
+
+const ORDER_0_BITS: u32 = 12;
+const ORDER_1_BITS: u32 = 12;
+
+const STRIPE_COUNT: usize = 4;
+
+pub fn rans_encode_nx16(flags: Flags, src: &[u8]) -> io::Result<Vec<u8>> {
+    let mut dst = Vec::new();
+
+    if flags.contains(Flags::STRIPE) && !src.is_empty() {
+        encode_stripe(&mut dst, flags, src)?;
+    } else {
+        let (flags, body) = encode_body(flags, src)?;
+        write_header(&mut dst, flags, src.len())?;
+        dst.extend(body);
+    }
+
+    Ok(dst)
+}
+
+fn write_header<W>(writer: &mut W, flags: Flags, len: usize) -> io::Result<()>
+where
+    W: Write,
+{
+    writer.write_u8(flags.bits())?;
+
+    if !flags.contains(Flags::NO_SIZE) {
+        let n = u32::try_from(len).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        write_uint7(writer, n)?;
+    }
+
+    Ok(())
+}
+
+fn encode_body(mut flags: Flags, src: &[u8]) -> io::Result<(Flags, Vec<u8>)> {
+    flags.remove(Flags::STRIPE);
+
+    if src.is_empty() {
+        return Ok((uncompressed_flags(flags), Vec::new()));
+    }
+
+    let n = if flags.contains(Flags::N32) { 32 } else { 4 };
+
+    let mut body = Vec::new();
+    let mut data = Cow::from(src);
+
+    if flags.contains(Flags::PACK) {
+        match encode_pack(&data) {
+            Some((symbols, packed_data)) => {
+                write_pack_meta(&mut body, &symbols, packed_data.len())?;
+
+                if packed_data.is_empty() {
+                    flags.insert(Flags::CAT);
+                }
+
+                data = Cow::from(packed_data);
+            }
+            None => flags.remove(Flags::PACK),
+        }
+    }
+
+    if flags.contains(Flags::RLE) {
+        match encode_rle(&data) {
+            Some((meta, literals)) => {
+                write_rle_meta(&mut body, &meta, literals.len(), n)?;
+                data = Cow::from(literals);
+            }
+            None => flags.remove(Flags::RLE),
+        }
+    }
+
+    if flags.contains(Flags::CAT) {
+        body.extend(data.iter());
+    } else if flags.contains(Flags::ORDER) {
+        encode_order_1(&mut body, &data, n)?;
+    } else {
+        encode_order_0(&mut body, &data, n)?;
+    }
+
+    if body.len() > src.len() {
+        Ok((uncompressed_flags(flags), src.to_vec()))
+    } else {
+        Ok((flags, body))
+    }
+}
+
+fn uncompressed_flags(flags: Flags) -> Flags {
+    (flags & Flags::NO_SIZE) | Flags::CAT
+}
+
+fn encode_stripe(dst: &mut Vec<u8>, flags: Flags, src: &[u8]) -> io::Result<()> {
+    let mut chunks = vec![Vec::new(); STRIPE_COUNT];
+
+    for (i, &b) in src.iter().enumerate() {
+        chunks[i % STRIPE_COUNT].push(b);
+    }
+
+    let chunk_flags = (flags - Flags::STRIPE) | Flags::NO_SIZE;
+
+    let compressed_chunks = chunks
+        .iter()
+        .map(|chunk| rans_encode_nx16(chunk_flags, chunk))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    // The number of states is only relevant to the substreams.
+    let header_flags = flags - Flags::N32;
+    write_header(dst, header_flags, src.len())?;
+
+    dst.write_u8(STRIPE_COUNT as u8)?;
+
+    for chunk in &compressed_chunks {
+        let clen = u32::try_from(chunk.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        write_uint7(dst, clen)?;
+    }
+
+    for chunk in compressed_chunks {
+        dst.extend(chunk);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn encode_pack(src: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+    let mut present = [false; 256];
+
+    for &b in src {
+        present[usize::from(b)] = true;
+    }
+
+    let symbols: Vec<u8> = (0..=u8::MAX).filter(|&b| present[usize::from(b)]).collect();
+
+    if symbols.len() > 16 {
+        return None;
+    }
+
+    let mut map = [0; 256];
+
+    for (i, &sym) in symbols.iter().enumerate() {
+        map[usize::from(sym)] = i as u8;
+    }
+
+    let (values_per_byte, bits) = match symbols.len() {
+        0 | 1 => return Some((symbols, Vec::new())),
+        2 => (8, 1),
+        3 | 4 => (4, 2),
+        _ => (2, 4),
+    };
+
+    let packed_data = src
+        .chunks(values_per_byte)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0, |v, (i, &b)| v | (map[usize::from(b)] << (i * bits)))
+        })
+        .collect();
+
+    Some((symbols, packed_data))
+}
+
+pub(crate) fn write_pack_meta<W>(writer: &mut W, symbols: &[u8], len: usize) -> io::Result<()>
+where
+    W: Write,
+{
+    writer.write_u8(symbols.len() as u8)?;
+    writer.write_all(symbols)?;
+
+    let n = u32::try_from(len).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    write_uint7(writer, n)?;
+
+    Ok(())
+}
+
+fn encode_rle(src: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+    // A symbol is worth run-length encoding when it repeats more often than it starts a run.
+    let mut scores = [0i64; 256];
+    let mut prev_sym = None;
+
+    for &sym in src {
+        if prev_sym == Some(sym) {
+            scores[usize::from(sym)] += 1;
+        } else {
+            scores[usize::from(sym)] -= 1;
+        }
+
+        prev_sym = Some(sym);
+    }
+
+    let symbols: Vec<u8> = (0..=u8::MAX)
+        .filter(|&b| scores[usize::from(b)] > 0)
+        .collect();
+
+    if symbols.is_empty() {
+        return None;
+    }
+
+    let mut runs = Vec::new();
+    let mut literals = Vec::new();
+    let mut i = 0;
+
+    while i < src.len() {
+        let sym = src[i];
+        literals.push(sym);
+
+        if scores[usize::from(sym)] > 0 {
+            let run = src[i + 1..].iter().take_while(|&&b| b == sym).count();
+            write_uint7(&mut runs, run as u32).ok()?;
+            i += run;
+        }
+
+        i += 1;
+    }
+
+    let mut meta = Vec::with_capacity(1 + symbols.len() + runs.len());
+    // A symbol count of 256 overflows to 0.
+    meta.push(symbols.len() as u8);
+    meta.extend(&symbols);
+    meta.extend(runs);
+
+    Some((meta, literals))
+}
+
+fn write_rle_meta<W>(writer: &mut W, meta: &[u8], len: usize, n: usize) -> io::Result<()>
+where
+    W: Write,
+{
+    let meta_len =
+        u32::try_from(meta.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let len = u32::try_from(len).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let mut compressed_meta = Vec::new();
+    encode_order_0(&mut compressed_meta, meta, n)?;
+
+    if compressed_meta.len() < meta.len() {
+        write_uint7(writer, meta_len << 1)?;
+        write_uint7(writer, len)?;
+
+        let compressed_meta_len = u32::try_from(compressed_meta.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        write_uint7(writer, compressed_meta_len)?;
+
+        writer.write_all(&compressed_meta)?;
+    } else {
+        write_uint7(writer, (meta_len << 1) | 1)?;
+        write_uint7(writer, len)?;
+        writer.write_all(meta)?;
+    }
+
+    Ok(())
+}
+
+fn encode_order_0<W>(writer: &mut W, src: &[u8], n: usize) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut freqs = vec![0; 256];
+
+    for &sym in src {
+        freqs[usize::from(sym)] += 1;
+    }
+
+    normalize_frequencies(&mut freqs, ORDER_0_BITS);
+    write_frequencies_0(writer, &freqs)?;
+    scale_frequencies(&mut freqs, ORDER_0_BITS);
+
+    let cumulative_freqs = build_cumulative_frequencies(&freqs);
+
+    let mut states = vec![LOWER_BOUND; n];
+    let mut buf = Vec::new();
+
+    for (i, &sym) in src.iter().enumerate().rev() {
+        let j = i % n;
+        let k = usize::from(sym);
+
+        states[j] = rans_encode_symbol(
+            &mut buf,
+            states[j],
+            freqs[k],
+            cumulative_freqs[k],
+            ORDER_0_BITS,
+        );
+    }
+
+    write_states(writer, &states, &buf)
+}
+
+fn encode_order_1<W>(writer: &mut W, src: &[u8], n: usize) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut contexts = vec![vec![0; 256]; 256];
+
+    for (ctx, sym) in order_1_symbols(src, n) {
+        contexts[ctx][sym] += 1;
+    }
+
+    let mut alphabet = [false; 256];
+
+    for (i, freqs) in contexts.iter().enumerate() {
+        for (j, &f) in freqs.iter().enumerate() {
+            if f > 0 {
+                alphabet[i] = true;
+                alphabet[j] = true;
+            }
+        }
+    }
+
+    for freqs in &mut contexts {
+        normalize_frequencies(freqs, ORDER_1_BITS);
+    }
+
+    write_frequencies_1(writer, &contexts, &alphabet, ORDER_1_BITS)?;
+
+    let mut cumulative_contexts = Vec::with_capacity(contexts.len());
+
+    for freqs in &mut contexts {
+        scale_frequencies(freqs, ORDER_1_BITS);
+        cumulative_contexts.push(build_cumulative_frequencies(freqs));
+    }
+
+    let mut states = vec![LOWER_BOUND; n];
+    let mut buf = Vec::new();
+
+    let symbols: Vec<_> = order_1_symbols(src, n).collect();
+    let segment_len = src.len() / n;
+
+    // The last state also encodes the remainder of the input that does not fit evenly in the
+    // segments.
+    for (i, &(ctx, sym)) in symbols.iter().enumerate().rev() {
+        let j = if i < segment_len * n { i % n } else { n - 1 };
+
+        states[j] = rans_encode_symbol(
+            &mut buf,
+            states[j],
+            contexts[ctx][sym],
+            cumulative_contexts[ctx][sym],
+            ORDER_1_BITS,
+        );
+    }
+
+    write_states(writer, &states, &buf)
+}
+
+// Returns (context, symbol) pairs in decoding order, i.e., interleaved by state.
+fn order_1_symbols(src: &[u8], n: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let segment_len = src.len() / n;
+
+    let interleaved = (0..segment_len).flat_map(move |i| {
+        (0..n).map(move |j| {
+            let k = j * segment_len + i;
+            let ctx = if i == 0 { 0 } else { usize::from(src[k - 1]) };
+            (ctx, usize::from(src[k]))
+        })
+    });
+
+    let remainder = (segment_len * n..src.len()).map(move |k| {
+        let ctx = if k == 0 { 0 } else { usize::from(src[k - 1]) };
+        (ctx, usize::from(src[k]))
+    });
+
+    interleaved.chain(remainder)
+}
+
+fn write_states<W>(writer: &mut W, states: &[u32], buf: &[u16]) -> io::Result<()>
+where
+    W: Write,
+{
+    for &state in states {
+        writer.write_u32::<LittleEndian>(state)?;
+    }
+
+    for &n in buf.iter().rev() {
+        writer.write_u16::<LittleEndian>(n)?;
+    }
+
+    Ok(())
+}
+
+fn rans_encode_symbol(buf: &mut Vec<u16>, mut x: u32, freq: u32, cfreq: u32, bits: u32) -> u32 {
+    let x_max = ((LOWER_BOUND >> bits) << 16) * freq;
+
+    if x >= x_max {
+        buf.push((x & 0xffff) as u16);
+        x >>= 16;
+    }
+
+    ((x / freq) << bits) + (x % freq) + cfreq
+}
+
+// Normalizes the frequencies to sum to a power of two that is no larger than `1 << bits`.
+fn normalize_frequencies(freqs: &mut [u32], bits: u32) {
+    let sum: u64 = freqs.iter().map(|&f| u64::from(f)).sum();
+
+    if sum == 0 {
+        return;
+    }
+
+    let total = sum.next_power_of_two().min(1 << bits);
+
+    let mut max = 0;
+    let mut max_index = 0;
+    let mut normalized_sum = 0;
+
+    for (i, f) in freqs.iter_mut().enumerate() {
+        if *f == 0 {
+            continue;
+        }
+
+        if *f > max {
+            max = *f;
+            max_index = i;
+        }
+
+        // Every symbol that is present must have a nonzero frequency.
+        let normalized_f = ((u64::from(*f) * total) / sum).max(1);
+        *f = normalized_f as u32;
+        normalized_sum += normalized_f;
+    }
+
+    if normalized_sum < total {
+        freqs[max_index] += (total - normalized_sum) as u32;
+    } else {
+        // Rounding up small frequencies can overshoot the total. Take the excess from the largest
+        // frequencies.
+        for _ in total..normalized_sum {
+            if let Some(f) = freqs.iter_mut().max() {
+                *f -= 1;
+            }
+        }
+    }
+}
+
+// Scales normalized frequencies up to sum to `1 << bits`, as is done when decoding.
+fn scale_frequencies(freqs: &mut [u32], bits: u32) {
+    let mut total: u32 = freqs.iter().sum();
+
+    if total == 0 {
+        return;
+    }
+
+    let mut shift = 0;
+
+    while total < (1 << bits) {
+        total <<= 1;
+        shift += 1;
+    }
+
+    for f in freqs {
+        *f <<= shift;
+    }
+}
+
+fn build_cumulative_frequencies(freqs: &[u32]) -> Vec<u32> {
+    let mut cumulative_freqs = vec![0; freqs.len()];
+
+    for i in 0..freqs.len() - 1 {
+        cumulative_freqs[i + 1] = cumulative_freqs[i] + freqs[i];
+    }
+
+    cumulative_freqs
+}
+
+fn write_alphabet<W>(writer: &mut W, alphabet: &[bool; 256]) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut rle = 0;
+
+    for sym in 0..alphabet.len() {
+        if !alphabet[sym] {
+            continue;
+        }
+
+        if rle > 0 {
+            rle -= 1;
+            continue;
+        }
+
+        writer.write_u8(sym as u8)?;
+
+        if sym > 0 && alphabet[sym - 1] {
+            rle = alphabet[sym + 1..].iter().take_while(|&&a| a).count();
+            writer.write_u8(rle as u8)?;
+        }
+    }
+
+    writer.write_u8(0x00)?;
+
+    Ok(())
+}
+
+fn write_frequencies_0<W>(writer: &mut W, freqs: &[u32]) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut alphabet = [false; 256];
+
+    for (a, &f) in alphabet.iter_mut().zip(freqs) {
+        *a = f > 0;
+    }
+
+    write_alphabet(writer, &alphabet)?;
+
+    for &f in freqs.iter().filter(|&&f| f > 0) {
+        write_uint7(writer, f)?;
+    }
+
+    Ok(())
+}
+
+fn write_frequencies_1<W>(
+    writer: &mut W,
+    contexts: &[Vec<u32>],
+    alphabet: &[bool; 256],
+    bits: u32,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut table = Vec::new();
+    write_alphabet(&mut table, alphabet)?;
+
+    let symbols: Vec<_> = (0..alphabet.len()).filter(|&i| alphabet[i]).collect();
+
+    for &i in &symbols {
+        let freqs = &contexts[i];
+        let mut j = 0;
+
+        while j < symbols.len() {
+            let f = freqs[symbols[j]];
+            write_uint7(&mut table, f)?;
+
+            if f == 0 {
+                let run = symbols[j + 1..]
+                    .iter()
+                    .take_while(|&&k| freqs[k] == 0)
+                    .take(usize::from(u8::MAX))
+                    .count();
+
+                table.write_u8(run as u8)?;
+
+                j += run;
+            }
+
+            j += 1;
+        }
+    }
+
+    let mut compressed_table = Vec::new();
+    encode_order_0(&mut compressed_table, &table, 4)?;
+
+    let comp = (bits as u8) << 4;
+
+    if compressed_table.len() < table.len() {
+        writer.write_u8(comp | 0x01)?;
+
+        let table_len = u32::try_from(table.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        write_uint7(writer, table_len)?;
+
+        let compressed_table_len = u32::try_from(compressed_table.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        write_uint7(writer, compressed_table_len)?;
+
+        writer.write_all(&compressed_table)?;
+    } else {
+        writer.write_u8(comp)?;
+        writer.write_all(&table)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codecs::rans_nx16::rans_decode_nx16;
+
+    fn t(flags: Flags, data: &[u8]) -> io::Result<()> {
+        let compressed_data = rans_encode_nx16(flags, data)?;
+
+        let mut reader = &compressed_data[..];
+        let len = if flags.contains(Flags::NO_SIZE) {
+            data.len()
+        } else {
+            0
+        };
+
+        assert_eq!(
+            rans_decode_nx16(&mut reader, len)?,
+            data,
+            "flags = {:?}",
+            flags
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_self() -> io::Result<()> {
+        let mut data = b"noodles".to_vec();
+        data.extend(b"nooooooooooooooooooooodles".iter().cycle().take(1024));
+        data.extend((0..=255).cycle().take(2048));
+
+        let all_flags = [
+            Flags::empty(),
+            Flags::ORDER,
+            Flags::N32,
+            Flags::ORDER | Flags::N32,
+            Flags::STRIPE,
+            Flags::STRIPE | Flags::ORDER,
+            Flags::NO_SIZE,
+            Flags::CAT,
+            Flags::RLE,
+            Flags::RLE | Flags::ORDER,
+            Flags::PACK,
+            Flags::PACK | Flags::ORDER,
+            Flags::PACK | Flags::RLE,
+            Flags::PACK | Flags::RLE | Flags::ORDER,
+        ];
+
+        for flags in all_flags {
+            t(flags, b"")?;
+            t(flags, b"n")?;
+            t(flags, b"noodles")?;
+            t(flags, b"nnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnn")?;
+            t(flags, b"ndls ndls ndls ndls ndls ndls")?;
+            t(flags, &data)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_pack() {
+        assert_eq!(encode_pack(b""), Some((Vec::new(), Vec::new())));
+        assert_eq!(encode_pack(b"nnn"), Some((vec![b'n'], Vec::new())));
+        assert_eq!(
+            encode_pack(b"ndnnd"),
+            Some((vec![b'd', b'n'], vec![0b01101]))
+        );
+        assert_eq!(
+            encode_pack(b"noodles"),
+            Some((
+                vec![b'd', b'e', b'l', b'n', b'o', b's'],
+                vec![0x43, 0x04, 0x12, 0x05]
+            ))
+        );
+        assert!(encode_pack(&(0..=16).collect::<Vec<_>>()).is_none());
+    }
+
+    #[test]
+    fn test_write_alphabet() -> io::Result<()> {
+        let mut alphabet = [false; 256];
+
+        for &b in b"abcdz" {
+            alphabet[usize::from(b)] = true;
+        }
+
+        let mut buf = Vec::new();
+        write_alphabet(&mut buf, &alphabet)?;
+
+        assert_eq!(buf, [b'a', b'b', 0x02, b'z', 0x00]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_frequencies() {
+        let mut freqs = [1, 2, 0, 4];
+        normalize_frequencies(&mut freqs, 12);
+        assert_eq!(freqs, [1, 2, 0, 5]);
+
+        let mut freqs = [3, 1, 8192];
+        normalize_frequencies(&mut freqs, 12);
+        assert_eq!(freqs.iter().sum::<u32>(), 4096);
+        assert!(freqs.iter().all(|&f| f > 0));
+    }
+}
@@ -99,7 +99,14 @@ impl Block {
             CompressionMethod::NameTokenizer => {
                 let mut reader = self.data();
                 let names = decode_names(&mut reader)?;
-                let data: Vec<_> = names.into_iter().flat_map(|s| s.into_bytes()).collect();
+
+                let mut data = Vec::with_capacity(self.uncompressed_len);
+
+                for name in names {
+                    data.extend(name.into_bytes());
+                    data.push(0x00);
+                }
+
                 Ok(Bytes::from(data))
             }
         }
@@ -1,5 +1,6 @@
 //! CRAM data container and fields.
 
+mod block_content_encoder_map;
 pub(crate) mod builder;
 pub(crate) mod compression_header;
 mod header;
@@ -7,7 +8,8 @@ mod reference_sequence_context;
 pub(crate) mod slice;
 
 pub(crate) use self::{
-    builder::Builder, header::Header, reference_sequence_context::ReferenceSequenceContext,
+    block_content_encoder_map::BlockContentEncoderMap, builder::Builder, header::Header,
+    reference_sequence_context::ReferenceSequenceContext,
 };
 pub use self::{compression_header::CompressionHeader, slice::Slice};
 
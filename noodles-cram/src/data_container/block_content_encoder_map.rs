@@ -0,0 +1,186 @@
+use crate::{
+    codecs::{aac, rans, rans_nx16, Encoder},
+    data_container::compression_header::data_series_encoding_map::DataSeries,
+    file_definition::Version,
+    writer::CompressionProfile,
+};
+
+const LZMA_BEST_COMPRESSION_LEVEL: u32 = 9;
+
+/// A map of block contents to the encoders used to compress them.
+///
+/// Each block content has a list of candidate encoders. The block is compressed with each
+/// candidate, and the smallest output is kept. An empty list leaves the block uncompressed.
+#[derive(Clone, Debug)]
+pub struct BlockContentEncoderMap {
+    core_data_encoders: Vec<Encoder>,
+    data_series_encoders: Vec<Vec<Encoder>>,
+    tag_values_encoders: Vec<Encoder>,
+}
+
+impl BlockContentEncoderMap {
+    /// Creates a block content encoder map for the given CRAM version and compression profile.
+    pub fn new(version: Version, compression_profile: CompressionProfile) -> Self {
+        if (version.major(), version.minor()) >= (3, 1) {
+            Self::v3_1(compression_profile)
+        } else {
+            Self::v3_0(compression_profile)
+        }
+    }
+
+    fn v3_0(compression_profile: CompressionProfile) -> Self {
+        let encoders = match compression_profile {
+            CompressionProfile::Fast => vec![Encoder::Gzip(flate2::Compression::fast())],
+            CompressionProfile::Normal => vec![Encoder::Gzip(flate2::Compression::default())],
+            CompressionProfile::Small => vec![
+                Encoder::Gzip(flate2::Compression::best()),
+                Encoder::Bzip2(bzip2::Compression::best()),
+                Encoder::Rans4x8(rans::Order::Zero),
+                Encoder::Rans4x8(rans::Order::One),
+            ],
+            CompressionProfile::Archive => vec![
+                Encoder::Gzip(flate2::Compression::best()),
+                Encoder::Bzip2(bzip2::Compression::best()),
+                Encoder::Lzma(LZMA_BEST_COMPRESSION_LEVEL),
+                Encoder::Rans4x8(rans::Order::Zero),
+                Encoder::Rans4x8(rans::Order::One),
+            ],
+        };
+
+        Self {
+            core_data_encoders: vec![Encoder::Gzip(flate2::Compression::default())],
+            data_series_encoders: vec![encoders.clone(); DataSeries::LEN],
+            tag_values_encoders: encoders,
+        }
+    }
+
+    fn v3_1(compression_profile: CompressionProfile) -> Self {
+        use rans_nx16::Flags;
+
+        let (encoders, integer_encoders, quality_scores_encoders, tag_values_encoders) =
+            match compression_profile {
+                CompressionProfile::Fast => {
+                    let encoders = vec![Encoder::RansNx16(Flags::empty())];
+
+                    (
+                        encoders.clone(),
+                        encoders.clone(),
+                        vec![Encoder::RansNx16(Flags::ORDER)],
+                        encoders,
+                    )
+                }
+                CompressionProfile::Normal => (
+                    vec![Encoder::RansNx16(Flags::ORDER)],
+                    vec![Encoder::RansNx16(Flags::ORDER | Flags::PACK)],
+                    vec![Encoder::Fqzcomp],
+                    vec![Encoder::AdaptiveArithmeticCoding(aac::Flags::ORDER)],
+                ),
+                CompressionProfile::Small | CompressionProfile::Archive => {
+                    let mut encoders = vec![
+                        Encoder::RansNx16(Flags::empty()),
+                        Encoder::RansNx16(Flags::ORDER),
+                        Encoder::RansNx16(Flags::ORDER | Flags::PACK),
+                        Encoder::AdaptiveArithmeticCoding(aac::Flags::empty()),
+                        Encoder::AdaptiveArithmeticCoding(aac::Flags::ORDER),
+                    ];
+
+                    if compression_profile == CompressionProfile::Archive {
+                        encoders.extend([
+                            Encoder::RansNx16(Flags::ORDER | Flags::RLE),
+                            Encoder::AdaptiveArithmeticCoding(aac::Flags::ORDER | aac::Flags::RLE),
+                            Encoder::Bzip2(bzip2::Compression::best()),
+                            Encoder::Lzma(LZMA_BEST_COMPRESSION_LEVEL),
+                        ]);
+                    }
+
+                    (
+                        encoders.clone(),
+                        encoders.clone(),
+                        vec![
+                            Encoder::Fqzcomp,
+                            Encoder::RansNx16(Flags::ORDER),
+                            Encoder::AdaptiveArithmeticCoding(aac::Flags::ORDER),
+                        ],
+                        encoders,
+                    )
+                }
+            };
+
+        let mut data_series_encoders = vec![encoders; DataSeries::LEN];
+
+        data_series_encoders[DataSeries::ReadNames as usize] = vec![Encoder::NameTokenizer];
+        data_series_encoders[DataSeries::QualityScores as usize] = quality_scores_encoders;
+
+        for data_series in [
+            DataSeries::BamBitFlags,
+            DataSeries::CramBitFlags,
+            DataSeries::ReadLengths,
+            DataSeries::InSeqPositions,
+            DataSeries::NextMateAlignmentStart,
+            DataSeries::TemplateSize,
+        ] {
+            data_series_encoders[data_series as usize] = integer_encoders.clone();
+        }
+
+        let core_data_encoder = match compression_profile {
+            CompressionProfile::Fast => Encoder::Gzip(flate2::Compression::fast()),
+            _ => Encoder::Gzip(flate2::Compression::default()),
+        };
+
+        Self {
+            core_data_encoders: vec![core_data_encoder],
+            data_series_encoders,
+            tag_values_encoders,
+        }
+    }
+
+    pub fn core_data_encoders(&self) -> &[Encoder] {
+        &self.core_data_encoders
+    }
+
+    pub fn data_series_encoders(&self, data_series: DataSeries) -> &[Encoder] {
+        &self.data_series_encoders[data_series as usize]
+    }
+
+    pub fn tag_values_encoders(&self) -> &[Encoder] {
+        &self.tag_values_encoders
+    }
+}
+
+impl Default for BlockContentEncoderMap {
+    fn default() -> Self {
+        Self::new(Version::default(), CompressionProfile::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container::block::CompressionMethod;
+
+    fn compression_methods(encoders: &[Encoder]) -> Vec<CompressionMethod> {
+        encoders.iter().map(|e| e.compression_method()).collect()
+    }
+
+    #[test]
+    fn test_new() {
+        let map = BlockContentEncoderMap::new(Version::new(3, 0), CompressionProfile::Normal);
+        assert_eq!(
+            compression_methods(map.data_series_encoders(DataSeries::QualityScores)),
+            [CompressionMethod::Gzip]
+        );
+
+        let map = BlockContentEncoderMap::new(Version::new(3, 0), CompressionProfile::Archive);
+        assert!(compression_methods(map.tag_values_encoders()).contains(&CompressionMethod::Lzma));
+
+        let map = BlockContentEncoderMap::new(Version::new(3, 1), CompressionProfile::Normal);
+        assert_eq!(
+            compression_methods(map.data_series_encoders(DataSeries::ReadNames)),
+            [CompressionMethod::NameTokenizer]
+        );
+        assert_eq!(
+            compression_methods(map.data_series_encoders(DataSeries::QualityScores)),
+            [CompressionMethod::Fqzcomp]
+        );
+    }
+}
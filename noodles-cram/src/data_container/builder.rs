@@ -93,6 +93,7 @@ impl Builder {
                     reference_sequence_repository,
                     header,
                     &compression_header,
+                    &options.block_content_encoder_map,
                     record_counter,
                 )
             })
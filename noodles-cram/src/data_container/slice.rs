@@ -5,7 +5,7 @@ pub use self::{builder::Builder, header::Header};
 
 use std::io;
 
-use noodles_core::Position;
+use noodles_core::{Position, Region};
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
@@ -158,13 +158,14 @@ impl Slice {
             None
         };
 
-        // § 11 "Reference sequences" (2021-11-15): "All CRAM reader implementations are
-        // expected to check for reference MD5 checksums and report any missing or
-        // mismatching entries."
-        if compression_header
+        let is_reference_required = compression_header
             .preservation_map()
-            .is_reference_required()
-        {
+            .is_reference_required();
+
+        // For a single reference sequence slice, only the span the slice covers is fetched from
+        // the repository. As with an embedded reference sequence, positions in it are relative to
+        // the slice alignment start.
+        let slice_reference_sequence = if is_reference_required {
             if let ReferenceSequenceContext::Some(context) =
                 self.header().reference_sequence_context()
             {
@@ -174,16 +175,20 @@ impl Slice {
                     .map(|(_, rs)| rs.name())
                     .expect("invalid slice reference sequence ID");
 
+                let region = Region::new(
+                    reference_sequence_name.to_string(),
+                    context.alignment_start()..=context.alignment_end(),
+                );
+
                 let sequence = reference_sequence_repository
-                    .get(reference_sequence_name)
+                    .get_region(&region)
                     .transpose()?
                     .expect("invalid slice reference sequence name");
 
-                let start = context.alignment_start();
-                let end = context.alignment_end();
-
-                let actual_md5 =
-                    builder::calculate_normalized_sequence_digest(&sequence[start..=end]);
+                // § 11 "Reference sequences" (2021-11-15): "All CRAM reader implementations are
+                // expected to check for reference MD5 checksums and report any missing or
+                // mismatching entries."
+                let actual_md5 = builder::calculate_normalized_sequence_digest(sequence.as_ref());
                 let expected_md5 = self.header().reference_md5();
 
                 if actual_md5 != expected_md5 {
@@ -195,8 +200,14 @@ impl Slice {
                         ),
                     ));
                 }
+
+                Some(sequence)
+            } else {
+                None
             }
-        }
+        } else {
+            embedded_reference_sequence
+        };
 
         for record in records {
             if record.bam_flags().is_unmapped() || record.cram_flags().decode_sequence_as_unknown()
@@ -206,22 +217,7 @@ impl Slice {
 
             let mut alignment_start = record.alignment_start.expect("invalid alignment start");
 
-            let reference_sequence = if compression_header
-                .preservation_map()
-                .is_reference_required()
-            {
-                let rs = record
-                    .reference_sequence(header.reference_sequences())
-                    .transpose()?
-                    .expect("invalid reference sequence ID");
-
-                let sequence = reference_sequence_repository
-                    .get(rs.name())
-                    .transpose()?
-                    .expect("invalid reference sequence name");
-
-                Some(sequence)
-            } else if let Some(ref sequence) = embedded_reference_sequence {
+            let reference_sequence = if let Some(ref sequence) = slice_reference_sequence {
                 let offset = match self.header().reference_sequence_context() {
                     ReferenceSequenceContext::Some(context) => {
                         usize::from(context.alignment_start())
@@ -234,6 +230,18 @@ impl Slice {
                     .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
                 Some(sequence.clone())
+            } else if is_reference_required {
+                let rs = record
+                    .reference_sequence(header.reference_sequences())
+                    .transpose()?
+                    .expect("invalid reference sequence ID");
+
+                let sequence = reference_sequence_repository
+                    .get(rs.name())
+                    .transpose()?
+                    .expect("invalid reference sequence name");
+
+                Some(sequence)
             } else {
                 None
             };
@@ -5,15 +5,16 @@ use noodles_fasta as fasta;
 use noodles_sam as sam;
 
 use crate::{
+    codecs::{fqzcomp::fqz_encode, Encoder},
     container::{
         block::{self, CompressionMethod},
         Block,
     },
     data_container::{
-        compression_header::data_series_encoding_map::DataSeries, CompressionHeader,
-        ReferenceSequenceContext,
+        compression_header::data_series_encoding_map::DataSeries, BlockContentEncoderMap,
+        CompressionHeader, ReferenceSequenceContext,
     },
-    record::Flags,
+    record::{Feature, Flags},
     writer, BitWriter, Record,
 };
 
@@ -22,6 +23,37 @@ use super::{Header, Slice};
 const CORE_DATA_BLOCK_CONTENT_ID: i32 = 0;
 const MAX_RECORD_COUNT: usize = 10240;
 
+const DATA_SERIES: [DataSeries; DataSeries::LEN] = [
+    DataSeries::BamBitFlags,
+    DataSeries::CramBitFlags,
+    DataSeries::ReferenceId,
+    DataSeries::ReadLengths,
+    DataSeries::InSeqPositions,
+    DataSeries::ReadGroups,
+    DataSeries::ReadNames,
+    DataSeries::NextMateBitFlags,
+    DataSeries::NextFragmentReferenceSequenceId,
+    DataSeries::NextMateAlignmentStart,
+    DataSeries::TemplateSize,
+    DataSeries::DistanceToNextFragment,
+    DataSeries::TagIds,
+    DataSeries::NumberOfReadFeatures,
+    DataSeries::ReadFeaturesCodes,
+    DataSeries::InReadPositions,
+    DataSeries::DeletionLengths,
+    DataSeries::StretchesOfBases,
+    DataSeries::StretchesOfQualityScores,
+    DataSeries::BaseSubstitutionCodes,
+    DataSeries::Insertion,
+    DataSeries::ReferenceSkipLength,
+    DataSeries::Padding,
+    DataSeries::HardClip,
+    DataSeries::SoftClip,
+    DataSeries::MappingQualities,
+    DataSeries::Bases,
+    DataSeries::QualityScores,
+];
+
 #[derive(Debug, Default)]
 pub struct Builder {
     records: Vec<Record>,
@@ -80,10 +112,12 @@ impl Builder {
         reference_sequence_repostitory: &fasta::repository::Repository,
         header: &sam::Header,
         compression_header: &CompressionHeader,
+        block_content_encoder_map: &BlockContentEncoderMap,
         record_counter: u64,
     ) -> io::Result<Slice> {
         let (core_data_block, external_blocks) = write_records(
             compression_header,
+            block_content_encoder_map,
             self.reference_sequence_context,
             &mut self.records,
         )?;
@@ -131,6 +165,7 @@ impl Builder {
 
 fn write_records(
     compression_header: &CompressionHeader,
+    block_content_encoder_map: &BlockContentEncoderMap,
     reference_sequence_context: ReferenceSequenceContext,
     records: &mut [Record],
 ) -> io::Result<(Block, Vec<Block>)> {
@@ -156,33 +191,120 @@ fn write_records(
 
     set_mates(records);
 
-    for record in records {
+    for record in records.iter() {
         record_writer.write_record(record)?;
     }
 
     let core_data_block = core_data_writer.finish().and_then(|buf| {
-        Block::builder()
-            .set_content_type(block::ContentType::CoreData)
-            .set_content_id(CORE_DATA_BLOCK_CONTENT_ID)
-            .compress_and_set_data(buf, CompressionMethod::Gzip)
-            .map(|builder| builder.build())
+        build_block(
+            block::ContentType::CoreData,
+            CORE_DATA_BLOCK_CONTENT_ID,
+            buf,
+            block_content_encoder_map.core_data_encoders(),
+            None,
+        )
     })?;
 
+    let quality_scores_lens: Vec<_> = records.iter().map(quality_scores_len).collect();
+
     let external_blocks: Vec<_> = external_data_writers
         .into_iter()
         .filter(|(_, buf)| !buf.is_empty())
         .map(|(block_content_id, buf)| {
-            Block::builder()
-                .set_content_type(block::ContentType::ExternalData)
-                .set_content_id(block_content_id)
-                .compress_and_set_data(buf, CompressionMethod::Gzip)
-                .map(|builder| builder.build())
+            let (encoders, record_lens) = match data_series_for_block_content_id(block_content_id) {
+                Some(DataSeries::QualityScores) => (
+                    block_content_encoder_map.data_series_encoders(DataSeries::QualityScores),
+                    Some(&quality_scores_lens[..]),
+                ),
+                Some(data_series) => (
+                    block_content_encoder_map.data_series_encoders(data_series),
+                    None,
+                ),
+                None => (block_content_encoder_map.tag_values_encoders(), None),
+            };
+
+            build_block(
+                block::ContentType::ExternalData,
+                block_content_id,
+                buf,
+                encoders,
+                record_lens,
+            )
         })
         .collect::<Result<_, _>>()?;
 
     Ok((core_data_block, external_blocks))
 }
 
+fn data_series_for_block_content_id(block_content_id: i32) -> Option<DataSeries> {
+    // Data series are written to blocks 1..=DataSeries::LEN, in declaration order.
+    usize::try_from(block_content_id)
+        .ok()
+        .and_then(|i| i.checked_sub(1))
+        .and_then(|i| DATA_SERIES.get(i))
+        .copied()
+}
+
+// Compresses the block data with each encoder and keeps the smallest output.
+//
+// fqzcomp uses the given record lengths, if any, to separate the records in the block data.
+fn build_block(
+    content_type: block::ContentType,
+    block_content_id: i32,
+    buf: Vec<u8>,
+    encoders: &[Encoder],
+    record_lens: Option<&[usize]>,
+) -> io::Result<Block> {
+    let mut best: Option<(CompressionMethod, Vec<u8>)> = None;
+
+    for encoder in encoders {
+        let data = match (encoder, record_lens) {
+            (Encoder::Fqzcomp, Some(lens)) if lens.iter().sum::<usize>() == buf.len() => {
+                fqz_encode(lens, &buf)?
+            }
+            _ => encoder.encode(&buf)?,
+        };
+
+        if best
+            .as_ref()
+            .map(|(_, b)| data.len() < b.len())
+            .unwrap_or(true)
+        {
+            best = Some((encoder.compression_method(), data));
+        }
+    }
+
+    let builder = Block::builder()
+        .set_content_type(content_type)
+        .set_content_id(block_content_id);
+
+    match best {
+        Some((compression_method, data)) => Ok(builder
+            .set_compression_method(compression_method)
+            .set_uncompressed_len(buf.len())
+            .set_data(data.into())
+            .build()),
+        None => builder
+            .compress_and_set_data(buf, CompressionMethod::None)
+            .map(|builder| builder.build()),
+    }
+}
+
+// Returns the number of quality scores a record writes to the quality scores data series.
+fn quality_scores_len(record: &Record) -> usize {
+    let mut len = record
+        .features()
+        .iter()
+        .filter(|feature| matches!(feature, Feature::ReadBase(..) | Feature::QualityScore(..)))
+        .count();
+
+    if record.cram_flags().are_quality_scores_stored_as_array() {
+        len += record.quality_scores().len();
+    }
+
+    len
+}
+
 fn set_mates(records: &mut [Record]) {
     assert!(!records.is_empty());
 
@@ -247,6 +369,133 @@ pub(super) fn calculate_normalized_sequence_digest(sequence: &[u8]) -> [u8; 16]
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::codecs::rans;
+
+    #[test]
+    fn test_build_block() -> io::Result<()> {
+        let buf = b"ndls".repeat(64);
+
+        let encoders = [
+            Encoder::Gzip(flate2::Compression::default()),
+            Encoder::Bzip2(bzip2::Compression::default()),
+            Encoder::Rans4x8(rans::Order::Zero),
+            Encoder::Rans4x8(rans::Order::One),
+        ];
+
+        let mut candidates = encoders
+            .iter()
+            .map(|encoder| {
+                encoder
+                    .encode(&buf)
+                    .map(|data| (encoder.compression_method(), data))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        // The first candidate is kept on ties.
+        candidates.reverse();
+        let (expected_compression_method, expected_data) = candidates
+            .into_iter()
+            .min_by_key(|(_, data)| data.len())
+            .unwrap();
+
+        let block = build_block(
+            block::ContentType::ExternalData,
+            1,
+            buf.clone(),
+            &encoders,
+            None,
+        )?;
+
+        assert_eq!(block.compression_method(), expected_compression_method);
+        assert_eq!(block.data(), &expected_data[..]);
+        assert_eq!(&block.decompressed_data()?[..], &buf[..]);
+
+        for encoder in &encoders {
+            let block = build_block(
+                block::ContentType::ExternalData,
+                1,
+                buf.clone(),
+                std::slice::from_ref(encoder),
+                None,
+            )?;
+
+            assert_eq!(block.compression_method(), encoder.compression_method());
+        }
+
+        let block = build_block(block::ContentType::ExternalData, 1, buf.clone(), &[], None)?;
+        assert_eq!(block.compression_method(), CompressionMethod::None);
+        assert_eq!(block.data(), &buf[..]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_records_with_quality_score_features() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+        use noodles_sam::record::quality_scores::Score;
+
+        use crate::{
+            file_definition::Version,
+            writer::{CompressionProfile, Options},
+        };
+
+        // The first record writes one quality score from its feature, and the second, four from
+        // its quality scores.
+        let mut records = vec![
+            Record::builder()
+                .set_bam_flags(sam::record::Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_read_length(4)
+                .set_alignment_start(Position::MIN)
+                .add_feature(Feature::QualityScore(
+                    Position::try_from(2)?,
+                    Score::try_from(13)?,
+                ))
+                .build(),
+            Record::builder()
+                .set_bam_flags(sam::record::Flags::empty())
+                .set_flags(Flags::QUALITY_SCORES_STORED_AS_ARRAY)
+                .set_reference_sequence_id(0)
+                .set_read_length(4)
+                .set_alignment_start(Position::try_from(5)?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+        ];
+
+        let mut compression_header_builder = CompressionHeader::builder();
+        compression_header_builder.apply_options(&Options::default());
+
+        for record in &records {
+            compression_header_builder.update(record);
+        }
+
+        let compression_header = compression_header_builder.build();
+
+        // CRAM 3.1 with the normal profile only compresses quality scores with fqzcomp.
+        let block_content_encoder_map =
+            BlockContentEncoderMap::new(Version::new(3, 1), CompressionProfile::Normal);
+
+        let (_, external_blocks) = write_records(
+            &compression_header,
+            &block_content_encoder_map,
+            ReferenceSequenceContext::some(0, Position::MIN, Position::try_from(8)?),
+            &mut records,
+        )?;
+
+        let block_content_id = DataSeries::QualityScores as i32 + 1;
+        let block = external_blocks
+            .iter()
+            .find(|block| block.content_id() == block_content_id)
+            .expect("missing quality scores block");
+
+        let buf = block.decompressed_data()?;
+        assert_eq!(buf.len(), 5);
+
+        assert_eq!(block.compression_method(), CompressionMethod::Fqzcomp);
+        assert_eq!(block.data(), &fqz_encode(&[1, 4], &buf)?[..]);
+
+        Ok(())
+    }
 
     #[test]
     fn test_calculate_normalized_sequence_digest() {
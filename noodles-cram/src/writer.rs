@@ -1,4 +1,7 @@
+//! CRAM writer.
+
 mod builder;
+mod compression_profile;
 mod container;
 pub(crate) mod data_container;
 mod header_container;
@@ -6,8 +9,8 @@ pub(crate) mod num;
 mod options;
 pub(crate) mod record;
 
-pub use self::builder::Builder;
 pub(crate) use self::options::Options;
+pub use self::{builder::Builder, compression_profile::CompressionProfile};
 
 use std::{
     io::{self, Write},
@@ -122,7 +125,8 @@ where
 
     /// Writes a CRAM file definition.
     ///
-    /// The file ID is set as a blank value (`[0x00; 20]`).
+    /// The format version is the one set by [`Builder::set_version`], and the file ID is set as a
+    /// blank value (`[0x00; 20]`).
     ///
     /// # Examples
     ///
@@ -145,7 +149,7 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_file_definition(&mut self) -> io::Result<()> {
-        let file_definition = FileDefinition::default();
+        let file_definition = FileDefinition::new(self.options.version, [0; 20]);
         write_file_definition(&mut self.inner, &file_definition)
     }
 
@@ -287,3 +291,106 @@ where
     let format = [version.major(), version.minor()];
     writer.write_all(&format)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{container::block::CompressionMethod, record::Flags, Reader};
+
+    fn write_and_read_records(
+        version: Version,
+        compression_profile: CompressionProfile,
+    ) -> Result<(Vec<u8>, Vec<Record>), Box<dyn std::error::Error>> {
+        let header = sam::Header::default();
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_version(version)
+            .set_compression_profile(compression_profile)
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        let mut expected = Vec::new();
+
+        for (id, (name, bases, quality_scores)) in [
+            ("r0:1", "ACGT", "NDLS"),
+            ("r0:2", "TGCA", "SLDN"),
+            ("r1:1", "AACC", "NNNN"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            // Unpaired records are written as detached.
+            let record = Record::builder()
+                .set_id(id as u64)
+                .set_flags(Flags::QUALITY_SCORES_STORED_AS_ARRAY | Flags::DETACHED)
+                .set_read_name(name.parse()?)
+                .set_read_length(bases.len())
+                .set_bases(bases.parse()?)
+                .set_quality_scores(quality_scores.parse()?)
+                .build();
+
+            writer.write_record(&header, record.clone())?;
+            expected.push(record);
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+
+        let file_definition = reader.read_file_definition()?;
+        assert_eq!(file_definition.version(), version);
+
+        reader.read_file_header()?;
+
+        let repository = fasta::Repository::default();
+        let actual = reader
+            .records(&repository, &header)
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(actual, expected);
+
+        Ok((writer.get_ref().clone(), actual))
+    }
+
+    #[test]
+    fn test_write_record_with_compression_profiles() -> Result<(), Box<dyn std::error::Error>> {
+        for version in [Version::new(3, 0), Version::new(3, 1)] {
+            for compression_profile in [
+                CompressionProfile::Fast,
+                CompressionProfile::Normal,
+                CompressionProfile::Small,
+                CompressionProfile::Archive,
+            ] {
+                write_and_read_records(version, compression_profile)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_version_3_1() -> Result<(), Box<dyn std::error::Error>> {
+        let (data, _) = write_and_read_records(Version::new(3, 1), CompressionProfile::Normal)?;
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let data_container = reader
+            .read_data_container()?
+            .expect("missing data container");
+
+        let compression_methods: Vec<_> = data_container.slices()[0]
+            .external_blocks()
+            .iter()
+            .map(|block| block.compression_method())
+            .collect();
+
+        assert!(compression_methods.contains(&CompressionMethod::NameTokenizer));
+        assert!(compression_methods.contains(&CompressionMethod::Fqzcomp));
+
+        Ok(())
+    }
+}
@@ -2,8 +2,8 @@ use std::io::Write;
 
 use noodles_fasta as fasta;
 
-use super::{Options, Writer};
-use crate::DataContainer;
+use super::{CompressionProfile, Options, Writer};
+use crate::{data_container::BlockContentEncoderMap, file_definition::Version, DataContainer};
 
 /// A CRAM writer builder.
 pub struct Builder<W> {
@@ -53,6 +53,44 @@ where
         self
     }
 
+    /// Sets the CRAM format version.
+    ///
+    /// This also determines the block compression methods available to the compression profile.
+    /// CRAM 3.1 compresses read names with the name tokenizer, quality scores with fqzcomp, and
+    /// other data series with rANS Nx16 and adaptive arithmetic coding.
+    ///
+    /// The default is CRAM 3.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{self as cram, file_definition::Version};
+    /// let writer = cram::Writer::builder(Vec::new())
+    ///     .set_version(Version::new(3, 1))
+    ///     .build();
+    /// ```
+    pub fn set_version(mut self, version: Version) -> Self {
+        self.options.version = version;
+        self
+    }
+
+    /// Sets the compression profile.
+    ///
+    /// The default is [`CompressionProfile::Normal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{self as cram, CompressionProfile};
+    /// let writer = cram::Writer::builder(Vec::new())
+    ///     .set_compression_profile(CompressionProfile::Archive)
+    ///     .build();
+    /// ```
+    pub fn set_compression_profile(mut self, compression_profile: CompressionProfile) -> Self {
+        self.options.compression_profile = compression_profile;
+        self
+    }
+
     /// Builds a CRAM writer.
     ///
     /// # Examples
@@ -61,7 +99,10 @@ where
     /// use noodles_cram as cram;
     /// let writer = cram::Writer::builder(Vec::new()).build();
     /// ```
-    pub fn build(self) -> Writer<W> {
+    pub fn build(mut self) -> Writer<W> {
+        self.options.block_content_encoder_map =
+            BlockContentEncoderMap::new(self.options.version, self.options.compression_profile);
+
         Writer {
             inner: self.inner,
             reference_sequence_repository: self.reference_sequence_repository,
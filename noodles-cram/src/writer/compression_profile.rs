@@ -0,0 +1,26 @@
+/// A CRAM writer compression profile.
+///
+/// A compression profile selects the block compression methods used for each data series and tag
+/// block. Profiles that favor smaller output compress each block with several methods and keep
+/// the smallest result.
+///
+/// The available methods depend on the CRAM format version. CRAM 3.0 uses gzip, bzip2, LZMA, and
+/// rANS 4x8. CRAM 3.1 adds rANS Nx16, adaptive arithmetic coding, fqzcomp, and the name
+/// tokenizer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CompressionProfile {
+    /// Optimizes for speed.
+    Fast,
+    /// Balances speed and compression ratio.
+    Normal,
+    /// Optimizes for compression ratio.
+    Small,
+    /// Optimizes for compression ratio, regardless of speed.
+    Archive,
+}
+
+impl Default for CompressionProfile {
+    fn default() -> Self {
+        Self::Normal
+    }
+}
@@ -1,7 +1,13 @@
+use super::CompressionProfile;
+use crate::{data_container::BlockContentEncoderMap, file_definition::Version};
+
 #[derive(Clone, Debug)]
 pub struct Options {
     pub preserve_read_names: bool,
     pub encode_alignment_start_positions_as_deltas: bool,
+    pub version: Version,
+    pub compression_profile: CompressionProfile,
+    pub block_content_encoder_map: BlockContentEncoderMap,
 }
 
 impl Default for Options {
@@ -9,6 +15,9 @@ impl Default for Options {
         Self {
             preserve_read_names: true,
             encode_alignment_start_positions_as_deltas: true,
+            version: Version::default(),
+            compression_profile: CompressionProfile::default(),
+            block_content_encoder_map: BlockContentEncoderMap::default(),
         }
     }
 }
@@ -1,9 +1,10 @@
 //! Coordinate-sorted index and fields.
 
 mod builder;
+pub mod indexer;
 pub mod reference_sequence;
 
-pub use self::{builder::Builder, reference_sequence::ReferenceSequence};
+pub use self::{builder::Builder, indexer::Indexer, reference_sequence::ReferenceSequence};
 
 use std::io;
 
@@ -34,6 +35,18 @@ impl Index {
         Builder::default()
     }
 
+    /// Returns an indexer to create an index from records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let indexer = csi::Index::indexer();
+    /// ```
+    pub fn indexer() -> Indexer {
+        Indexer::default()
+    }
+
     /// Returns the number of bits for the minimum interval.
     ///
     /// # Examples
@@ -0,0 +1,309 @@
+//! Coordinate-sorted index (CSI) indexer.
+
+mod alignment_context;
+mod builder;
+
+pub use self::{alignment_context::AlignmentContext, builder::Builder};
+
+use std::io;
+
+use noodles_core::Position;
+
+use super::{reference_sequence, Index, ReferenceSequence};
+use crate::index::reference_sequence::bin::Chunk;
+
+const DEFAULT_MIN_SHIFT: u8 = 14;
+const DEFAULT_DEPTH: u8 = 5;
+
+/// A coordinate-sorted index (CSI) indexer.
+#[derive(Debug)]
+pub struct Indexer {
+    min_shift: u8,
+    depth: u8,
+    aux: Vec<u8>,
+    reference_sequence_builders: Vec<reference_sequence::Builder>,
+    last_position: Option<(usize, Position)>,
+    unplaced_unmapped_record_count: u64,
+}
+
+impl Indexer {
+    /// Returns a builder to create an indexer with a min shift, depth, and auxiliary data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let builder = csi::index::Indexer::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Creates an indexer with a depth that can address the longest reference sequence.
+    ///
+    /// The depth is increased from the default when the given max reference sequence length
+    /// cannot be addressed with the given min shift.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let indexer = csi::index::Indexer::with_max_reference_sequence_length(14, 1 << 32);
+    /// ```
+    pub fn with_max_reference_sequence_length(
+        min_shift: u8,
+        max_reference_sequence_length: usize,
+    ) -> Self {
+        Self::builder()
+            .set_min_shift(min_shift)
+            .set_depth(calculate_depth(min_shift, max_reference_sequence_length))
+            .build()
+    }
+
+    /// Adds a record.
+    ///
+    /// An unplaced, unmapped record has no alignment context.
+    ///
+    /// Records must be added in coordinate-sorted order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::{
+    ///     self as csi,
+    ///     index::{indexer::AlignmentContext, reference_sequence::bin::Chunk},
+    /// };
+    ///
+    /// let mut indexer = csi::Index::indexer();
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    ///
+    /// indexer.add_record(
+    ///     Some(AlignmentContext::new(0, start, end, true)),
+    ///     Chunk::new(
+    ///         bgzf::VirtualPosition::from(144),
+    ///         bgzf::VirtualPosition::from(233),
+    ///     ),
+    /// )?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_record(
+        &mut self,
+        alignment_context: Option<AlignmentContext>,
+        chunk: Chunk,
+    ) -> io::Result<()> {
+        let (reference_sequence_id, start, end, is_mapped) = match alignment_context {
+            Some(context) => (
+                context.reference_sequence_id(),
+                context.start(),
+                context.end(),
+                context.is_mapped(),
+            ),
+            None => {
+                self.unplaced_unmapped_record_count += 1;
+                return Ok(());
+            }
+        };
+
+        if let Some(last_position) = self.last_position {
+            if (reference_sequence_id, start) < last_position {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid record order: records are not coordinate-sorted",
+                ));
+            }
+        }
+
+        let max_position = ReferenceSequence::max_position(self.min_shift, self.depth)?;
+
+        if start > end || end > max_position {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid record interval: expected {}..={} to be within 1..={}",
+                    start, end, max_position
+                ),
+            ));
+        }
+
+        if reference_sequence_id >= self.reference_sequence_builders.len() {
+            self.reference_sequence_builders
+                .resize_with(reference_sequence_id + 1, Default::default);
+        }
+
+        self.reference_sequence_builders[reference_sequence_id].add_record(
+            self.min_shift,
+            self.depth,
+            start,
+            end,
+            is_mapped,
+            chunk,
+        );
+
+        self.last_position = Some((reference_sequence_id, start));
+
+        Ok(())
+    }
+
+    /// Builds a coordinate-sorted index.
+    ///
+    /// The reference sequence count is the number of reference sequences in the associated file.
+    /// Reference sequences that have no records are included as empty reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::{self as csi, BinningIndex};
+    /// let indexer = csi::Index::indexer();
+    /// let index = indexer.build(2);
+    /// assert_eq!(index.reference_sequences().len(), 2);
+    /// ```
+    pub fn build(mut self, reference_sequence_count: usize) -> Index {
+        if reference_sequence_count > self.reference_sequence_builders.len() {
+            self.reference_sequence_builders
+                .resize_with(reference_sequence_count, Default::default);
+        }
+
+        let depth = self.depth;
+
+        let reference_sequences = self
+            .reference_sequence_builders
+            .into_iter()
+            .map(|builder| builder.build(depth))
+            .collect();
+
+        Index::builder()
+            .set_min_shift(self.min_shift)
+            .set_depth(self.depth)
+            .set_aux(self.aux)
+            .set_reference_sequences(reference_sequences)
+            .set_unplaced_unmapped_record_count(self.unplaced_unmapped_record_count)
+            .build()
+    }
+}
+
+impl Default for Indexer {
+    fn default() -> Self {
+        Builder::default().build()
+    }
+}
+
+// Records can extend past the end of a reference sequence, which `htslib` accounts for by padding
+// the length.
+fn calculate_depth(min_shift: u8, max_reference_sequence_length: usize) -> u8 {
+    const PADDING: u64 = 256;
+
+    let max_position = max_reference_sequence_length as u64 + PADDING;
+    let mut depth = DEFAULT_DEPTH;
+
+    while (1 << (u64::from(min_shift) + 3 * u64::from(depth))) - 1 < max_position {
+        depth += 1;
+    }
+
+    depth
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+    use crate::BinningIndex;
+
+    fn chunk() -> Chunk {
+        Chunk::new(
+            bgzf::VirtualPosition::default(),
+            bgzf::VirtualPosition::default(),
+        )
+    }
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        let mut indexer = Indexer::default();
+
+        indexer.add_record(
+            Some(AlignmentContext::new(
+                1,
+                Position::try_from(8)?,
+                Position::try_from(13)?,
+                true,
+            )),
+            Chunk::new(
+                bgzf::VirtualPosition::from(144),
+                bgzf::VirtualPosition::from(233),
+            ),
+        )?;
+
+        indexer.add_record(
+            None,
+            Chunk::new(
+                bgzf::VirtualPosition::from(233),
+                bgzf::VirtualPosition::from(377),
+            ),
+        )?;
+
+        let index = indexer.build(3);
+
+        let reference_sequences = index.reference_sequences();
+        assert_eq!(reference_sequences.len(), 3);
+        assert!(reference_sequences[0].bins().is_empty());
+        assert_eq!(reference_sequences[1].bins().len(), 1);
+        assert!(reference_sequences[2].bins().is_empty());
+
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let mut indexer = Indexer::default();
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+
+        indexer.add_record(Some(AlignmentContext::new(1, start, end, true)), chunk())?;
+
+        assert!(matches!(
+            indexer.add_record(Some(AlignmentContext::new(0, start, end, true)), chunk()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let start = Position::try_from(5)?;
+
+        assert!(matches!(
+            indexer.add_record(Some(AlignmentContext::new(1, start, end, true)), chunk()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_large_position() -> Result<(), Box<dyn std::error::Error>> {
+        let start = Position::try_from(1 << 29)?;
+        let end = start;
+
+        let mut indexer = Indexer::default();
+
+        assert!(matches!(
+            indexer.add_record(Some(AlignmentContext::new(0, start, end, true)), chunk()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut indexer = Indexer::builder().set_depth(6).build();
+        indexer.add_record(Some(AlignmentContext::new(0, start, end, true)), chunk())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_depth() {
+        assert_eq!(calculate_depth(DEFAULT_MIN_SHIFT, 0), DEFAULT_DEPTH);
+        assert_eq!(calculate_depth(DEFAULT_MIN_SHIFT, 248956422), DEFAULT_DEPTH);
+        assert_eq!(calculate_depth(DEFAULT_MIN_SHIFT, (1 << 29) - 1), 6);
+        assert_eq!(calculate_depth(DEFAULT_MIN_SHIFT, 1 << 32), 7);
+    }
+}
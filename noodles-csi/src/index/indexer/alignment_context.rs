@@ -0,0 +1,106 @@
+use noodles_core::Position;
+
+/// The alignment context of a record added to an indexer.
+///
+/// This is the reference sequence ID, the start and end positions, and whether the record is
+/// mapped.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AlignmentContext {
+    reference_sequence_id: usize,
+    start: Position,
+    end: Position,
+    is_mapped: bool,
+}
+
+impl AlignmentContext {
+    /// Creates an alignment context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::indexer::AlignmentContext;
+    ///
+    /// let context = AlignmentContext::new(0, Position::try_from(8)?, Position::try_from(13)?, true);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn new(
+        reference_sequence_id: usize,
+        start: Position,
+        end: Position,
+        is_mapped: bool,
+    ) -> Self {
+        Self {
+            reference_sequence_id,
+            start,
+            end,
+            is_mapped,
+        }
+    }
+
+    /// Returns the reference sequence ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::indexer::AlignmentContext;
+    ///
+    /// let context = AlignmentContext::new(0, Position::try_from(8)?, Position::try_from(13)?, true);
+    /// assert_eq!(context.reference_sequence_id(), 0);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn reference_sequence_id(&self) -> usize {
+        self.reference_sequence_id
+    }
+
+    /// Returns the start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::indexer::AlignmentContext;
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let context = AlignmentContext::new(0, start, Position::try_from(13)?, true);
+    /// assert_eq!(context.start(), start);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::indexer::AlignmentContext;
+    ///
+    /// let end = Position::try_from(13)?;
+    /// let context = AlignmentContext::new(0, Position::try_from(8)?, end, true);
+    /// assert_eq!(context.end(), end);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns whether the record is mapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::indexer::AlignmentContext;
+    ///
+    /// let context = AlignmentContext::new(0, Position::try_from(8)?, Position::try_from(13)?, true);
+    /// assert!(context.is_mapped());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn is_mapped(&self) -> bool {
+        self.is_mapped
+    }
+}
@@ -0,0 +1,79 @@
+use super::{Indexer, DEFAULT_DEPTH, DEFAULT_MIN_SHIFT};
+
+/// A coordinate-sorted index (CSI) indexer builder.
+#[derive(Debug)]
+pub struct Builder {
+    min_shift: u8,
+    depth: u8,
+    aux: Vec<u8>,
+}
+
+impl Builder {
+    /// Sets the number of bits for the minimum interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let indexer = csi::index::Indexer::builder().set_min_shift(13).build();
+    /// ```
+    pub fn set_min_shift(mut self, min_shift: u8) -> Self {
+        self.min_shift = min_shift;
+        self
+    }
+
+    /// Sets the depth of the binning index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let indexer = csi::index::Indexer::builder().set_depth(6).build();
+    /// ```
+    pub fn set_depth(mut self, depth: u8) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Sets auxiliary data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let indexer = csi::index::Indexer::builder().set_aux(b"ndls".to_vec()).build();
+    /// ```
+    pub fn set_aux(mut self, aux: Vec<u8>) -> Self {
+        self.aux = aux;
+        self
+    }
+
+    /// Builds a coordinate-sorted index (CSI) indexer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let indexer = csi::index::Indexer::builder().build();
+    /// ```
+    pub fn build(self) -> Indexer {
+        Indexer {
+            min_shift: self.min_shift,
+            depth: self.depth,
+            aux: self.aux,
+            reference_sequence_builders: Vec::new(),
+            last_position: None,
+            unplaced_unmapped_record_count: 0,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            min_shift: DEFAULT_MIN_SHIFT,
+            depth: DEFAULT_DEPTH,
+            aux: Vec::new(),
+        }
+    }
+}
@@ -1,8 +1,11 @@
 //! Coordinate-sorted index (CSI) reference sequence and fields.
 
 pub mod bin;
+mod builder;
 mod metadata;
 
+pub(crate) use self::builder::Builder;
+
 pub use self::{bin::Bin, metadata::Metadata};
 
 use std::{io, num::NonZeroUsize};
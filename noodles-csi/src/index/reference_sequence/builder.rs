@@ -0,0 +1,246 @@
+use std::{cmp, collections::HashMap};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+
+use super::{bin::Chunk, reg2bin, Bin, Metadata, ReferenceSequence};
+
+#[derive(Debug, Default)]
+pub(crate) struct Builder {
+    bins: HashMap<usize, Vec<Chunk>>,
+    linear_index: Vec<Option<bgzf::VirtualPosition>>,
+    start_position: Option<bgzf::VirtualPosition>,
+    end_position: bgzf::VirtualPosition,
+    mapped_record_count: u64,
+    unmapped_record_count: u64,
+}
+
+impl Builder {
+    pub(crate) fn add_record(
+        &mut self,
+        min_shift: u8,
+        depth: u8,
+        start: Position,
+        end: Position,
+        is_mapped: bool,
+        chunk: Chunk,
+    ) -> &mut Self {
+        self.update_bins(min_shift, depth, start, end, chunk);
+        self.update_linear_index(min_shift, start, end, chunk);
+        self.update_metadata(is_mapped, chunk);
+        self
+    }
+
+    pub(crate) fn build(self, depth: u8) -> ReferenceSequence {
+        if self.bins.is_empty() {
+            return ReferenceSequence::new(Vec::new(), None);
+        }
+
+        let linear_index = fill_linear_index(self.linear_index);
+
+        let mut bins: Vec<_> = self
+            .bins
+            .into_iter()
+            .map(|(id, chunks)| {
+                let i = first_window_index(id, depth);
+                let loffset = linear_index.get(i).copied().unwrap_or_default();
+                Bin::new(id, loffset, chunks)
+            })
+            .collect();
+
+        bins.sort_unstable_by_key(|bin| bin.id());
+
+        let metadata = Metadata::new(
+            self.start_position.unwrap_or_default(),
+            self.end_position,
+            self.mapped_record_count,
+            self.unmapped_record_count,
+        );
+
+        ReferenceSequence::new(bins, Some(metadata))
+    }
+
+    fn update_bins(
+        &mut self,
+        min_shift: u8,
+        depth: u8,
+        start: Position,
+        end: Position,
+        chunk: Chunk,
+    ) {
+        let id = reg2bin(start, end, min_shift, depth);
+        let chunks = self.bins.entry(id).or_default();
+
+        if let Some(last_chunk) = chunks.last_mut() {
+            if chunk.start() <= last_chunk.end() {
+                *last_chunk = Chunk::new(last_chunk.start(), chunk.end());
+                return;
+            }
+        }
+
+        chunks.push(chunk);
+    }
+
+    fn update_linear_index(&mut self, min_shift: u8, start: Position, end: Position, chunk: Chunk) {
+        let start_index = (usize::from(start) - 1) >> min_shift;
+        let end_index = (usize::from(end) - 1) >> min_shift;
+
+        if end_index >= self.linear_index.len() {
+            self.linear_index.resize(end_index + 1, None);
+        }
+
+        for position in &mut self.linear_index[start_index..=end_index] {
+            position.get_or_insert(chunk.start());
+        }
+    }
+
+    fn update_metadata(&mut self, is_mapped: bool, chunk: Chunk) {
+        if is_mapped {
+            self.mapped_record_count += 1;
+        } else {
+            self.unmapped_record_count += 1;
+        }
+
+        self.start_position = Some(match self.start_position {
+            Some(start_position) => cmp::min(start_position, chunk.start()),
+            None => chunk.start(),
+        });
+
+        self.end_position = cmp::max(self.end_position, chunk.end());
+    }
+}
+
+// Windows with no records take the position of the previous window.
+fn fill_linear_index(
+    linear_index: Vec<Option<bgzf::VirtualPosition>>,
+) -> Vec<bgzf::VirtualPosition> {
+    let mut last_position = bgzf::VirtualPosition::default();
+
+    linear_index
+        .into_iter()
+        .map(|position| {
+            if let Some(position) = position {
+                last_position = position;
+            }
+
+            last_position
+        })
+        .collect()
+}
+
+// Returns the index of the first minimum interval (window) covered by the given bin.
+fn first_window_index(id: usize, depth: u8) -> usize {
+    let mut level = 0;
+    let mut first_id = 0;
+
+    loop {
+        let next_first_id = first_id + (1 << (3 * level));
+
+        if id < next_first_id || level >= depth {
+            break;
+        }
+
+        first_id = next_first_id;
+        level += 1;
+    }
+
+    (id - first_id) << (3 * (depth - level))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIN_SHIFT: u8 = 4;
+    const DEPTH: u8 = 2;
+
+    #[test]
+    fn test_build() -> Result<(), noodles_core::position::TryFromIntError> {
+        let mut builder = Builder::default();
+
+        builder.add_record(
+            MIN_SHIFT,
+            DEPTH,
+            Position::try_from(8)?,
+            Position::try_from(13)?,
+            true,
+            Chunk::new(
+                bgzf::VirtualPosition::from(233),
+                bgzf::VirtualPosition::from(377),
+            ),
+        );
+
+        builder.add_record(
+            MIN_SHIFT,
+            DEPTH,
+            Position::try_from(9)?,
+            Position::try_from(10)?,
+            false,
+            Chunk::new(
+                bgzf::VirtualPosition::from(377),
+                bgzf::VirtualPosition::from(610),
+            ),
+        );
+
+        builder.add_record(
+            MIN_SHIFT,
+            DEPTH,
+            Position::try_from(34)?,
+            Position::try_from(55)?,
+            true,
+            Chunk::new(
+                bgzf::VirtualPosition::from(610),
+                bgzf::VirtualPosition::from(987),
+            ),
+        );
+
+        let actual = builder.build(DEPTH);
+
+        let expected = ReferenceSequence::new(
+            vec![
+                Bin::new(
+                    1,
+                    bgzf::VirtualPosition::from(233),
+                    vec![Chunk::new(
+                        bgzf::VirtualPosition::from(610),
+                        bgzf::VirtualPosition::from(987),
+                    )],
+                ),
+                Bin::new(
+                    9,
+                    bgzf::VirtualPosition::from(233),
+                    vec![Chunk::new(
+                        bgzf::VirtualPosition::from(233),
+                        bgzf::VirtualPosition::from(610),
+                    )],
+                ),
+            ],
+            Some(Metadata::new(
+                bgzf::VirtualPosition::from(233),
+                bgzf::VirtualPosition::from(987),
+                2,
+                1,
+            )),
+        );
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_no_records() {
+        let reference_sequence = Builder::default().build(DEPTH);
+        assert_eq!(reference_sequence, ReferenceSequence::new(Vec::new(), None));
+    }
+
+    #[test]
+    fn test_first_window_index() {
+        assert_eq!(first_window_index(0, DEPTH), 0);
+        assert_eq!(first_window_index(1, DEPTH), 0);
+        assert_eq!(first_window_index(2, DEPTH), 8);
+        assert_eq!(first_window_index(9, DEPTH), 0);
+        assert_eq!(first_window_index(17, DEPTH), 8);
+        assert_eq!(first_window_index(72, DEPTH), 63);
+    }
+}
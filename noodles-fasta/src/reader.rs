@@ -21,6 +21,14 @@ pub(crate) const NEWLINE: u8 = b'\n';
 const LINE_FEED: char = '\n';
 const CARRIAGE_RETURN: char = '\r';
 
+/// A buffered reader that can seek.
+///
+/// This allows an indexed reader to use either a raw or a bgzip-compressed source. It is `Send`
+/// so that the reader can be used as a repository adapter.
+pub trait BufReadSeek: BufRead + Seek + Send {}
+
+impl<T> BufReadSeek for T where T: BufRead + Seek + Send {}
+
 /// A FASTA reader.
 pub struct Reader<R> {
     inner: R,
@@ -168,6 +176,9 @@ where
 {
     /// Returns a record of the given region.
     ///
+    /// Only the bases in the region are read from the stream. The start of the region is located
+    /// using the line bases and line width of its index record.
+    ///
     /// # Examples
     ///
     /// ```
@@ -205,16 +216,33 @@ where
         let i = resolve_region(index, region)?;
         let index_record = &index[i];
 
-        let pos = index_record.offset();
+        let len = usize::try_from(index_record.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let range = interval_to_slice_range(region.interval(), len);
+
+        if range.start > range.end || range.end > len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid region interval: {}", region),
+            ));
+        }
+
+        let pos = index_record.offset() + line_offset(index_record, range.start as u64);
         self.seek(SeekFrom::Start(pos))?;
 
         let definition = Definition::new(region.to_string(), None);
 
-        let mut raw_sequence = Vec::new();
-        self.read_sequence(&mut raw_sequence)?;
+        let mut raw_sequence = Vec::with_capacity(range.len());
+        let bases_read = read_sequence_limit(&mut self.inner, range.len(), &mut raw_sequence)?;
+
+        if bases_read < range.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "unexpected end of sequence",
+            ));
+        }
 
-        let range = interval_to_slice_range(region.interval(), raw_sequence.len());
-        let sequence = Sequence::from(raw_sequence[range].to_vec());
+        let sequence = Sequence::from(raw_sequence);
 
         Ok(Record::new(definition, sequence))
     }
@@ -355,6 +383,53 @@ fn resolve_region(index: &[fai::Record], region: &Region) -> io::Result<usize> {
         })
 }
 
+// Returns the offset of the base at the given 0-based position from the start of the sequence.
+fn line_offset(index_record: &fai::Record, position: u64) -> u64 {
+    let line_bases = index_record.line_bases();
+
+    if line_bases == 0 {
+        return 0;
+    }
+
+    let line_width = index_record.line_width();
+    (position / line_bases) * line_width + position % line_bases
+}
+
+// Reads up to `max_bases` bases, skipping newlines.
+fn read_sequence_limit<R>(reader: &mut R, max_bases: usize, buf: &mut Vec<u8>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    let mut bases_read = 0;
+
+    while bases_read < max_bases {
+        let reader_buf = reader.fill_buf()?;
+
+        if reader_buf.is_empty() || reader_buf[0] == DEFINITION_PREFIX {
+            break;
+        }
+
+        let mut len = 0;
+
+        for &b in reader_buf {
+            if bases_read >= max_bases {
+                break;
+            }
+
+            len += 1;
+
+            if b != NEWLINE && b != CARRIAGE_RETURN as u8 {
+                buf.push(b);
+                bases_read += 1;
+            }
+        }
+
+        reader.consume(len);
+    }
+
+    Ok(bases_read)
+}
+
 // Shifts a 1-based interval to a 0-based range for slicing.
 fn interval_to_slice_range<I>(interval: I, len: usize) -> Range<usize>
 where
@@ -472,6 +547,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_query_with_multiline_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b">sq0\nNNNN\n>sq1\nACGT\r\nTGCA\r\nAC\r\n>sq2\nNNNN\n";
+        let index = vec![
+            fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+            fai::Record::new(String::from("sq1"), 10, 15, 4, 6),
+            fai::Record::new(String::from("sq2"), 4, 36, 4, 5),
+        ];
+
+        let mut reader = Reader::new(Cursor::new(&data[..]));
+
+        let record = reader.query(&index, &"sq1".parse()?)?;
+        assert_eq!(record.sequence().as_ref(), b"ACGTTGCAAC");
+
+        let record = reader.query(&index, &"sq1:3-6".parse()?)?;
+        assert_eq!(record.sequence().as_ref(), b"GTTG");
+
+        let record = reader.query(&index, &"sq1:8-10".parse()?)?;
+        assert_eq!(record.sequence().as_ref(), b"AAC");
+
+        let record = reader.query(&index, &"sq2:4-4".parse()?)?;
+        assert_eq!(record.sequence().as_ref(), b"N");
+
+        assert!(matches!(
+            reader.query(&index, &"sq1:8-11".parse()?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_line_offset() {
+        let index_record = fai::Record::new(String::from("sq0"), 10, 5, 4, 5);
+
+        assert_eq!(line_offset(&index_record, 0), 0);
+        assert_eq!(line_offset(&index_record, 3), 3);
+        assert_eq!(line_offset(&index_record, 4), 5);
+        assert_eq!(line_offset(&index_record, 9), 11);
+    }
+
     #[test]
     fn test_interval_to_slice_range() -> Result<(), noodles_core::position::TryFromIntError> {
         use noodles_core::Position;
@@ -488,4 +604,46 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_query_with_bgzf_indexed_reader() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        use crate::record::{Definition, Sequence};
+
+        let mut writer = bgzf::Writer::builder(Vec::new())
+            .build_gzi_index(true)
+            .build();
+
+        writer.write_all(b">sq0\nNNNN\n")?;
+        writer.flush()?;
+        writer.write_all(b">sq1\nACGT\n")?;
+        writer.try_finish()?;
+
+        let gzi_index = writer.gzi_index().cloned().unwrap();
+        let data = writer.into_inner();
+
+        let index = vec![
+            fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+            fai::Record::new(String::from("sq1"), 4, 15, 4, 5),
+        ];
+
+        let mut reader = Reader::new(bgzf::IndexedReader::new(Cursor::new(data), gzi_index));
+
+        let region = "sq1:2-3".parse()?;
+        let record = reader.query(&index, &region)?;
+        assert_eq!(
+            record,
+            Record::new(
+                Definition::new("sq1:2-3", None),
+                Sequence::from(b"CG".to_vec())
+            )
+        );
+
+        let region = "sq0".parse()?;
+        let record = reader.query(&index, &region)?;
+        assert_eq!(record.sequence().as_ref(), b"NNNN");
+
+        Ok(())
+    }
 }
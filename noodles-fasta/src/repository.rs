@@ -2,77 +2,179 @@
 
 mod adapter;
 pub mod adapters;
+mod cache;
 
-pub use self::adapter::Adapter;
+pub use self::{
+    adapter::Adapter,
+    cache::{CacheCapacity, CacheStats},
+};
 
 use std::{
-    collections::HashMap,
     fmt, io,
-    sync::{Arc, RwLock},
+    sync::{Arc, Mutex, RwLock},
 };
 
+use noodles_core::Region;
+
+use self::cache::Cache;
 use super::record::Sequence;
 
+// The adapter and cache are locked separately so that cache hits are not blocked by an adapter
+// fetch.
 struct AdapterCache {
-    adapter: Box<dyn Adapter>,
-    cache: HashMap<String, Sequence>,
+    adapter: Mutex<Box<dyn Adapter + Send>>,
+    cache: RwLock<Cache>,
 }
 
 /// A caching sequence repository.
-pub struct Repository(Arc<RwLock<AdapterCache>>);
+///
+/// By default, the cache is unbounded. Use [`Self::with_cache_capacity`] to limit the number or
+/// total length of cached sequences, in which case the least recently used sequences are evicted
+/// first.
+pub struct Repository(Arc<AdapterCache>);
 
 impl Repository {
     /// Creates a sequence repository.
     pub fn new<A>(adapter: A) -> Self
     where
-        A: Adapter + 'static,
+        A: Adapter + Send + 'static,
+    {
+        Self::with_cache_capacity(adapter, CacheCapacity::default())
+    }
+
+    /// Creates a sequence repository with a bounded cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, repository::CacheCapacity};
+    ///
+    /// // Cache at most 1 GiB of sequences.
+    /// let repository = fasta::Repository::with_cache_capacity(
+    ///     Vec::new(),
+    ///     CacheCapacity::Bytes(1 << 30),
+    /// );
+    /// ```
+    pub fn with_cache_capacity<A>(adapter: A, capacity: CacheCapacity) -> Self
+    where
+        A: Adapter + Send + 'static,
     {
-        Self(Arc::new(RwLock::new(AdapterCache {
-            adapter: Box::new(adapter),
-            cache: HashMap::new(),
-        })))
+        Self(Arc::new(AdapterCache {
+            adapter: Mutex::new(Box::new(adapter)),
+            cache: RwLock::new(Cache::new(capacity)),
+        }))
     }
 
     /// Returns the sequence of the given name.
     pub fn get(&self, name: &str) -> Option<io::Result<Sequence>> {
-        {
-            let lock = self.0.read().unwrap();
-
-            if let Some(sequence) = lock.cache.get(name) {
-                return Some(Ok(sequence.clone()));
-            }
+        if let Some(sequence) = self.0.cache.read().unwrap().get(name) {
+            return Some(Ok(sequence));
         }
 
-        let mut lock = self.0.write().unwrap();
+        let mut adapter = self.0.adapter.lock().unwrap();
 
-        let record = match lock.adapter.get(name)? {
+        // The sequence may have been fetched while waiting for the adapter.
+        if let Some(sequence) = self.0.cache.read().unwrap().peek(name) {
+            return Some(Ok(sequence));
+        }
+
+        let record = match adapter.get(name)? {
             Ok(record) => record,
             Err(e) => return Some(Err(e)),
         };
 
-        lock.cache
-            .entry(name.into())
-            .or_insert_with(|| record.sequence().clone());
+        self.0
+            .cache
+            .write()
+            .unwrap()
+            .insert(name.into(), record.sequence().clone());
 
         Some(Ok(record.sequence().clone()))
     }
 
+    /// Returns the subsequence of the given region.
+    ///
+    /// If the entire sequence is cached, the subsequence is sliced from it. Otherwise, the region
+    /// is fetched using [`Adapter::get_region`], and the subsequence is returned without being
+    /// cached. This allows adapters that read subsequences directly (e.g., from an indexed
+    /// source) to avoid loading entire sequences, e.g., for each CRAM slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_core::Region;
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+    ///
+    /// let repository = fasta::Repository::new(vec![fasta::Record::new(
+    ///     Definition::new("sq0", None),
+    ///     Sequence::from(b"ACGT".to_vec()),
+    /// )]);
+    ///
+    /// let region = "sq0:2-3".parse()?;
+    /// let sequence = repository.get_region(&region).transpose()?;
+    /// assert_eq!(sequence, Some(Sequence::from(b"CG".to_vec())));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn get_region(&self, region: &Region) -> Option<io::Result<Sequence>> {
+        if let Some(sequence) = self.0.cache.read().unwrap().get(region.name()) {
+            return Some(slice(&sequence, region));
+        }
+
+        let mut adapter = self.0.adapter.lock().unwrap();
+
+        // The entire sequence may have been fetched while waiting for the adapter.
+        if let Some(sequence) = self.0.cache.read().unwrap().peek(region.name()) {
+            return Some(slice(&sequence, region));
+        }
+
+        let result = adapter
+            .get_region(region)?
+            .map(|record| record.sequence().clone());
+
+        Some(result)
+    }
+
+    /// Returns the cache statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let repository = fasta::Repository::default();
+    /// let stats = repository.cache_stats();
+    /// assert_eq!(stats.hits(), 0);
+    /// assert_eq!(stats.misses(), 0);
+    /// ```
+    pub fn cache_stats(&self) -> CacheStats {
+        self.0.cache.read().unwrap().stats()
+    }
+
     /// Returns the number of cached sequences.
     pub fn len(&self) -> usize {
-        self.0.read().unwrap().cache.len()
+        self.0.cache.read().unwrap().len()
     }
 
     /// Returns whether any sequences are cached.
     pub fn is_empty(&self) -> bool {
-        self.0.read().unwrap().cache.is_empty()
+        self.0.cache.read().unwrap().is_empty()
     }
 
     /// Clears the sequence cache.
     pub fn clear(&self) {
-        self.0.write().unwrap().cache.clear();
+        self.0.cache.write().unwrap().clear();
     }
 }
 
+fn slice(sequence: &Sequence, region: &Region) -> io::Result<Sequence> {
+    sequence.slice(region.interval()).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid region interval: {}", region),
+        )
+    })
+}
+
 impl Clone for Repository {
     fn clone(&self) -> Self {
         Self(self.0.clone())
@@ -82,7 +184,7 @@ impl Clone for Repository {
 impl fmt::Debug for Repository {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Repository")
-            .field("cache", &self.0.read().unwrap().cache)
+            .field("cache", &*self.0.cache.read().unwrap())
             .finish()
     }
 }
@@ -101,6 +203,12 @@ mod tests {
         Record,
     };
 
+    #[test]
+    fn test_send_and_sync() {
+        fn is_send_and_sync<T: Send + Sync>() {}
+        is_send_and_sync::<Repository>();
+    }
+
     #[test]
     fn test_get() -> io::Result<()> {
         let sq0 = Record::new(
@@ -117,4 +225,152 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_fetches_once() -> io::Result<()> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct CountingAdapter {
+            records: Vec<Record>,
+            fetch_count: Arc<AtomicUsize>,
+        }
+
+        impl Adapter for CountingAdapter {
+            fn get(&mut self, name: &str) -> Option<io::Result<Record>> {
+                self.fetch_count.fetch_add(1, Ordering::SeqCst);
+                self.records.get(name)
+            }
+        }
+
+        let fetch_count = Arc::new(AtomicUsize::new(0));
+        let repository = Repository::new(CountingAdapter {
+            records: vec![Record::new(
+                Definition::new("sq0", None),
+                Sequence::from(b"ACGT".to_vec()),
+            )],
+            fetch_count: fetch_count.clone(),
+        });
+
+        for _ in 0..3 {
+            assert!(repository.get("sq0").transpose()?.is_some());
+        }
+
+        assert_eq!(fetch_count.load(Ordering::SeqCst), 1);
+
+        let stats = repository.cache_stats();
+        assert_eq!(stats.hits(), 2);
+        assert_eq!(stats.misses(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_region() -> Result<(), Box<dyn std::error::Error>> {
+        let repository = Repository::new(vec![Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGTACGT".to_vec()),
+        )]);
+
+        for (raw_region, expected) in [("sq0:2-3", &b"CG"[..]), ("sq0:5-8", &b"ACGT"[..])] {
+            let region = raw_region.parse()?;
+            let sequence = repository.get_region(&region).transpose()?;
+            assert_eq!(sequence, Some(Sequence::from(expected.to_vec())));
+        }
+
+        assert!(repository.is_empty());
+
+        assert!(repository.get("sq0").transpose()?.is_some());
+        let sequence = repository.get_region(&"sq0:2-3".parse()?).transpose()?;
+        assert_eq!(sequence, Some(Sequence::from(b"CG".to_vec())));
+
+        let stats = repository.cache_stats();
+        assert_eq!(stats.hits(), 1);
+        assert_eq!(stats.misses(), 3);
+
+        assert!(repository.get_region(&"sq1:2-3".parse()?).is_none());
+
+        assert!(matches!(
+            repository.get_region(&"sq0:5-13".parse()?),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_region_does_not_fetch_entire_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[derive(Default)]
+        struct Counts {
+            get: AtomicUsize,
+            get_region: AtomicUsize,
+        }
+
+        struct CountingAdapter {
+            records: Vec<Record>,
+            counts: Arc<Counts>,
+        }
+
+        impl Adapter for CountingAdapter {
+            fn get(&mut self, name: &str) -> Option<io::Result<Record>> {
+                self.counts.get.fetch_add(1, Ordering::SeqCst);
+                self.records.get(name)
+            }
+
+            fn get_region(&mut self, region: &Region) -> Option<io::Result<Record>> {
+                self.counts.get_region.fetch_add(1, Ordering::SeqCst);
+                self.records.get_region(region)
+            }
+        }
+
+        let counts = Arc::new(Counts::default());
+        let repository = Repository::new(CountingAdapter {
+            records: vec![Record::new(
+                Definition::new("sq0", None),
+                Sequence::from(b"ACGTACGT".to_vec()),
+            )],
+            counts: counts.clone(),
+        });
+
+        for (raw_region, expected) in [("sq0:2-3", &b"CG"[..]), ("sq0:5-8", &b"ACGT"[..])] {
+            let region = raw_region.parse()?;
+            let sequence = repository.get_region(&region).transpose()?;
+            assert_eq!(sequence, Some(Sequence::from(expected.to_vec())));
+        }
+
+        assert_eq!(counts.get.load(Ordering::SeqCst), 0);
+        assert_eq!(counts.get_region.load(Ordering::SeqCst), 2);
+        assert!(repository.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_with_cache_capacity() -> io::Result<()> {
+        let records: Vec<_> = ["sq0", "sq1", "sq2"]
+            .into_iter()
+            .map(|name| {
+                Record::new(
+                    Definition::new(name, None),
+                    Sequence::from(b"ACGT".to_vec()),
+                )
+            })
+            .collect();
+
+        let repository = Repository::with_cache_capacity(records, CacheCapacity::Sequences(2));
+
+        for name in ["sq0", "sq1", "sq0", "sq2", "sq0", "sq1"] {
+            assert!(repository.get(name).transpose()?.is_some());
+        }
+
+        assert_eq!(repository.len(), 2);
+
+        let stats = repository.cache_stats();
+        assert_eq!(stats.hits(), 2);
+        assert_eq!(stats.misses(), 4);
+        assert_eq!(stats.evictions(), 2);
+
+        Ok(())
+    }
 }
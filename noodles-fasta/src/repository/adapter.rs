@@ -1,9 +1,70 @@
 use std::io;
 
-use crate::Record;
+use noodles_core::Region;
+
+use crate::{record::Definition, Record};
 
 /// A repository adapter.
 pub trait Adapter {
     /// Returns the record with the given name.
     fn get(&mut self, name: &str) -> Option<io::Result<Record>>;
+
+    /// Returns a record of the given region.
+    ///
+    /// The returned record only includes the bases in the region. Its definition name is the
+    /// region string.
+    ///
+    /// By default, this gets the entire record by name and then slices its sequence. Adapters
+    /// that can read subsequences directly (e.g., from an indexed source) should override this.
+    fn get_region(&mut self, region: &Region) -> Option<io::Result<Record>> {
+        let record = match self.get(region.name())? {
+            Ok(record) => record,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let result = record
+            .sequence()
+            .slice(region.interval())
+            .map(|sequence| Record::new(Definition::new(region.to_string(), None), sequence))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid region interval: {}", region),
+                )
+            });
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::Sequence;
+
+    #[test]
+    fn test_get_region() -> Result<(), Box<dyn std::error::Error>> {
+        let mut adapter = vec![Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGTACGT".to_vec()),
+        )];
+
+        let record = adapter.get_region(&"sq0:3-5".parse()?).transpose()?;
+        assert_eq!(
+            record,
+            Some(Record::new(
+                Definition::new("sq0:3-5", None),
+                Sequence::from(b"GTA".to_vec())
+            ))
+        );
+
+        assert!(adapter.get_region(&"sq1:3-5".parse()?).is_none());
+
+        assert!(matches!(
+            adapter.get_region(&"sq0:3-13".parse()?),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
 }
@@ -2,22 +2,22 @@ mod builder;
 
 pub use self::builder::Builder;
 
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader, Seek},
-};
+use std::io::{self, BufRead, Seek};
 
 use noodles_core::Region;
 
-use crate::{fai, repository::Adapter, Reader, Record};
+use crate::{fai, reader::BufReadSeek, repository::Adapter, Reader, Record};
 
 /// An indexed reader adapter.
+///
+/// The underlying reader can be a raw FASTA or, when wrapped in a [`noodles_bgzf::IndexedReader`],
+/// a bgzip-compressed FASTA.
 pub struct IndexedReader<R> {
     reader: Reader<R>,
     index: fai::Index,
 }
 
-impl IndexedReader<BufReader<File>> {
+impl IndexedReader<Box<dyn BufReadSeek>> {
     /// Creates an indexed reader adapter builder for paths on a filesystem.
     ///
     /// # Examples
@@ -59,4 +59,61 @@ where
         let region = Region::new(name, ..);
         Some(self.reader.query(&self.index, &region))
     }
+
+    fn get_region(&mut self, region: &Region) -> Option<io::Result<Record>> {
+        if self
+            .index
+            .iter()
+            .any(|record| record.name() == region.name())
+        {
+            Some(self.reader.query(&self.index, region))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+
+    #[test]
+    fn test_get_with_bgzf_source() -> io::Result<()> {
+        let mut writer = bgzf::Writer::builder(Vec::new())
+            .build_gzi_index(true)
+            .build();
+
+        writer.write_all(b">sq0\nNNNN\n")?;
+        writer.flush()?;
+        writer.write_all(b">sq1\nACGT\n")?;
+        writer.try_finish()?;
+
+        let gzi_index = writer.gzi_index().cloned().unwrap();
+        let data = writer.into_inner();
+
+        let inner: Box<dyn BufReadSeek> =
+            Box::new(bgzf::IndexedReader::new(Cursor::new(data), gzi_index));
+        let index = vec![
+            fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+            fai::Record::new(String::from("sq1"), 4, 15, 4, 5),
+        ];
+
+        let mut adapter = IndexedReader::new(Reader::new(inner), index);
+
+        let record = adapter.get("sq1").transpose()?.unwrap();
+        assert_eq!(record.sequence().as_ref(), b"ACGT");
+
+        let region = "sq1:2-3".parse().unwrap();
+        let record = adapter.get_region(&region).transpose()?.unwrap();
+        assert_eq!(record.sequence().as_ref(), b"CG");
+
+        let region = "sq2:2-3".parse().unwrap();
+        assert!(adapter.get_region(&region).is_none());
+
+        Ok(())
+    }
 }
@@ -1,12 +1,17 @@
 use std::{
     ffi::{OsStr, OsString},
     fs::File,
-    io::{self, BufReader},
+    io::{self, BufReader, Read, Seek, SeekFrom},
     path::{Path, PathBuf},
 };
 
+use noodles_bgzf::{self as bgzf, gzi};
+
 use super::IndexedReader;
-use crate::{fai, Reader};
+use crate::{fai, reader::BufReadSeek, Reader};
+
+// § 2.3.1 "Member format" (RFC 1952)
+const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
 
 /// An indexed reader adapter builder.
 ///
@@ -15,9 +20,13 @@ use crate::{fai, Reader};
 ///
 /// By default, it opens a [`Reader`] for a source path (`src`) and reads its associated index at
 /// `<src>.fai`. The index location can be overridden by calling [`set_index_src`].
+///
+/// If the source is bgzip-compressed, its gzip index (GZI) is also read from `<src>.gzi`. This
+/// location can be overridden by calling [`set_gzi_index_src`].
 #[derive(Default)]
 pub struct Builder {
     index_src: Option<PathBuf>,
+    gzi_index_src: Option<PathBuf>,
 }
 
 impl Builder {
@@ -39,11 +48,34 @@ impl Builder {
         self
     }
 
+    /// Sets the gzip index (GZI) source path.
+    ///
+    /// This is only used when the source is bgzip-compressed. When set, this path is used instead
+    /// of inferring one from the given source path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::repository::adapters::IndexedReader;
+    /// let builder = IndexedReader::builder().set_gzi_index_src("reference.fa.gz.gzi");
+    /// ```
+    pub fn set_gzi_index_src<P>(mut self, gzi_index_src: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.gzi_index_src = Some(gzi_index_src.into());
+        self
+    }
+
     /// Creates an indexed reader adapter from the given path.
     ///
     /// By default, `<src>.fai` is used as the path to the associated index. This can be overridden
     /// by calling [`set_index_src`].
     ///
+    /// The source may be bgzip-compressed (e.g., `reference.fa.gz`), which is detected by its
+    /// gzip magic number. In this case, `<src>.gzi` is used as the path to its gzip index, which
+    /// can be overridden by calling [`set_gzi_index_src`].
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -52,13 +84,28 @@ impl Builder {
     /// let adapter = IndexedReader::builder().open("reference.fa")?;
     /// # Ok::<_, io::Error>(())
     /// ```
-    pub fn open<P>(self, src: P) -> io::Result<IndexedReader<BufReader<File>>>
+    pub fn open<P>(self, src: P) -> io::Result<IndexedReader<Box<dyn BufReadSeek>>>
     where
         P: AsRef<Path>,
     {
         let src = src.as_ref();
 
-        let reader = File::open(src).map(BufReader::new).map(Reader::new)?;
+        let mut file = File::open(src)?;
+
+        // The BGZF reader buffers its own input, so only the raw source is wrapped in a
+        // `BufReader`.
+        let inner: Box<dyn BufReadSeek> = if is_gzip(&mut file)? {
+            let gzi_index_src = self
+                .gzi_index_src
+                .unwrap_or_else(|| push_ext(src.to_path_buf(), "gzi"));
+            let gzi_index = gzi::read(gzi_index_src)?;
+
+            Box::new(bgzf::IndexedReader::new(file, gzi_index))
+        } else {
+            Box::new(BufReader::new(file))
+        };
+
+        let reader = Reader::new(inner);
 
         let index_src = self
             .index_src
@@ -69,6 +116,24 @@ impl Builder {
     }
 }
 
+// Checks the stream for the gzip magic number and then restores the stream position.
+fn is_gzip<R>(reader: &mut R) -> io::Result<bool>
+where
+    R: Read + Seek,
+{
+    let position = reader.stream_position()?;
+
+    let mut buf = Vec::with_capacity(GZIP_MAGIC_NUMBER.len());
+    reader
+        .by_ref()
+        .take(GZIP_MAGIC_NUMBER.len() as u64)
+        .read_to_end(&mut buf)?;
+
+    reader.seek(SeekFrom::Start(position))?;
+
+    Ok(buf == GZIP_MAGIC_NUMBER)
+}
+
 fn push_ext<S>(path: PathBuf, ext: S) -> PathBuf
 where
     S: AsRef<OsStr>,
@@ -83,6 +148,28 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_gzip() -> io::Result<()> {
+        use std::io::Cursor;
+
+        let mut reader = Cursor::new([0x1f, 0x8b, 0x08, 0x04]);
+        assert!(is_gzip(&mut reader)?);
+        assert_eq!(reader.position(), 0);
+
+        let mut reader = Cursor::new(b">sq0\nACGT\n");
+        reader.set_position(1);
+        assert!(!is_gzip(&mut reader)?);
+        assert_eq!(reader.position(), 1);
+
+        let mut reader = Cursor::new([0x1f]);
+        assert!(!is_gzip(&mut reader)?);
+
+        let mut reader = Cursor::new([]);
+        assert!(!is_gzip(&mut reader)?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_push_ext() {
         assert_eq!(
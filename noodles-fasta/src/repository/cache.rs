@@ -0,0 +1,246 @@
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::record::Sequence;
+
+/// The capacity of a sequence repository cache.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CacheCapacity {
+    /// The cache is not bounded, i.e., sequences are never evicted.
+    Unbounded,
+    /// The max number of cached sequences.
+    Sequences(usize),
+    /// The max total length of cached sequences, in bytes.
+    Bytes(usize),
+}
+
+impl Default for CacheCapacity {
+    fn default() -> Self {
+        Self::Unbounded
+    }
+}
+
+/// Sequence repository cache statistics.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CacheStats {
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+impl CacheStats {
+    /// Returns the number of lookups that were served from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns the number of lookups that were not in the cache.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Returns the number of sequences evicted from the cache.
+    pub fn evictions(&self) -> u64 {
+        self.evictions
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    sequence: Sequence,
+    last_used: AtomicU64,
+}
+
+/// A least recently used (LRU) sequence cache.
+///
+/// Lookups only need a shared reference, so hits can be served under a read lock. Recency is
+/// tracked per entry, and the least recently used entry is found on eviction.
+#[derive(Debug, Default)]
+pub(super) struct Cache {
+    capacity: CacheCapacity,
+    entries: HashMap<String, Entry>,
+    tick: AtomicU64,
+    size: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: u64,
+}
+
+impl Cache {
+    pub fn new(capacity: CacheCapacity) -> Self {
+        Self {
+            capacity,
+            ..Default::default()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evictions: self.evictions,
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<Sequence> {
+        match self.entries.get(name) {
+            Some(entry) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                entry.last_used.store(self.next_tick(), Ordering::Relaxed);
+                Some(entry.sequence.clone())
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    // Returns a cached sequence without counting the lookup or marking it as used.
+    pub fn peek(&self, name: &str) -> Option<Sequence> {
+        self.entries.get(name).map(|entry| entry.sequence.clone())
+    }
+
+    pub fn insert(&mut self, name: String, sequence: Sequence) {
+        if self.entries.contains_key(&name) || !self.fits(1, sequence.len()) {
+            return;
+        }
+
+        while !self.fits(self.entries.len() + 1, self.size + sequence.len()) {
+            self.evict();
+        }
+
+        let tick = self.next_tick();
+
+        self.size += sequence.len();
+        self.entries.insert(
+            name,
+            Entry {
+                sequence,
+                last_used: AtomicU64::new(tick),
+            },
+        );
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.size = 0;
+    }
+
+    fn fits(&self, len: usize, size: usize) -> bool {
+        match self.capacity {
+            CacheCapacity::Unbounded => true,
+            CacheCapacity::Sequences(n) => len <= n,
+            CacheCapacity::Bytes(n) => size <= n,
+        }
+    }
+
+    fn evict(&mut self) {
+        let name = match self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used.load(Ordering::Relaxed))
+        {
+            Some((name, _)) => name.clone(),
+            None => return,
+        };
+
+        if let Some(entry) = self.entries.remove(&name) {
+            self.size -= entry.sequence.len();
+            self.evictions += 1;
+        }
+    }
+
+    fn next_tick(&self) -> u64 {
+        self.tick.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(len: usize) -> Sequence {
+        Sequence::from(vec![b'N'; len])
+    }
+
+    #[test]
+    fn test_insert_with_sequences_capacity() {
+        let mut cache = Cache::new(CacheCapacity::Sequences(2));
+
+        cache.insert(String::from("sq0"), sequence(4));
+        cache.insert(String::from("sq1"), sequence(4));
+        assert!(cache.get("sq0").is_some());
+
+        cache.insert(String::from("sq2"), sequence(4));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("sq0").is_some());
+        assert!(cache.get("sq1").is_none());
+        assert!(cache.get("sq2").is_some());
+
+        assert_eq!(cache.stats().hits(), 3);
+        assert_eq!(cache.stats().misses(), 1);
+        assert_eq!(cache.stats().evictions(), 1);
+    }
+
+    #[test]
+    fn test_insert_with_bytes_capacity() {
+        let mut cache = Cache::new(CacheCapacity::Bytes(10));
+
+        cache.insert(String::from("sq0"), sequence(4));
+        cache.insert(String::from("sq1"), sequence(4));
+        cache.insert(String::from("sq2"), sequence(4));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("sq0").is_none());
+
+        cache.insert(String::from("sq3"), sequence(10));
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get("sq3").is_some());
+
+        cache.insert(String::from("sq4"), sequence(11));
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get("sq4").is_none());
+        assert!(cache.get("sq3").is_some());
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut cache = Cache::new(CacheCapacity::Bytes(8));
+
+        cache.insert(String::from("sq0"), sequence(8));
+        cache.clear();
+        assert!(cache.is_empty());
+
+        cache.insert(String::from("sq1"), sequence(8));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut cache = Cache::new(CacheCapacity::Sequences(2));
+
+        cache.insert(String::from("sq0"), sequence(4));
+        cache.insert(String::from("sq1"), sequence(4));
+
+        assert!(cache.peek("sq0").is_some());
+        assert!(cache.peek("sq2").is_none());
+        assert_eq!(cache.stats(), CacheStats::default());
+
+        // Peeking does not mark sq0 as used, so it is still evicted first.
+        cache.insert(String::from("sq2"), sequence(4));
+        assert!(cache.peek("sq0").is_none());
+        assert!(cache.peek("sq1").is_some());
+    }
+}
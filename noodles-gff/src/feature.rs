@@ -0,0 +1,244 @@
+//! GFF3 feature graph.
+//!
+//! A feature graph connects records using the `ID`, `Parent`, and `Derives_from` attributes,
+//! e.g., gene → mRNA → exon/CDS.
+
+mod builder;
+
+pub use self::builder::{BuildError, Builder};
+
+use noodles_core::Position;
+
+use crate::Record;
+
+/// A GFF3 feature.
+///
+/// A feature is made of one or more records. Discontinuous features, e.g., a CDS spanning
+/// multiple exons, share the same `ID` and have one record per segment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Feature {
+    id: Option<String>,
+    records: Vec<Record>,
+    parents: Vec<usize>,
+    children: Vec<usize>,
+    derives_from: Vec<usize>,
+}
+
+impl Feature {
+    fn new(id: Option<String>, record: Record) -> Self {
+        Self {
+            id,
+            records: vec![record],
+            parents: Vec::new(),
+            children: Vec::new(),
+            derives_from: Vec::new(),
+        }
+    }
+
+    /// Returns the feature ID.
+    ///
+    /// This is the value of the `ID` attribute, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, feature};
+    ///
+    /// let record: gff::Record = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0".parse()?;
+    ///
+    /// let mut builder = feature::Graph::builder();
+    /// builder.add_record(record)?;
+    /// let graph = builder.build()?;
+    ///
+    /// assert_eq!(graph.features()[0].id(), Some("gene0"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Returns the records of the feature.
+    ///
+    /// A discontinuous feature has more than one record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, feature};
+    ///
+    /// let mut builder = feature::Graph::builder();
+    /// builder.add_record("sq0\tNOODLES\tCDS\t8\t13\t.\t+\t0\tID=cds0".parse()?)?;
+    /// builder.add_record("sq0\tNOODLES\tCDS\t21\t34\t.\t+\t0\tID=cds0".parse()?)?;
+    /// let graph = builder.build()?;
+    ///
+    /// assert_eq!(graph.features()[0].records().len(), 2);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Returns the reference sequence name of the feature.
+    pub fn reference_sequence_name(&self) -> &str {
+        self.records[0].reference_sequence_name()
+    }
+
+    /// Returns the feature type.
+    pub fn ty(&self) -> &str {
+        self.records[0].ty()
+    }
+
+    /// Returns the smallest start position of the feature records.
+    pub fn start(&self) -> Position {
+        self.records
+            .iter()
+            .map(|record| record.start())
+            .min()
+            .expect("missing records")
+    }
+
+    /// Returns the largest end position of the feature records.
+    pub fn end(&self) -> Position {
+        self.records
+            .iter()
+            .map(|record| record.end())
+            .max()
+            .expect("missing records")
+    }
+
+    /// Returns the indices of the parents of the feature.
+    pub fn parents(&self) -> &[usize] {
+        &self.parents
+    }
+
+    /// Returns the indices of the children of the feature.
+    pub fn children(&self) -> &[usize] {
+        &self.children
+    }
+
+    /// Returns the indices of the features this feature derives from.
+    pub fn derives_from(&self) -> &[usize] {
+        &self.derives_from
+    }
+}
+
+/// A GFF3 feature graph.
+///
+/// Features are stored in the order they are first read. A feature with multiple parents, e.g.,
+/// an exon shared by two transcripts, is a child of each parent.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Graph {
+    features: Vec<Feature>,
+}
+
+impl Graph {
+    /// Returns a builder to create a feature graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::feature;
+    /// let builder = feature::Graph::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Returns the features in the graph.
+    pub fn features(&self) -> &[Feature] {
+        &self.features
+    }
+
+    /// Returns the number of features in the graph.
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    /// Returns whether the graph has no features.
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// Returns the node at the given feature index.
+    pub fn get(&self, i: usize) -> Option<Node<'_>> {
+        if i < self.features.len() {
+            Some(Node {
+                graph: self,
+                index: i,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over nodes of features that have no parents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, feature};
+    ///
+    /// let mut builder = feature::Graph::builder();
+    /// builder.add_record("sq0\tNOODLES\tgene\t8\t34\t.\t+\t.\tID=gene0".parse()?)?;
+    /// builder.add_record("sq0\tNOODLES\tmRNA\t8\t34\t.\t+\t.\tID=tx0;Parent=gene0".parse()?)?;
+    /// builder.add_record("sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tParent=tx0".parse()?)?;
+    /// let graph = builder.build()?;
+    ///
+    /// let genes: Vec<_> = graph.roots().collect();
+    /// assert_eq!(genes.len(), 1);
+    ///
+    /// let transcripts: Vec<_> = genes[0].children().collect();
+    /// assert_eq!(transcripts[0].feature().ty(), "mRNA");
+    ///
+    /// let exons: Vec<_> = transcripts[0].children().collect();
+    /// assert_eq!(exons[0].feature().ty(), "exon");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn roots(&self) -> impl Iterator<Item = Node<'_>> {
+        self.features
+            .iter()
+            .enumerate()
+            .filter(|(_, feature)| feature.parents.is_empty())
+            .map(move |(index, _)| Node { graph: self, index })
+    }
+}
+
+/// A view of a feature in a feature graph.
+#[derive(Clone, Copy, Debug)]
+pub struct Node<'g> {
+    graph: &'g Graph,
+    index: usize,
+}
+
+impl<'g> Node<'g> {
+    /// Returns the index of the feature in the graph.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the feature.
+    pub fn feature(&self) -> &'g Feature {
+        &self.graph.features[self.index]
+    }
+
+    /// Returns an iterator over the parents of the feature.
+    pub fn parents(&self) -> impl Iterator<Item = Node<'g>> {
+        self.related(self.feature().parents())
+    }
+
+    /// Returns an iterator over the children of the feature.
+    pub fn children(&self) -> impl Iterator<Item = Node<'g>> {
+        self.related(self.feature().children())
+    }
+
+    /// Returns an iterator over the features this feature derives from.
+    pub fn derives_from(&self) -> impl Iterator<Item = Node<'g>> {
+        self.related(self.feature().derives_from())
+    }
+
+    fn related(&self, indices: &'g [usize]) -> impl Iterator<Item = Node<'g>> {
+        let graph = self.graph;
+
+        indices.iter().map(move |&index| Node { graph, index })
+    }
+}
@@ -0,0 +1,384 @@
+use std::{collections::HashMap, error, fmt};
+
+use super::{Feature, Graph};
+use crate::{Directive, Line, Record};
+
+const DERIVES_FROM: &str = "Derives_from";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Relation {
+    Parent,
+    DerivesFrom,
+}
+
+/// A GFF3 feature graph builder.
+///
+/// Records are added in file order. References to IDs are resolved when either a forward
+/// references are resolved directive (`###`) is added or the graph is built. After `###`, IDs
+/// read before it can no longer be referenced.
+#[derive(Debug, Default)]
+pub struct Builder {
+    features: Vec<Feature>,
+    ids: HashMap<String, usize>,
+    references: Vec<(usize, Relation, String)>,
+}
+
+impl Builder {
+    /// Adds a GFF line.
+    ///
+    /// Records are added to the graph, and the forward references are resolved directive
+    /// (`###`) resolves all pending references. Other lines are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, feature};
+    ///
+    /// let mut builder = feature::Graph::builder();
+    /// builder.add_line("sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0".parse()?)?;
+    /// builder.add_line("###".parse()?)?;
+    ///
+    /// let graph = builder.build()?;
+    /// assert_eq!(graph.len(), 1);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_line(&mut self, line: Line) -> Result<(), BuildError> {
+        match line {
+            Line::Record(record) => self.add_record(record),
+            Line::Directive(Directive::ForwardReferencesAreResolved) => self.resolve_references(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Adds a GFF record.
+    ///
+    /// A record with an `ID` that was already added in the current scope is added as a segment
+    /// of that discontinuous feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, feature};
+    ///
+    /// let mut builder = feature::Graph::builder();
+    /// builder.add_record("sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0".parse()?)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_record(&mut self, record: Record) -> Result<(), BuildError> {
+        let attributes = record.attributes();
+        let id = attributes.id().map(String::from);
+
+        let references: Vec<_> = attributes
+            .parent()
+            .into_iter()
+            .flat_map(|value| value.iter())
+            .map(|id| (Relation::Parent, id.into()))
+            .chain(
+                attributes
+                    .get(DERIVES_FROM)
+                    .into_iter()
+                    .flat_map(|value| value.iter())
+                    .map(|id| (Relation::DerivesFrom, id.into())),
+            )
+            .collect();
+
+        let i = match id.as_ref().and_then(|id| self.ids.get(id)).copied() {
+            Some(i) => {
+                let feature = &mut self.features[i];
+
+                if feature.reference_sequence_name() != record.reference_sequence_name()
+                    || feature.ty() != record.ty()
+                {
+                    return Err(BuildError::InvalidDiscontinuousFeature(
+                        id.unwrap_or_default(),
+                    ));
+                }
+
+                feature.records.push(record);
+
+                i
+            }
+            None => {
+                let i = self.features.len();
+
+                if let Some(id) = id.clone() {
+                    self.ids.insert(id, i);
+                }
+
+                self.features.push(Feature::new(id, record));
+
+                i
+            }
+        };
+
+        self.references.extend(
+            references
+                .into_iter()
+                .map(|(relation, id)| (i, relation, id)),
+        );
+
+        Ok(())
+    }
+
+    /// Resolves all pending references.
+    ///
+    /// This is called when a forward references are resolved directive (`###`) is added. IDs
+    /// added before this can no longer be referenced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, feature};
+    ///
+    /// let mut builder = feature::Graph::builder();
+    /// builder.add_record("sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tParent=tx0".parse()?)?;
+    ///
+    /// assert_eq!(
+    ///     builder.resolve_references(),
+    ///     Err(feature::BuildError::MissingParent(String::from("tx0")))
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn resolve_references(&mut self) -> Result<(), BuildError> {
+        for (i, relation, id) in self.references.drain(..) {
+            let j = match self.ids.get(&id) {
+                Some(&j) => j,
+                None => {
+                    return match relation {
+                        Relation::Parent => Err(BuildError::MissingParent(id)),
+                        Relation::DerivesFrom => Err(BuildError::MissingDerivesFrom(id)),
+                    }
+                }
+            };
+
+            match relation {
+                Relation::Parent => {
+                    if !self.features[i].parents.contains(&j) {
+                        self.features[i].parents.push(j);
+                        self.features[j].children.push(i);
+                    }
+                }
+                Relation::DerivesFrom => {
+                    if !self.features[i].derives_from.contains(&j) {
+                        self.features[i].derives_from.push(j);
+                    }
+                }
+            }
+        }
+
+        self.ids.clear();
+
+        Ok(())
+    }
+
+    /// Builds a feature graph.
+    ///
+    /// This resolves all pending references and checks that no feature is its own ancestor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::feature;
+    /// let graph = feature::Graph::builder().build()?;
+    /// assert!(graph.is_empty());
+    /// # Ok::<_, feature::BuildError>(())
+    /// ```
+    pub fn build(mut self) -> Result<Graph, BuildError> {
+        self.resolve_references()?;
+
+        let graph = Graph {
+            features: self.features,
+        };
+
+        validate_acyclic(&graph)?;
+
+        Ok(graph)
+    }
+}
+
+fn validate_acyclic(graph: &Graph) -> Result<(), BuildError> {
+    let mut parent_counts: Vec<_> = graph
+        .features
+        .iter()
+        .map(|feature| feature.parents.len())
+        .collect();
+
+    let mut queue: Vec<_> = (0..parent_counts.len())
+        .filter(|&i| parent_counts[i] == 0)
+        .collect();
+
+    while let Some(i) = queue.pop() {
+        for &j in &graph.features[i].children {
+            parent_counts[j] -= 1;
+
+            if parent_counts[j] == 0 {
+                queue.push(j);
+            }
+        }
+    }
+
+    // Every feature in a cycle is a parent and therefore has an ID.
+    match graph
+        .features
+        .iter()
+        .zip(parent_counts)
+        .find_map(|(feature, count)| if count > 0 { feature.id() } else { None })
+    {
+        Some(id) => Err(BuildError::CircularParent(id.into())),
+        None => Ok(()),
+    }
+}
+
+/// An error returned when a GFF3 feature graph fails to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// A `Parent` references an ID that does not exist in its scope.
+    MissingParent(String),
+    /// A `Derives_from` references an ID that does not exist in its scope.
+    MissingDerivesFrom(String),
+    /// A discontinuous feature has segments with different reference sequence names or types.
+    InvalidDiscontinuousFeature(String),
+    /// A feature is its own ancestor.
+    CircularParent(String),
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingParent(id) => write!(f, "missing parent: {}", id),
+            Self::MissingDerivesFrom(id) => write!(f, "missing Derives_from: {}", id),
+            Self::InvalidDiscontinuousFeature(id) => {
+                write!(f, "invalid discontinuous feature: {}", id)
+            }
+            Self::CircularParent(id) => write!(f, "circular parent: {}", id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(lines: &[&str]) -> Result<Graph, BuildError> {
+        let mut builder = Graph::builder();
+
+        for s in lines {
+            let line = s.parse().expect("invalid line");
+            builder.add_line(line)?;
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_build() -> Result<(), BuildError> {
+        let graph = build(&[
+            "sq0\tNOODLES\tgene\t8\t55\t.\t+\t.\tID=gene0",
+            "sq0\tNOODLES\tmRNA\t8\t55\t.\t+\t.\tID=tx0;Parent=gene0",
+            "sq0\tNOODLES\tmRNA\t8\t34\t.\t+\t.\tID=tx1;Parent=gene0",
+            "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tParent=tx0,tx1",
+            "sq0\tNOODLES\tCDS\t10\t13\t.\t+\t0\tID=cds0;Parent=tx0",
+            "sq0\tNOODLES\tCDS\t21\t34\t.\t+\t2\tID=cds0;Parent=tx0",
+            "sq0\tNOODLES\tpolypeptide\t10\t34\t.\t+\t.\tDerives_from=tx0",
+        ])?;
+
+        assert_eq!(graph.len(), 6);
+
+        let genes: Vec<_> = graph.roots().collect();
+        assert_eq!(genes.len(), 2);
+        assert_eq!(genes[0].feature().id(), Some("gene0"));
+
+        let transcripts: Vec<_> = genes[0].children().map(|node| node.index()).collect();
+        assert_eq!(transcripts, [1, 2]);
+
+        // multi-parent feature
+        let exon = graph.get(3).expect("missing exon");
+        let parents: Vec<_> = exon.parents().map(|node| node.index()).collect();
+        assert_eq!(parents, [1, 2]);
+        assert_eq!(graph.features()[2].children(), [3]);
+
+        // discontinuous feature
+        let cds = &graph.features()[4];
+        assert_eq!(cds.records().len(), 2);
+        assert_eq!(cds.parents(), [1]);
+        assert_eq!(usize::from(cds.start()), 10);
+        assert_eq!(usize::from(cds.end()), 34);
+        assert_eq!(graph.features()[1].children(), [3, 4]);
+
+        let polypeptide = &graph.features()[5];
+        assert!(polypeptide.parents().is_empty());
+        assert_eq!(polypeptide.derives_from(), [1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_forward_reference() -> Result<(), BuildError> {
+        let graph = build(&[
+            "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tParent=tx0",
+            "sq0\tNOODLES\tmRNA\t8\t13\t.\t+\t.\tID=tx0",
+            "###",
+        ])?;
+
+        assert_eq!(graph.features()[0].parents(), [1]);
+        assert_eq!(graph.features()[1].children(), [0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_forward_references_are_resolved_directive() -> Result<(), BuildError> {
+        assert_eq!(
+            build(&[
+                "sq0\tNOODLES\tmRNA\t8\t13\t.\t+\t.\tID=tx0",
+                "###",
+                "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tParent=tx0",
+            ]),
+            Err(BuildError::MissingParent(String::from("tx0")))
+        );
+
+        // An ID can be reused after `###`.
+        let graph = build(&[
+            "sq0\tNOODLES\tCDS\t8\t13\t.\t+\t0\tID=cds0",
+            "###",
+            "sq1\tNOODLES\tCDS\t8\t13\t.\t+\t0\tID=cds0",
+        ])?;
+
+        assert_eq!(graph.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_invalid_references() {
+        assert_eq!(
+            build(&["sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tParent=tx0"]),
+            Err(BuildError::MissingParent(String::from("tx0")))
+        );
+
+        assert_eq!(
+            build(&["sq0\tNOODLES\tpolypeptide\t8\t13\t.\t+\t.\tDerives_from=tx0"]),
+            Err(BuildError::MissingDerivesFrom(String::from("tx0")))
+        );
+
+        assert_eq!(
+            build(&[
+                "sq0\tNOODLES\tCDS\t8\t13\t.\t+\t0\tID=cds0",
+                "sq0\tNOODLES\texon\t21\t34\t.\t+\t.\tID=cds0",
+            ]),
+            Err(BuildError::InvalidDiscontinuousFeature(String::from(
+                "cds0"
+            )))
+        );
+
+        assert_eq!(
+            build(&[
+                "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0;Parent=tx0",
+                "sq0\tNOODLES\tmRNA\t8\t13\t.\t+\t.\tID=tx0;Parent=gene0",
+            ]),
+            Err(BuildError::CircularParent(String::from("gene0")))
+        );
+    }
+}
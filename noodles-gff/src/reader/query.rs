@@ -0,0 +1,122 @@
+use std::{
+    io::{self, Read, Seek},
+    vec,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::Interval;
+use noodles_csi::index::reference_sequence::bin::Chunk;
+
+use super::Reader;
+use crate::{Line, Record};
+
+enum State {
+    Seek,
+    Read(bgzf::VirtualPosition),
+    Done,
+}
+
+/// An iterator over records of a GFF reader that intersects a given region.
+///
+/// This is created by calling [`Reader::query`].
+pub struct Query<'r, R>
+where
+    R: Read + Seek + 'r,
+{
+    reader: &'r mut Reader<bgzf::Reader<R>>,
+
+    chunks: vec::IntoIter<Chunk>,
+
+    reference_sequence_name: String,
+    interval: Interval,
+
+    state: State,
+    line_buf: String,
+}
+
+impl<'r, R> Query<'r, R>
+where
+    R: Read + Seek,
+{
+    pub(super) fn new(
+        reader: &'r mut Reader<bgzf::Reader<R>>,
+        chunks: Vec<Chunk>,
+        reference_sequence_name: String,
+        interval: Interval,
+    ) -> Self {
+        Self {
+            reader,
+
+            chunks: chunks.into_iter(),
+
+            reference_sequence_name,
+            interval,
+
+            state: State::Seek,
+            line_buf: String::new(),
+        }
+    }
+
+    fn read_record(&mut self) -> io::Result<Option<Record>> {
+        loop {
+            self.line_buf.clear();
+
+            if self.reader.read_line(&mut self.line_buf)? == 0 {
+                return Ok(None);
+            }
+
+            match self.line_buf.parse() {
+                Ok(Line::Record(record)) => return Ok(Some(record)),
+                Ok(_) => {}
+                Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+            }
+        }
+    }
+}
+
+impl<'r, R> Iterator for Query<'r, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.state {
+                State::Seek => {
+                    self.state = match self.chunks.next() {
+                        Some(chunk) => {
+                            if let Err(e) = self.reader.seek(chunk.start()) {
+                                return Some(Err(e));
+                            }
+
+                            State::Read(chunk.end())
+                        }
+                        None => State::Done,
+                    }
+                }
+                State::Read(chunk_end) => match self.read_record() {
+                    Ok(Some(record)) => {
+                        if self.reader.virtual_position() >= chunk_end {
+                            self.state = State::Seek;
+                        }
+
+                        if intersects(&record, &self.reference_sequence_name, self.interval) {
+                            return Some(Ok(record));
+                        }
+                    }
+                    Ok(None) => self.state = State::Seek,
+                    Err(e) => return Some(Err(e)),
+                },
+                State::Done => return None,
+            }
+        }
+    }
+}
+
+fn intersects(record: &Record, reference_sequence_name: &str, region_interval: Interval) -> bool {
+    let record_interval = Interval::from(record.start()..=record.end());
+
+    record.reference_sequence_name() == reference_sequence_name
+        && record_interval.intersects(region_interval)
+}
@@ -1,17 +1,200 @@
 //! GFF record attributes and entry.
 
 pub mod entry;
+pub mod gap;
+pub mod target;
 
-pub use self::entry::Entry;
+pub use self::{entry::Entry, gap::Gap, target::Target};
 
 use std::{error, fmt, ops::Deref, str::FromStr};
 
+use self::entry::Value;
+
 const DELIMITER: char = ';';
 
+const ID: &str = "ID";
+const NAME: &str = "Name";
+const ALIAS: &str = "Alias";
+const PARENT: &str = "Parent";
+const TARGET: &str = "Target";
+const GAP: &str = "Gap";
+const DBXREF: &str = "Dbxref";
+const ONTOLOGY_TERM: &str = "Ontology_term";
+const IS_CIRCULAR: &str = "Is_circular";
+
 /// GFF record attributes.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Attributes(Vec<Entry>);
 
+impl Attributes {
+    /// Returns the value of the first entry with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::{attributes::entry::Value, Attributes};
+    /// let attributes: Attributes = "gene_id=ndls0;gene_name=gene0".parse()?;
+    /// assert_eq!(attributes.get("gene_name"), Some(&Value::from("gene0")));
+    /// assert!(attributes.get("transcript_id").is_none());
+    /// # Ok::<_, noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.iter()
+            .find(|entry| entry.key() == key)
+            .map(|entry| entry.value())
+    }
+
+    /// Returns the feature ID (`ID`).
+    ///
+    /// This returns `None` if the ID is missing or has more than one value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "ID=gene0".parse()?;
+    /// assert_eq!(attributes.id(), Some("gene0"));
+    /// # Ok::<_, noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn id(&self) -> Option<&str> {
+        self.get(ID).and_then(|value| value.as_string())
+    }
+
+    /// Returns the display name of the feature (`Name`).
+    ///
+    /// This returns `None` if the name is missing or has more than one value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "Name=NDLS0".parse()?;
+    /// assert_eq!(attributes.name(), Some("NDLS0"));
+    /// # Ok::<_, noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn name(&self) -> Option<&str> {
+        self.get(NAME).and_then(|value| value.as_string())
+    }
+
+    /// Returns the secondary names of the feature (`Alias`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "Alias=ndls0,NDLS0".parse()?;
+    /// let aliases: Vec<_> = attributes.alias().map(|v| v.iter().collect()).unwrap_or_default();
+    /// assert_eq!(aliases, ["ndls0", "NDLS0"]);
+    /// # Ok::<_, noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn alias(&self) -> Option<&Value> {
+        self.get(ALIAS)
+    }
+
+    /// Returns the parent IDs of the feature (`Parent`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "Parent=tx0,tx1".parse()?;
+    /// let parents: Vec<_> = attributes.parent().map(|v| v.iter().collect()).unwrap_or_default();
+    /// assert_eq!(parents, ["tx0", "tx1"]);
+    /// # Ok::<_, noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn parent(&self) -> Option<&Value> {
+        self.get(PARENT)
+    }
+
+    /// Returns the alignment target of the feature (`Target`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    ///
+    /// let attributes: Attributes = "Target=EST23 8 13 +".parse()?;
+    /// let target = attributes.target().transpose()?;
+    /// assert_eq!(target.map(|t| t.id().to_string()), Some(String::from("EST23")));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn target(&self) -> Option<Result<Target, target::ParseError>> {
+        self.get(TARGET).map(|value| match value.as_string() {
+            Some(s) => s.parse(),
+            None => Err(target::ParseError::Invalid),
+        })
+    }
+
+    /// Returns the alignment of the feature to its target (`Gap`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    ///
+    /// let attributes: Attributes = "Gap=M8 D3 M6".parse()?;
+    /// let gap = attributes.gap().transpose()?;
+    /// assert_eq!(gap.map(|g| g.len()), Some(3));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn gap(&self) -> Option<Result<Gap, gap::ParseError>> {
+        self.get(GAP).map(|value| match value.as_string() {
+            Some(s) => s.parse(),
+            None => Err(gap::ParseError::Invalid),
+        })
+    }
+
+    /// Returns the database cross references of the feature (`Dbxref`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "Dbxref=EMBL:AA816246,NCBI_gi:10727410".parse()?;
+    /// let dbxrefs: Vec<_> = attributes.dbxref().map(|v| v.iter().collect()).unwrap_or_default();
+    /// assert_eq!(dbxrefs, ["EMBL:AA816246", "NCBI_gi:10727410"]);
+    /// # Ok::<_, noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn dbxref(&self) -> Option<&Value> {
+        self.get(DBXREF)
+    }
+
+    /// Returns the ontology terms of the feature (`Ontology_term`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "Ontology_term=GO:0046703".parse()?;
+    /// let terms: Vec<_> = attributes.ontology_term().map(|v| v.iter().collect()).unwrap_or_default();
+    /// assert_eq!(terms, ["GO:0046703"]);
+    /// # Ok::<_, noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn ontology_term(&self) -> Option<&Value> {
+        self.get(ONTOLOGY_TERM)
+    }
+
+    /// Returns whether the feature is circular (`Is_circular`).
+    ///
+    /// The feature is circular only if the value is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    ///
+    /// let attributes: Attributes = "Is_circular=true".parse()?;
+    /// assert!(attributes.is_circular());
+    ///
+    /// let attributes = Attributes::default();
+    /// assert!(!attributes.is_circular());
+    /// # Ok::<_, noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn is_circular(&self) -> bool {
+        self.get(IS_CIRCULAR).and_then(|value| value.as_string()) == Some("true")
+    }
+}
+
 impl Deref for Attributes {
     type Target = [Entry];
 
@@ -118,4 +301,45 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_reserved_tags() -> Result<(), Box<dyn std::error::Error>> {
+        let attributes: Attributes = "ID=cds0;Name=CDS%2C0;Alias=a0,a1;Parent=tx0,tx1;\
+Target=EST23 8 13 +;Gap=M8 D3 M6;Dbxref=EMBL:AA816246;Ontology_term=GO:0046703;\
+Is_circular=true"
+            .parse()?;
+
+        assert_eq!(attributes.id(), Some("cds0"));
+        assert_eq!(attributes.name(), Some("CDS,0"));
+        assert_eq!(
+            attributes.alias(),
+            Some(&Value::from(vec![String::from("a0"), String::from("a1")]))
+        );
+        assert_eq!(
+            attributes.parent(),
+            Some(&Value::from(vec![String::from("tx0"), String::from("tx1")]))
+        );
+        assert_eq!(
+            attributes.target().transpose()?.map(|t| t.to_string()),
+            Some(String::from("EST23 8 13 +"))
+        );
+        assert_eq!(
+            attributes.gap().transpose()?.map(|g| g.to_string()),
+            Some(String::from("M8 D3 M6"))
+        );
+        assert_eq!(attributes.dbxref(), Some(&Value::from("EMBL:AA816246")));
+        assert_eq!(attributes.ontology_term(), Some(&Value::from("GO:0046703")));
+        assert!(attributes.is_circular());
+
+        let attributes: Attributes = "ID=a,b;Target=x,y 8 13".parse()?;
+        assert!(attributes.id().is_none());
+        assert_eq!(attributes.target(), Some(Err(target::ParseError::Invalid)));
+
+        let attributes = Attributes::default();
+        assert!(attributes.id().is_none());
+        assert!(attributes.gap().is_none());
+        assert!(!attributes.is_circular());
+
+        Ok(())
+    }
 }
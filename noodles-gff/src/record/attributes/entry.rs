@@ -1,5 +1,9 @@
 //! GFF record attribute entry.
 
+pub mod value;
+
+pub use self::value::Value;
+
 use std::{
     borrow::Cow,
     error, fmt,
@@ -19,12 +23,13 @@ const PERCENT_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b',');
 
 const SEPARATOR: char = '=';
+const VALUE_DELIMITER: char = ',';
 
 /// A GFF record attribute entry.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Entry {
     key: String,
-    value: String,
+    value: Value,
 }
 
 impl Entry {
@@ -33,13 +38,19 @@ impl Entry {
     /// # Examples
     ///
     /// ```
-    /// use noodles_gff::record::attributes::Entry;
+    /// use noodles_gff::record::attributes::{entry::Value, Entry};
+    ///
     /// let entry = Entry::new("gene_name", "gene0");
+    ///
+    /// let entry = Entry::new(
+    ///     "Parent",
+    ///     Value::from(vec![String::from("tx0"), String::from("tx1")]),
+    /// );
     /// ```
     pub fn new<K, V>(key: K, value: V) -> Self
     where
         K: Into<String>,
-        V: Into<String>,
+        V: Into<Value>,
     {
         Self {
             key: key.into(),
@@ -65,11 +76,11 @@ impl Entry {
     /// # Examples
     ///
     /// ```
-    /// use noodles_gff::record::attributes::Entry;
+    /// use noodles_gff::record::attributes::{entry::Value, Entry};
     /// let entry = Entry::new("gene_name", "gene0");
-    /// assert_eq!(entry.value(), "gene0");
+    /// assert_eq!(entry.value(), &Value::from("gene0"));
     /// ```
-    pub fn value(&self) -> &str {
+    pub fn value(&self) -> &Value {
         &self.value
     }
 }
@@ -81,7 +92,7 @@ impl fmt::Display for Entry {
             "{}{}{}",
             percent_encode(self.key()),
             SEPARATOR,
-            percent_encode(self.value())
+            self.value()
         )
     }
 }
@@ -145,11 +156,23 @@ fn parse_key(s: &str) -> Result<Cow<'_, str>, ParseError> {
     }
 }
 
-fn parse_value(s: &str) -> Result<Cow<'_, str>, ParseError> {
-    if s.is_empty() {
-        Err(ParseError::MissingValue)
-    } else {
-        percent_decode(s).map_err(ParseError::InvalidValue)
+// Values are split before being decoded so that percent-encoded delimiters (`%2C`) are kept.
+// Empty list items, e.g., the trailing item in `tx0,`, are dropped.
+fn parse_value(s: &str) -> Result<Value, ParseError> {
+    let mut values: Vec<String> = s
+        .split(VALUE_DELIMITER)
+        .filter(|t| !t.is_empty())
+        .map(|t| {
+            percent_decode(t)
+                .map(|u| u.into_owned())
+                .map_err(ParseError::InvalidValue)
+        })
+        .collect::<Result<_, _>>()?;
+
+    match values.len() {
+        0 => Err(ParseError::MissingValue),
+        1 => Ok(Value::String(values.remove(0))),
+        _ => Ok(Value::Array(values)),
     }
 }
 
@@ -172,6 +195,15 @@ mod tests {
 
         let entry = Entry::new("%s", "13,21");
         assert_eq!(entry.to_string(), "%25s=13%2C21");
+
+        let entry = Entry::new(
+            "Dbxref",
+            Value::from(vec![
+                String::from("EMBL:AA816246"),
+                String::from("NCBI:13,21"),
+            ]),
+        );
+        assert_eq!(entry.to_string(), "Dbxref=EMBL:AA816246,NCBI:13%2C21");
     }
 
     #[test]
@@ -181,10 +213,27 @@ mod tests {
             Ok(Entry::new("gene_name", "gene0"))
         );
         assert_eq!("%25s=13%2C21".parse(), Ok(Entry::new("%s", "13,21")));
+        assert_eq!(
+            "Parent=tx0,tx%2C1".parse(),
+            Ok(Entry::new(
+                "Parent",
+                Value::from(vec![String::from("tx0"), String::from("tx,1")])
+            ))
+        );
+
+        assert_eq!("Parent=tx0,".parse(), Ok(Entry::new("Parent", "tx0")));
+        assert_eq!(
+            "Parent=tx0,,tx1".parse(),
+            Ok(Entry::new(
+                "Parent",
+                Value::from(vec![String::from("tx0"), String::from("tx1")])
+            ))
+        );
 
         assert_eq!("".parse::<Entry>(), Err(ParseError::Empty));
         assert_eq!("gene_name".parse::<Entry>(), Err(ParseError::Invalid));
         assert_eq!("=gene0".parse::<Entry>(), Err(ParseError::MissingKey));
         assert_eq!("gene_name=".parse::<Entry>(), Err(ParseError::MissingValue));
+        assert_eq!("Parent=,".parse::<Entry>(), Err(ParseError::MissingValue));
     }
 }
@@ -0,0 +1,117 @@
+//! GFF record attribute entry value.
+
+use std::{fmt, slice};
+
+use super::percent_encode;
+
+const DELIMITER: char = ',';
+
+/// A GFF record attribute entry value.
+///
+/// A value is either a single string or a list of strings, e.g., `Parent=tx0,tx1`. Values are
+/// stored percent-decoded.
+///
+/// Not every value round-trips through its string representation. An empty list is written as an
+/// empty string, which does not parse as a value; and a list with one item is written the same as
+/// a single value. When parsing, empty list items (e.g., `tx0,,tx1`) are dropped.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Value {
+    /// A single value.
+    String(String),
+    /// A list of values.
+    Array(Vec<String>),
+}
+
+impl Value {
+    /// Returns the value as a string, if it is a single value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::attributes::entry::Value;
+    ///
+    /// let value = Value::from("gene0");
+    /// assert_eq!(value.as_string(), Some("gene0"));
+    ///
+    /// let value = Value::from(vec![String::from("tx0"), String::from("tx1")]);
+    /// assert!(value.as_string().is_none());
+    /// ```
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            Self::Array(_) => None,
+        }
+    }
+
+    /// Returns an iterator over the values.
+    ///
+    /// A single value is yielded as a list with one value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::attributes::entry::Value;
+    ///
+    /// let value = Value::from("gene0");
+    /// assert_eq!(value.iter().collect::<Vec<_>>(), ["gene0"]);
+    ///
+    /// let value = Value::from(vec![String::from("tx0"), String::from("tx1")]);
+    /// assert_eq!(value.iter().collect::<Vec<_>>(), ["tx0", "tx1"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        let values = match self {
+            Self::String(s) => slice::from_ref(s),
+            Self::Array(values) => values,
+        };
+
+        values.iter().map(|s| s.as_str())
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, s) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", DELIMITER)?;
+            }
+
+            write!(f, "{}", percent_encode(s))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Self::String(s.into())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Self::String(s)
+    }
+}
+
+impl From<Vec<String>> for Value {
+    fn from(values: Vec<String>) -> Self {
+        Self::Array(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Value::from("gene0").to_string(), "gene0");
+        assert_eq!(Value::from("13,21").to_string(), "13%2C21");
+
+        let value = Value::from(vec![String::from("tx0"), String::from("13,21")]);
+        assert_eq!(value.to_string(), "tx0,13%2C21");
+
+        assert_eq!(Value::from(Vec::new()).to_string(), "");
+    }
+}
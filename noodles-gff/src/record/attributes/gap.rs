@@ -0,0 +1,116 @@
+//! GFF record attributes gap.
+
+pub mod operation;
+
+pub use self::operation::Operation;
+
+use std::{error, fmt, ops::Deref, str::FromStr};
+
+const DELIMITER: char = ' ';
+
+/// A GFF record attributes gap (`Gap`).
+///
+/// This is the alignment of a feature to its target as a list of operations, e.g., `M8 D3 M6`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Gap(Vec<Operation>);
+
+impl Deref for Gap {
+    type Target = [Operation];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for Gap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, op) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", DELIMITER)?;
+            }
+
+            write!(f, "{}", op)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Vec<Operation>> for Gap {
+    fn from(ops: Vec<Operation>) -> Self {
+        Self(ops)
+    }
+}
+
+/// An error returned when a raw GFF record attributes gap fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+    /// An operation is invalid.
+    InvalidOperation(operation::ParseError),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+            Self::InvalidOperation(e) => write!(f, "invalid operation: {}", e),
+        }
+    }
+}
+
+impl FromStr for Gap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        s.split(DELIMITER)
+            .map(|t| t.parse())
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::from)
+            .map_err(ParseError::InvalidOperation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let gap = Gap::from(vec![
+            Operation::new(operation::Kind::Match, 8),
+            Operation::new(operation::Kind::Deletion, 3),
+            Operation::new(operation::Kind::Match, 6),
+        ]);
+
+        assert_eq!(gap.to_string(), "M8 D3 M6");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "M8 D3 M6".parse(),
+            Ok(Gap::from(vec![
+                Operation::new(operation::Kind::Match, 8),
+                Operation::new(operation::Kind::Deletion, 3),
+                Operation::new(operation::Kind::Match, 6),
+            ]))
+        );
+
+        assert_eq!("".parse::<Gap>(), Err(ParseError::Empty));
+        assert!(matches!(
+            "M8 X3".parse::<Gap>(),
+            Err(ParseError::InvalidOperation(_))
+        ));
+    }
+}
@@ -0,0 +1,158 @@
+//! GFF record attributes gap operation and kind.
+
+use std::{error, fmt, num, str::FromStr};
+
+/// A GFF record attributes gap operation kind.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// A match (`M`).
+    Match,
+    /// An insertion into the reference (`I`).
+    Insertion,
+    /// A deletion from the reference (`D`).
+    Deletion,
+    /// A frameshift forward in the reference (`F`).
+    ForwardFrameshift,
+    /// A frameshift reverse in the reference (`R`).
+    ReverseFrameshift,
+}
+
+impl From<Kind> for char {
+    fn from(kind: Kind) -> Self {
+        match kind {
+            Kind::Match => 'M',
+            Kind::Insertion => 'I',
+            Kind::Deletion => 'D',
+            Kind::ForwardFrameshift => 'F',
+            Kind::ReverseFrameshift => 'R',
+        }
+    }
+}
+
+/// A GFF record attributes gap operation.
+#[allow(clippy::len_without_is_empty)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Operation {
+    kind: Kind,
+    len: usize,
+}
+
+impl Operation {
+    /// Creates a gap operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::attributes::gap::{operation::Kind, Operation};
+    /// let op = Operation::new(Kind::Match, 8);
+    /// ```
+    pub fn new(kind: Kind, len: usize) -> Self {
+        Self { kind, len }
+    }
+
+    /// Returns the kind of the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::attributes::gap::{operation::Kind, Operation};
+    /// let op = Operation::new(Kind::Match, 8);
+    /// assert_eq!(op.kind(), Kind::Match);
+    /// ```
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Returns the length of the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::attributes::gap::{operation::Kind, Operation};
+    /// let op = Operation::new(Kind::Match, 8);
+    /// assert_eq!(op.len(), 8);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", char::from(self.kind), self.len)
+    }
+}
+
+/// An error returned when a raw GFF record attributes gap operation fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The kind is invalid.
+    InvalidKind(char),
+    /// The length is invalid.
+    InvalidLength(num::ParseIntError),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::InvalidKind(c) => {
+                write!(f, "invalid kind: expected {{M, I, D, F, R}}, got {}", c)
+            }
+            Self::InvalidLength(e) => write!(f, "invalid length: {}", e),
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        let kind = match chars.next() {
+            Some('M') => Kind::Match,
+            Some('I') => Kind::Insertion,
+            Some('D') => Kind::Deletion,
+            Some('F') => Kind::ForwardFrameshift,
+            Some('R') => Kind::ReverseFrameshift,
+            Some(c) => return Err(ParseError::InvalidKind(c)),
+            None => return Err(ParseError::Empty),
+        };
+
+        let len = chars.as_str().parse().map_err(ParseError::InvalidLength)?;
+
+        Ok(Self::new(kind, len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Operation::new(Kind::Match, 8).to_string(), "M8");
+        assert_eq!(Operation::new(Kind::Insertion, 3).to_string(), "I3");
+        assert_eq!(Operation::new(Kind::Deletion, 3).to_string(), "D3");
+        assert_eq!(Operation::new(Kind::ForwardFrameshift, 1).to_string(), "F1");
+        assert_eq!(Operation::new(Kind::ReverseFrameshift, 2).to_string(), "R2");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("M8".parse(), Ok(Operation::new(Kind::Match, 8)));
+        assert_eq!("R2".parse(), Ok(Operation::new(Kind::ReverseFrameshift, 2)));
+
+        assert_eq!("".parse::<Operation>(), Err(ParseError::Empty));
+        assert_eq!("X8".parse::<Operation>(), Err(ParseError::InvalidKind('X')));
+        assert!(matches!(
+            "M".parse::<Operation>(),
+            Err(ParseError::InvalidLength(_))
+        ));
+    }
+}
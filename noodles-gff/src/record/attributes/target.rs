@@ -0,0 +1,218 @@
+//! GFF record attributes target.
+
+use std::{error, fmt, num, str::FromStr};
+
+use noodles_core::Position;
+
+use crate::record::{strand, Strand};
+
+const DELIMITER: char = ' ';
+
+/// A GFF record attributes target (`Target`).
+///
+/// This indicates the target of a nucleotide-to-nucleotide or protein-to-nucleotide alignment.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Target {
+    id: String,
+    start: Position,
+    end: Position,
+    strand: Option<Strand>,
+}
+
+impl Target {
+    /// Creates a target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_gff::record::attributes::Target;
+    ///
+    /// let target = Target::new(
+    ///     String::from("EST23"),
+    ///     Position::try_from(8)?,
+    ///     Position::try_from(13)?,
+    ///     None,
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn new(id: String, start: Position, end: Position, strand: Option<Strand>) -> Self {
+        Self {
+            id,
+            start,
+            end,
+            strand,
+        }
+    }
+
+    /// Returns the target ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::attributes::Target;
+    /// let target: Target = "EST23 8 13".parse()?;
+    /// assert_eq!(target.id(), "EST23");
+    /// # Ok::<_, noodles_gff::record::attributes::target::ParseError>(())
+    /// ```
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the start position of the target.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position of the target.
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns the strand of the target.
+    pub fn strand(&self) -> Option<Strand> {
+        self.strand
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}{}",
+            self.id, DELIMITER, self.start, DELIMITER, self.end
+        )?;
+
+        if let Some(strand) = self.strand {
+            write!(f, "{}{}", DELIMITER, strand)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a raw GFF record attributes target fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+    /// The start is invalid.
+    InvalidStart(num::ParseIntError),
+    /// The end is invalid.
+    InvalidEnd(num::ParseIntError),
+    /// The strand is invalid.
+    InvalidStrand(strand::ParseError),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+            Self::InvalidStart(e) => write!(f, "invalid start: {}", e),
+            Self::InvalidEnd(e) => write!(f, "invalid end: {}", e),
+            Self::InvalidStrand(e) => write!(f, "invalid strand: {}", e),
+        }
+    }
+}
+
+impl FromStr for Target {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        // Values are stored percent-decoded, so a target ID can have spaces. The other fields are
+        // read from the end.
+        let mut fields: Vec<_> = s.split(DELIMITER).collect();
+
+        let strand = match fields.last() {
+            Some(&t) if t == "+" || t == "-" => {
+                fields.pop();
+                t.parse().map(Some).map_err(ParseError::InvalidStrand)?
+            }
+            _ => None,
+        };
+
+        if fields.len() < 3 {
+            return Err(ParseError::Invalid);
+        }
+
+        let end = fields
+            .pop()
+            .map(|t| t.parse().map_err(ParseError::InvalidEnd))
+            .unwrap_or(Err(ParseError::Invalid))?;
+
+        let start = fields
+            .pop()
+            .map(|t| t.parse().map_err(ParseError::InvalidStart))
+            .unwrap_or(Err(ParseError::Invalid))?;
+
+        let id = fields.join(&DELIMITER.to_string());
+
+        if id.is_empty() {
+            return Err(ParseError::Invalid);
+        }
+
+        Ok(Self::new(id, start, end, strand))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() -> Result<(), noodles_core::position::TryFromIntError> {
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+
+        let target = Target::new(String::from("EST23"), start, end, None);
+        assert_eq!(target.to_string(), "EST23 8 13");
+
+        let target = Target::new(String::from("EST 23"), start, end, Some(Strand::Reverse));
+        assert_eq!(target.to_string(), "EST 23 8 13 -");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), noodles_core::position::TryFromIntError> {
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+
+        assert_eq!(
+            "EST23 8 13".parse(),
+            Ok(Target::new(String::from("EST23"), start, end, None))
+        );
+
+        assert_eq!(
+            "EST 23 8 13 +".parse(),
+            Ok(Target::new(
+                String::from("EST 23"),
+                start,
+                end,
+                Some(Strand::Forward)
+            ))
+        );
+
+        assert_eq!("".parse::<Target>(), Err(ParseError::Empty));
+        assert_eq!("EST23 8".parse::<Target>(), Err(ParseError::Invalid));
+        assert!(matches!(
+            "EST23 8 n".parse::<Target>(),
+            Err(ParseError::InvalidEnd(_))
+        ));
+        assert!(matches!(
+            "EST23 0 13".parse::<Target>(),
+            Err(ParseError::InvalidStart(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@
 //! **noodles-gtf** handles the reading and writing of the Gene Transfer Format (GTF).
 
 pub mod line;
-mod reader;
+pub mod reader;
 pub mod record;
 mod writer;
 
@@ -1,8 +1,19 @@
+//! GTF reader and iterators.
+
+mod query;
+
+pub use self::query::Query;
+
 use std::{
-    io::{self, BufRead},
+    io::{self, BufRead, Read, Seek},
     iter,
 };
 
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_csi::BinningIndex;
+use noodles_tabix as tabix;
+
 use super::{Line, Record};
 
 /// A GTF reader.
@@ -128,6 +139,108 @@ where
     }
 }
 
+impl<R> Reader<bgzf::Reader<R>>
+where
+    R: Read,
+{
+    /// Returns the current virtual position of the underlying BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_gtf as gtf;
+    ///
+    /// let data = Vec::new();
+    /// let reader = gtf::Reader::new(bgzf::Reader::new(&data[..]));
+    /// assert_eq!(reader.virtual_position(), bgzf::VirtualPosition::default());
+    /// ```
+    pub fn virtual_position(&self) -> bgzf::VirtualPosition {
+        self.inner.virtual_position()
+    }
+}
+
+impl<R> Reader<bgzf::Reader<R>>
+where
+    R: Read + Seek,
+{
+    /// Seeks the underlying BGZF stream to the given virtual position.
+    ///
+    /// Virtual positions typically come from an associated index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_gtf as gtf;
+    ///
+    /// let data = Cursor::new(Vec::new());
+    /// let mut reader = gtf::Reader::new(bgzf::Reader::new(data));
+    ///
+    /// let virtual_position = bgzf::VirtualPosition::default();
+    /// reader.seek(virtual_position)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn seek(&mut self, pos: bgzf::VirtualPosition) -> io::Result<bgzf::VirtualPosition> {
+        self.inner.seek(pos)
+    }
+
+    /// Returns an iterator over records that intersect the given region.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_gtf as gtf;
+    /// use noodles_tabix as tabix;
+    ///
+    /// let mut reader = File::open("annotations.gtf.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(gtf::Reader::new)?;
+    ///
+    /// let index = tabix::read("annotations.gtf.gz.tbi")?;
+    /// let region = "sq0:8-13".parse()?;
+    /// let query = reader.query(&index, &region)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     println!("{:?}", record);
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query(&mut self, index: &tabix::Index, region: &Region) -> io::Result<Query<'_, R>> {
+        let (reference_sequence_id, reference_sequence_name) = resolve_region(index, region)?;
+        let chunks = index.query(reference_sequence_id, region.interval())?;
+
+        Ok(Query::new(
+            self,
+            chunks,
+            reference_sequence_name,
+            region.interval(),
+        ))
+    }
+}
+
+fn resolve_region(index: &tabix::Index, region: &Region) -> io::Result<(usize, String)> {
+    let i = index
+        .header()
+        .reference_sequence_names()
+        .get_index_of(region.name())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "region reference sequence does not exist in reference sequences: {:?}",
+                    region
+                ),
+            )
+        })?;
+
+    Ok((i, region.name().into()))
+}
+
 fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
 where
     R: BufRead,
@@ -179,4 +292,56 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{Cursor, Write};
+
+        use noodles_core::Position;
+        use noodles_csi::index::reference_sequence::bin::Chunk;
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        let mut indexer = tabix::index::Indexer::gtf();
+
+        writer.write_all(b"##format: gtf\n")?;
+
+        for line in [
+            "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+            "sq0\tNOODLES\tgene\t21\t34\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+            "sq1\tNOODLES\tgene\t5\t8\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+        ] {
+            let start_position = writer.virtual_position();
+            writeln!(writer, "{}", line)?;
+            let end_position = writer.virtual_position();
+
+            let record: Record = line.parse()?;
+
+            indexer.add_record(
+                record.reference_sequence_name(),
+                record.start(),
+                record.end(),
+                Chunk::new(start_position, end_position),
+            );
+        }
+
+        let data = writer.finish()?;
+        let index = indexer.build();
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+
+        let region = "sq0:10-15".parse()?;
+        let records: Vec<_> = reader.query(&index, &region)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].start(), Position::try_from(8)?);
+
+        let region = "sq1".parse()?;
+        let records: Vec<_> = reader.query(&index, &region)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].reference_sequence_name(), "sq1");
+
+        let region = "sq2".parse()?;
+        assert!(reader.query(&index, &region).is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,61 @@
+//! Request authentication.
+
+use futures::future::{self, BoxFuture};
+use reqwest::RequestBuilder;
+
+/// A request authenticator.
+///
+/// An authenticator is called before each request to the htsget server, i.e., service info,
+/// ticket, and block requests. Because it is called per request, it can refresh credentials
+/// between requests, e.g., when a token expires during a long download.
+///
+/// Block URLs are only authenticated when they have the same origin as the client base URL and
+/// the ticket does not already set an `Authorization` header for the block.
+pub trait Authenticator: Send + Sync {
+    /// Adds credentials to the given request.
+    fn authenticate(&self, request: RequestBuilder)
+        -> BoxFuture<'_, crate::Result<RequestBuilder>>;
+}
+
+/// A static bearer token authenticator.
+///
+/// This sets the `Authorization` header of each request to `Bearer <token>`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_htsget::{self as htsget, auth::BearerToken};
+///
+/// let client = htsget::Client::builder()
+///     .set_authenticator(BearerToken::new("ndls"))
+///     .build("https://localhost/".parse()?);
+/// # Ok::<_, url::ParseError>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BearerToken(String);
+
+impl BearerToken {
+    /// Creates a bearer token authenticator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget::auth::BearerToken;
+    /// let authenticator = BearerToken::new("ndls");
+    /// ```
+    pub fn new<T>(token: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self(token.into())
+    }
+}
+
+impl Authenticator for BearerToken {
+    fn authenticate(
+        &self,
+        request: RequestBuilder,
+    ) -> BoxFuture<'_, crate::Result<RequestBuilder>> {
+        Box::pin(future::ready(Ok(request.bearer_auth(&self.0))))
+    }
+}
@@ -0,0 +1,354 @@
+//! Htsget response blocks.
+
+mod builder;
+mod retry_policy;
+
+pub use self::{builder::Builder, retry_policy::RetryPolicy};
+
+use std::future::Future;
+
+use bytes::Bytes;
+use futures::{
+    stream::{self, BoxStream},
+    TryStreamExt,
+};
+use reqwest::StatusCode;
+
+use crate::{
+    chunks::{build_request, decode_data_url},
+    ticket::BlockUrl,
+    Client, Error,
+};
+
+/// A downloaded block of an htsget response.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Block {
+    index: usize,
+    data: Bytes,
+}
+
+impl Block {
+    /// Returns the index of the block in the ticket URLs.
+    ///
+    /// A download can be resumed after this block using [`Builder::set_start`] with `index + 1`.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the block data.
+    pub fn data(&self) -> &Bytes {
+        &self.data
+    }
+
+    /// Returns the block data, consuming the block.
+    pub fn into_data(self) -> Bytes {
+        self.data
+    }
+}
+
+async fn fetch_block(
+    client: &Client,
+    block_url: &BlockUrl,
+    retry_policy: RetryPolicy,
+) -> crate::Result<Bytes> {
+    if block_url.url().scheme() == "data" {
+        return decode_data_url(block_url.url());
+    }
+
+    retry(retry_policy, || async {
+        let response = send_block_request(client, block_url).await?;
+        response.bytes().await.map_err(Error::Request)
+    })
+    .await
+}
+
+// Returns a stream of the data from the ticket URLs.
+//
+// Unlike `fetch_block`, block bodies are not buffered. Block requests are retried, but an error
+// while reading a block body ends the stream.
+pub(crate) fn stream_data(
+    client: Client,
+    urls: Vec<BlockUrl>,
+    retry_policy: RetryPolicy,
+) -> BoxStream<'static, crate::Result<Bytes>> {
+    Box::pin(
+        stream::try_unfold((client, urls, 0), move |(client, urls, i)| async move {
+            match urls.get(i) {
+                Some(block_url) => {
+                    let data = open_block(&client, block_url, retry_policy).await?;
+                    Ok(Some((data, (client, urls, i + 1))))
+                }
+                None => Ok(None),
+            }
+        })
+        .try_flatten(),
+    )
+}
+
+async fn open_block(
+    client: &Client,
+    block_url: &BlockUrl,
+    retry_policy: RetryPolicy,
+) -> crate::Result<BoxStream<'static, crate::Result<Bytes>>> {
+    if block_url.url().scheme() == "data" {
+        let data = decode_data_url(block_url.url())?;
+        return Ok(Box::pin(stream::once(async { Ok(data) })));
+    }
+
+    let response = retry(retry_policy, || send_block_request(client, block_url)).await?;
+
+    Ok(Box::pin(response.bytes_stream().map_err(Error::Request)))
+}
+
+async fn send_block_request(
+    client: &Client,
+    block_url: &BlockUrl,
+) -> crate::Result<reqwest::Response> {
+    // The request is rebuilt for each attempt so that credentials can be refreshed.
+    let request = build_request(client, block_url).await?;
+
+    request
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(Error::Request)
+}
+
+async fn retry<F, Fut, T>(retry_policy: RetryPolicy, mut f: F) -> crate::Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = crate::Result<T>>,
+{
+    let mut retry = 0;
+
+    loop {
+        match f().await {
+            Ok(t) => return Ok(t),
+            Err(e) if retry < retry_policy.max_retries() && is_retryable(&e) => {
+                tokio::time::sleep(retry_policy.backoff(retry)).await;
+                retry += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+// Block requests are idempotent range requests, so timeouts, connection and request failures,
+// server errors, and rate limiting are retried. Other errors, e.g., client errors or failures to
+// decode the response body, are not.
+fn is_retryable(e: &Error) -> bool {
+    match e {
+        Error::Request(e) => match e.status() {
+            Some(status) => status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS,
+            None => e.is_timeout() || e.is_connect() || e.is_request(),
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+        thread,
+        time::Duration,
+    };
+
+    use crate::mock_server::{MockServer, Response};
+    use futures::StreamExt;
+    use url::Url;
+
+    use super::*;
+
+    // Starts a mock block server. `/blocks/<i>` returns `b<i>`, where block 0 is delayed and
+    // block 1 fails with a 503 on its first two requests. `/blocks/missing` returns a 404.
+    fn start_server() -> std::io::Result<(Url, MockServer)> {
+        let attempts = Arc::new(Mutex::new(HashMap::new()));
+
+        let server = MockServer::start(move |request| {
+            let attempt = {
+                let mut attempts = attempts.lock().unwrap();
+                let attempt = attempts.entry(request.target().to_string()).or_insert(0);
+                *attempt += 1;
+                *attempt
+            };
+
+            match request.target().trim_start_matches("/blocks/") {
+                "0" => {
+                    thread::sleep(Duration::from_millis(100));
+                    Response::ok("b0")
+                }
+                "1" if attempt <= 2 => Response::new(503, ""),
+                "missing" => Response::not_found(),
+                i => Response::ok(format!("b{}", i)),
+            }
+        })?;
+
+        let base_url = server.base_url().parse().unwrap();
+
+        Ok((base_url, server))
+    }
+
+    fn build_urls(base_url: &Url, names: &[&str]) -> Vec<BlockUrl> {
+        let urls: Vec<_> = names
+            .iter()
+            .map(|name| format!(r#"{{"url":"{}blocks/{}"}}"#, base_url, name))
+            .collect();
+
+        serde_json::from_str(&format!("[{}]", urls.join(","))).unwrap()
+    }
+
+    fn retry_policy(max_retries: usize) -> RetryPolicy {
+        RetryPolicy::new(
+            max_retries,
+            Duration::from_millis(1),
+            Duration::from_millis(1),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        let (base_url, server) = start_server()?;
+        let client = Client::new(base_url.clone());
+        let urls = build_urls(&base_url, &["0", "1", "2", "3"]);
+
+        let blocks: Vec<_> = Builder::new(client, urls)
+            .set_concurrency(4)
+            .set_retry_policy(retry_policy(2))
+            .build()
+            .collect()
+            .await;
+
+        let blocks = blocks.into_iter().collect::<crate::Result<Vec<_>>>()?;
+
+        assert_eq!(
+            blocks,
+            (0..4)
+                .map(|i| Block {
+                    index: i,
+                    data: Bytes::from(format!("b{}", i)),
+                })
+                .collect::<Vec<_>>()
+        );
+
+        let requests = server.targets();
+        assert_eq!(requests.len(), 6);
+        assert_eq!(requests.iter().filter(|p| *p == "/blocks/1").count(), 3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_build_with_start() -> Result<(), Box<dyn std::error::Error>> {
+        let (base_url, server) = start_server()?;
+        let client = Client::new(base_url.clone());
+        let urls = build_urls(&base_url, &["0", "2", "3"]);
+
+        let blocks: Vec<_> = Builder::new(client, urls)
+            .set_start(1)
+            .build()
+            .collect()
+            .await;
+        let blocks = blocks.into_iter().collect::<crate::Result<Vec<_>>>()?;
+
+        assert_eq!(
+            blocks.iter().map(|block| block.index()).collect::<Vec<_>>(),
+            [1, 2]
+        );
+
+        assert_eq!(server.targets(), ["/blocks/2", "/blocks/3"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_build_with_errors() -> Result<(), Box<dyn std::error::Error>> {
+        let (base_url, server) = start_server()?;
+        let client = Client::new(base_url.clone());
+
+        // Client errors are not retried, and the stream ends after the first error.
+        let urls = build_urls(&base_url, &["missing", "2"]);
+        let blocks: Vec<_> = Builder::new(client.clone(), urls)
+            .set_retry_policy(retry_policy(2))
+            .build()
+            .collect()
+            .await;
+
+        assert_eq!(blocks.len(), 1);
+        assert!(matches!(blocks[0], Err(Error::Request(_))));
+        assert_eq!(server.targets(), ["/blocks/missing"]);
+
+        server.clear_requests();
+
+        // Server errors are retried until the max number of retries is exceeded.
+        let urls = build_urls(&base_url, &["1"]);
+        let blocks: Vec<_> = Builder::new(client, urls)
+            .set_retry_policy(retry_policy(1))
+            .build()
+            .collect()
+            .await;
+
+        assert_eq!(blocks.len(), 1);
+        assert!(blocks[0].is_err());
+        assert_eq!(server.targets(), ["/blocks/1", "/blocks/1"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stream_data() -> Result<(), Box<dyn std::error::Error>> {
+        let (base_url, server) = start_server()?;
+        let client = Client::new(base_url.clone());
+
+        let urls = build_urls(&base_url, &["0", "1", "2"]);
+        let data: Vec<_> = stream_data(client.clone(), urls, retry_policy(2))
+            .try_collect()
+            .await?;
+
+        assert_eq!(data.concat(), b"b0b1b2");
+        assert_eq!(
+            server.targets(),
+            [
+                "/blocks/0",
+                "/blocks/1",
+                "/blocks/1",
+                "/blocks/1",
+                "/blocks/2"
+            ]
+        );
+
+        server.clear_requests();
+
+        let urls = build_urls(&base_url, &["missing", "2"]);
+        let result: crate::Result<Vec<_>> = stream_data(client, urls, retry_policy(2))
+            .try_collect()
+            .await;
+
+        assert!(matches!(result, Err(Error::Request(_))));
+        assert_eq!(server.targets(), ["/blocks/missing"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_is_retryable() -> Result<(), Box<dyn std::error::Error>> {
+        assert!(!is_retryable(&Error::InvalidDataUrl));
+
+        // Nothing is listening on the port after the listener is dropped.
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+        let e = reqwest::get(format!("http://{}/", addr)).await.unwrap_err();
+        assert!(is_retryable(&Error::Request(e)));
+
+        // A body that fails to decode is not retried.
+        let (base_url, _server) = start_server()?;
+        let e = reqwest::get(base_url.join("blocks/2")?)
+            .await?
+            .json::<serde_json::Value>()
+            .await
+            .unwrap_err();
+        assert!(!is_retryable(&Error::Request(e)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use futures::{
+    stream::{self, BoxStream},
+    StreamExt,
+};
+
+use super::{fetch_block, Block, RetryPolicy};
+use crate::{ticket::BlockUrl, Client};
+
+/// An htsget response blocks builder.
+pub struct Builder {
+    client: Client,
+    urls: Vec<BlockUrl>,
+    concurrency: usize,
+    retry_policy: RetryPolicy,
+    start: usize,
+}
+
+impl Builder {
+    pub(crate) fn new(client: Client, urls: Vec<BlockUrl>) -> Self {
+        Self {
+            client,
+            urls,
+            concurrency: 1,
+            retry_policy: RetryPolicy::default(),
+            start: 0,
+        }
+    }
+
+    /// Sets the max number of blocks to download concurrently.
+    ///
+    /// Blocks are still returned in ticket order. Each block is buffered in memory until it is
+    /// returned, so up to this many blocks can be held at once. By default, blocks are downloaded
+    /// one at a time.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the concurrency is 0.
+    pub fn set_concurrency(mut self, concurrency: usize) -> Self {
+        assert!(concurrency > 0, "invalid concurrency: {}", concurrency);
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Sets the retry policy for failed block requests.
+    ///
+    /// By default, this is [`RetryPolicy::default`].
+    pub fn set_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the index of the first block to download.
+    ///
+    /// This is used to resume a download after the last completed block. By default, this is 0,
+    /// i.e., all blocks are downloaded.
+    pub fn set_start(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Builds a stream of blocks.
+    ///
+    /// The stream ends after the first error.
+    pub fn build(self) -> BoxStream<'static, crate::Result<Block>> {
+        let Self {
+            client,
+            urls,
+            concurrency,
+            retry_policy,
+            start,
+        } = self;
+
+        let requests = urls
+            .into_iter()
+            .enumerate()
+            .skip(start)
+            .map(move |(index, block_url)| {
+                let client = client.clone();
+
+                async move {
+                    fetch_block(&client, &block_url, retry_policy)
+                        .await
+                        .map(|data| Block { index, data })
+                }
+            });
+
+        let blocks = Box::pin(stream::iter(requests).buffered(concurrency));
+
+        // The stream ends after the first error. Dropping the inner stream cancels any in-flight
+        // requests and prevents further blocks from being requested.
+        Box::pin(stream::unfold(Some(blocks), |state| async move {
+            let mut blocks = state?;
+            let result = blocks.next().await?;
+            let next_state = if result.is_ok() { Some(blocks) } else { None };
+            Some((result, next_state))
+        }))
+    }
+}
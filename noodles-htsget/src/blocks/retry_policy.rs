@@ -0,0 +1,116 @@
+use std::{cmp, time::Duration};
+
+/// A block request retry policy.
+///
+/// Failed block requests are retried with exponential backoff, i.e., the delay before retry `n`
+/// (starting at 0) is `initial_backoff * 2^n`, capped at `max_backoff`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    max_retries: usize,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a retry policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use noodles_htsget::blocks::RetryPolicy;
+    /// let retry_policy = RetryPolicy::new(3, Duration::from_millis(500), Duration::from_secs(8));
+    /// ```
+    pub fn new(max_retries: usize, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        Self {
+            max_retries,
+            initial_backoff,
+            max_backoff,
+        }
+    }
+
+    /// Creates a retry policy that never retries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget::blocks::RetryPolicy;
+    /// let retry_policy = RetryPolicy::none();
+    /// assert_eq!(retry_policy.max_retries(), 0);
+    /// ```
+    pub fn none() -> Self {
+        Self::new(0, Duration::ZERO, Duration::ZERO)
+    }
+
+    /// Returns the max number of retries per block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget::blocks::RetryPolicy;
+    /// assert_eq!(RetryPolicy::default().max_retries(), 3);
+    /// ```
+    pub fn max_retries(&self) -> usize {
+        self.max_retries
+    }
+
+    /// Returns the delay before the first retry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use noodles_htsget::blocks::RetryPolicy;
+    /// assert_eq!(RetryPolicy::default().initial_backoff(), Duration::from_millis(500));
+    /// ```
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    /// Returns the max delay between retries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use noodles_htsget::blocks::RetryPolicy;
+    /// assert_eq!(RetryPolicy::default().max_backoff(), Duration::from_secs(8));
+    /// ```
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    pub(crate) fn backoff(&self, retry: usize) -> Duration {
+        let factor = 1u32.checked_shl(retry as u32).unwrap_or(u32::MAX);
+
+        let backoff = self
+            .initial_backoff
+            .checked_mul(factor)
+            .unwrap_or(self.max_backoff);
+
+        cmp::min(backoff, self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(500), Duration::from_secs(8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let retry_policy = RetryPolicy::new(8, Duration::from_millis(500), Duration::from_secs(8));
+
+        assert_eq!(retry_policy.backoff(0), Duration::from_millis(500));
+        assert_eq!(retry_policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(retry_policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(retry_policy.backoff(4), Duration::from_secs(8));
+        assert_eq!(retry_policy.backoff(5), Duration::from_secs(8));
+        assert_eq!(retry_policy.backoff(64), Duration::from_secs(8));
+    }
+}
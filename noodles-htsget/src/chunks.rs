@@ -1,19 +1,23 @@
-use std::pin::Pin;
-
 use bytes::Bytes;
-use futures::{stream, Stream, TryStreamExt};
+use futures::{
+    stream::{self, BoxStream},
+    TryStreamExt,
+};
+
+use reqwest::RequestBuilder;
+use url::Url;
 
-use super::{response::ticket::BlockUrl, Client, Error};
+use super::{ticket::BlockUrl, Client, Error};
 
-pub(crate) fn chunks<'a>(
-    client: &'a Client,
-    urls: &'a [BlockUrl],
-) -> impl Stream<Item = crate::Result<Bytes>> + 'a {
+pub(crate) fn chunks(
+    client: Client,
+    urls: Vec<BlockUrl>,
+) -> BoxStream<'static, crate::Result<Bytes>> {
     Box::pin(
         stream::try_unfold((client, urls, 0), |(client, urls, i)| async move {
             match urls.get(i) {
                 Some(url) => {
-                    let st = resolve_data(client, url).await;
+                    let st = resolve_data(&client, url).await;
                     Ok(Some((st, (client, urls, i + 1))))
                 }
                 None => Ok(None),
@@ -26,32 +30,59 @@ pub(crate) fn chunks<'a>(
 async fn resolve_data(
     client: &Client,
     block_url: &BlockUrl,
-) -> Pin<Box<dyn Stream<Item = crate::Result<Bytes>>>> {
+) -> BoxStream<'static, crate::Result<Bytes>> {
+    if block_url.url().scheme() == "data" {
+        let result = decode_data_url(block_url.url());
+        return Box::pin(stream::once(async { result }));
+    }
+
+    let request = match build_request(client, block_url).await {
+        Ok(request) => request,
+        Err(e) => return Box::pin(stream::once(async { Err(e) })),
+    };
+
+    match request.send().await.map_err(Error::Request) {
+        Ok(response) => Box::pin(response.bytes_stream().map_err(Error::Request)),
+        Err(e) => Box::pin(stream::once(async { Err(e) })),
+    }
+}
+
+pub(crate) fn decode_data_url(url: &Url) -> crate::Result<Bytes> {
     const DELIMITER: &str = ";base64,";
 
+    // _Htsget retrieval API spec v1.3.0_ § "Inline data block URIs": "client should ignore the
+    // media type (if any), treating the payload as a partial blob."
+    let (_, encoded_data) = url
+        .as_str()
+        .split_once(DELIMITER)
+        .ok_or(Error::InvalidDataUrl)?;
+
+    base64::decode(encoded_data)
+        .map(Bytes::from)
+        .map_err(Error::Decode)
+}
+
+pub(crate) async fn build_request(
+    client: &Client,
+    block_url: &BlockUrl,
+) -> crate::Result<RequestBuilder> {
     let url = block_url.url();
+    let mut request = client.http_client().get(url.clone());
 
-    if url.scheme() == "data" {
-        // _Htsget retrieval API spec v1.3.0_ § "Inline data block URIs": "client should ignore the
-        // media type (if any), treating the payload as a partial blob."
-        if let Some((_, encoded_data)) = url.as_str().split_once(DELIMITER) {
-            match base64::decode(encoded_data) {
-                Ok(data) => Box::pin(stream::once(async { Ok(Bytes::from(data)) })),
-                Err(e) => Box::pin(stream::once(async { Err(Error::Decode(e)) })),
-            }
-        } else {
-            Box::pin(stream::once(async { Err(Error::InvalidDataUrl) }))
-        }
-    } else {
-        let mut request = client.http_client().get(url.clone());
-
-        for (key, value) in block_url.headers() {
-            request = request.header(key, value);
-        }
-
-        match request.send().await.map_err(Error::Request) {
-            Ok(response) => Box::pin(response.bytes_stream().map_err(Error::Request)),
-            Err(e) => Box::pin(stream::once(async { Err(e) })),
-        }
+    for (key, value) in block_url.headers() {
+        request = request.header(key, value);
     }
+
+    // Credentials are only sent to the htsget server and only when the ticket does not already
+    // authorize the block.
+    let is_authorized = block_url
+        .headers()
+        .keys()
+        .any(|key| key.eq_ignore_ascii_case("authorization"));
+
+    if !is_authorized && url.origin() == client.base_url().origin() {
+        request = client.authenticate(request).await?;
+    }
+
+    Ok(request)
 }
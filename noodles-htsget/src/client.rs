@@ -1,15 +1,38 @@
+//! Htsget client.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{fmt, sync::Arc};
+
+use reqwest::RequestBuilder;
 use url::Url;
 
-use super::{reads, request, request::Kind, variants};
+use super::{auth::Authenticator, reads, request, request::Kind, variants, Error, ServiceInfo};
 
 /// A htsget client.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Client {
     http_client: reqwest::Client,
     base_url: Url,
+    authenticator: Option<Arc<dyn Authenticator>>,
 }
 
 impl Client {
+    /// Creates an htsget client builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget as htsget;
+    /// let client = htsget::Client::builder().build("https://localhost/".parse()?);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
     /// Creates an htsget client with a default HTTP client.
     ///
     /// # Examples
@@ -36,10 +59,7 @@ impl Client {
     /// # Ok::<_, url::ParseError>(())
     /// ```
     pub fn with_http_client(http_client: reqwest::Client, base_url: Url) -> Self {
-        Self {
-            http_client,
-            base_url,
-        }
+        Self::builder().set_http_client(http_client).build(base_url)
     }
 
     pub(crate) fn http_client(&self) -> &reqwest::Client {
@@ -50,6 +70,16 @@ impl Client {
         &self.base_url
     }
 
+    pub(crate) async fn authenticate(
+        &self,
+        request: RequestBuilder,
+    ) -> crate::Result<RequestBuilder> {
+        match &self.authenticator {
+            Some(authenticator) => authenticator.authenticate(request).await,
+            None => Ok(request),
+        }
+    }
+
     /// Creates a reads request for the given ID.
     ///
     /// # Examples
@@ -85,4 +115,195 @@ impl Client {
         let builder = request::Builder::new(self.clone(), Kind::Variants, id);
         variants::Builder::new(builder)
     }
+
+    /// Queries the service info of the reads endpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), noodles_htsget::Error> {
+    /// use noodles_htsget as htsget;
+    /// let client = htsget::Client::new("https://localhost/".parse().unwrap());
+    /// let service_info = client.reads_service_info().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reads_service_info(&self) -> crate::Result<ServiceInfo> {
+        self.service_info(Kind::Reads).await
+    }
+
+    /// Queries the service info of the variants endpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), noodles_htsget::Error> {
+    /// use noodles_htsget as htsget;
+    /// let client = htsget::Client::new("https://localhost/".parse().unwrap());
+    /// let service_info = client.variants_service_info().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn variants_service_info(&self) -> crate::Result<ServiceInfo> {
+        self.service_info(Kind::Variants).await
+    }
+
+    async fn service_info(&self, kind: Kind) -> crate::Result<ServiceInfo> {
+        let endpoint = self
+            .base_url()
+            .join(&format!("{}/service-info", kind.as_str()))
+            .map_err(Error::Url)?;
+
+        let request = self.authenticate(self.http_client().get(endpoint)).await?;
+
+        let response = request
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(Error::Request)?;
+
+        response.json().await.map_err(Error::Request)
+    }
+}
+
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("http_client", &self.http_client)
+            .field("base_url", &self.base_url)
+            .field("authenticator", &self.authenticator.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::mock_server::{MockServer, Response};
+    use futures::{future::BoxFuture, TryStreamExt};
+
+    use super::*;
+
+    // Starts a mock htsget server.
+    fn start_server() -> std::io::Result<(Url, MockServer)> {
+        let server = MockServer::start(|request| {
+            let base_url = format!("http://{}/", request.header("host").unwrap_or_default());
+
+            match request.target() {
+                "/reads/service-info" => Response::ok(
+                    r#"{"id":"org.example.htsget","name":"htsget","type":{"group":"org.ga4gh","artifact":"htsget","version":"1.3.0"},"organization":{"name":"noodles","url":"https://example.com/"},"version":"0.1.0","htsget":{"datatype":"reads","formats":["BAM"],"fieldsParametersEffective":true,"tagsParametersEffective":false}}"#,
+                ),
+                "/reads/NDLS0001?format=BAM" => Response::ok(format!(
+                    r#"{{"htsget":{{"format":"BAM","urls":[{{"url":"{base}blocks/0"}},{{"url":"{base}blocks/1","headers":{{"Authorization":"Bearer block"}}}},{{"url":"data:;base64,bm9vZGxlcw=="}}]}}}}"#,
+                    base = base_url
+                )),
+                "/blocks/0" => Response::ok("ndls"),
+                "/blocks/1" => Response::ok("-"),
+                _ => Response::not_found(),
+            }
+        })?;
+
+        let base_url = server.base_url().parse().unwrap();
+
+        Ok((base_url, server))
+    }
+
+    // Returns each request target and its `Authorization` header.
+    fn requests(server: &MockServer) -> Vec<(String, Option<String>)> {
+        server
+            .requests()
+            .iter()
+            .map(|request| {
+                (
+                    request.target().into(),
+                    request.header("authorization").map(String::from),
+                )
+            })
+            .collect()
+    }
+
+    // Issues a new token for every request.
+    #[derive(Default)]
+    struct RefreshingToken(AtomicUsize);
+
+    impl Authenticator for RefreshingToken {
+        fn authenticate(
+            &self,
+            request: RequestBuilder,
+        ) -> BoxFuture<'_, crate::Result<RequestBuilder>> {
+            let n = self.0.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move { Ok(request.bearer_auth(format!("token-{}", n))) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reads_service_info() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::auth::BearerToken;
+
+        let (base_url, server) = start_server()?;
+
+        let client = Client::builder()
+            .set_authenticator(BearerToken::new("ndls"))
+            .build(base_url);
+
+        let service_info = client.reads_service_info().await?;
+        let htsget = service_info.htsget().expect("missing htsget");
+
+        assert_eq!(htsget.formats(), [crate::Format::Bam]);
+        assert!(htsget.fields_parameters_effective());
+        assert!(!htsget.tags_parameters_effective());
+        assert!(htsget.max_regions().is_none());
+
+        assert_eq!(
+            requests(&server),
+            [(
+                String::from("/reads/service-info"),
+                Some(String::from("Bearer ndls"))
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reads_with_authenticator() -> Result<(), Box<dyn std::error::Error>> {
+        let (base_url, server) = start_server()?;
+
+        let client = Client::builder()
+            .set_authenticator(RefreshingToken::default())
+            .build(base_url);
+
+        let reads = client
+            .reads("NDLS0001")
+            .set_method(crate::Method::Get)
+            .send()
+            .await?;
+        let chunks: Vec<_> = reads.chunks().try_collect().await?;
+        let data: Vec<u8> = chunks.concat();
+
+        assert_eq!(data, b"ndls-noodles");
+
+        assert_eq!(
+            requests(&server),
+            [
+                (
+                    String::from("/reads/NDLS0001?format=BAM"),
+                    Some(String::from("Bearer token-0"))
+                ),
+                (
+                    String::from("/blocks/0"),
+                    Some(String::from("Bearer token-1"))
+                ),
+                (
+                    String::from("/blocks/1"),
+                    Some(String::from("Bearer block"))
+                ),
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use url::Url;
+
+use super::Client;
+use crate::auth::Authenticator;
+
+/// An htsget client builder.
+#[derive(Default)]
+pub struct Builder {
+    http_client: Option<reqwest::Client>,
+    authenticator: Option<Arc<dyn Authenticator>>,
+}
+
+impl Builder {
+    /// Sets the HTTP client.
+    ///
+    /// By default, a default HTTP client is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget as htsget;
+    /// let builder = htsget::Client::builder().set_http_client(reqwest::Client::new());
+    /// ```
+    pub fn set_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Sets the request authenticator.
+    ///
+    /// By default, requests are not authenticated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget::{self as htsget, auth::BearerToken};
+    /// let builder = htsget::Client::builder().set_authenticator(BearerToken::new("ndls"));
+    /// ```
+    pub fn set_authenticator<A>(mut self, authenticator: A) -> Self
+    where
+        A: Authenticator + 'static,
+    {
+        self.authenticator = Some(Arc::new(authenticator));
+        self
+    }
+
+    /// Builds an htsget client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget as htsget;
+    /// let client = htsget::Client::builder().build("https://localhost/".parse()?);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn build(self, base_url: Url) -> Client {
+        Client {
+            http_client: self.http_client.unwrap_or_default(),
+            base_url,
+            authenticator: self.authenticator,
+        }
+    }
+}
@@ -13,3 +13,14 @@ pub enum Format {
     /// BCF.
     Bcf,
 }
+
+impl AsRef<str> for Format {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Bam => "BAM",
+            Self::Cram => "CRAM",
+            Self::Vcf => "VCF",
+            Self::Bcf => "BCF",
+        }
+    }
+}
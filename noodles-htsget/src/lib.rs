@@ -1,18 +1,35 @@
 #![warn(missing_docs)]
 
 //! **noodles-htsget** is an htsget client.
+//!
+//! With the `server` feature, it can also generate htsget tickets on the server (see [`server`]).
 
+pub mod auth;
+pub mod blocks;
 pub(crate) mod chunks;
-mod client;
+pub mod client;
 mod format;
+#[cfg(test)]
+#[path = "../tests/common/mock_server.rs"]
+mod mock_server;
 pub mod reads;
 pub(crate) mod request;
 pub(crate) mod response;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod service_info;
+pub mod ticket;
 pub mod variants;
 
-pub use self::{client::Client, format::Format, response::Response};
+pub use self::{
+    client::Client, format::Format, request::Method, response::Response, service_info::ServiceInfo,
+    ticket::Ticket,
+};
 
-use std::{error, fmt};
+#[cfg(any(feature = "reads", feature = "variants"))]
+pub use self::response::Records;
+
+use std::{error, fmt, io};
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -31,6 +48,10 @@ pub enum Error {
     Decode(base64::DecodeError),
     /// The data URL is invalid.
     InvalidDataUrl,
+    /// The request failed to authenticate.
+    Authentication(Box<dyn error::Error + Send + Sync>),
+    /// The data failed to read.
+    Io(io::Error),
 }
 
 impl error::Error for Error {}
@@ -44,6 +65,8 @@ impl fmt::Display for Error {
             Self::Response(e) => e.fmt(f),
             Self::Decode(e) => write!(f, "decode error: {}", e),
             Self::InvalidDataUrl => f.write_str("invalid data URL"),
+            Self::Authentication(e) => write!(f, "authentication error: {}", e),
+            Self::Io(e) => write!(f, "I/O error: {}", e),
         }
     }
 }
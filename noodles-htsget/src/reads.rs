@@ -1,6 +1,7 @@
 //! Reads endpoint.
 
 mod builder;
+mod field;
 mod format;
 
-pub use self::{builder::Builder, format::Format};
+pub use self::{builder::Builder, field::Field, format::Format};
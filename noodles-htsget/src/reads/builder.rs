@@ -1,9 +1,9 @@
 use noodles_core::Region;
 
-use super::Format;
+use super::{Field, Format};
 use crate::{
     request::{self, Class},
-    Response,
+    Method, Response,
 };
 
 /// A reads endpoint builder.
@@ -16,6 +16,15 @@ impl Builder {
         Self { inner }
     }
 
+    /// Sets the HTTP request method.
+    ///
+    /// By default, requests are sent using `POST`. A `GET` request can only query at most one
+    /// region; sending one with more regions fails with [`crate::Error::Input`].
+    pub fn set_method(mut self, method: Method) -> Self {
+        self.inner = self.inner.set_method(method);
+        self
+    }
+
     /// Sets the data format.
     pub fn set_format(mut self, format: Format) -> Self {
         self.inner = self.inner.set_format(format.into());
@@ -36,6 +45,39 @@ impl Builder {
         self
     }
 
+    /// Sets the fields to include.
+    ///
+    /// By default, all fields are included.
+    pub fn set_fields<I>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = Field>,
+    {
+        self.inner = self.inner.set_fields(fields);
+        self
+    }
+
+    /// Sets the tags to include.
+    ///
+    /// By default, all tags are included. An empty list excludes all tags.
+    pub fn set_tags<I, T>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.inner = self.inner.set_tags(tags);
+        self
+    }
+
+    /// Sets the tags to exclude.
+    pub fn set_notags<I, T>(mut self, notags: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.inner = self.inner.set_notags(notags);
+        self
+    }
+
     /// Sends the request.
     pub async fn send(self) -> crate::Result<Response> {
         self.inner.send().await
@@ -0,0 +1,66 @@
+/// A reads field.
+///
+/// These are the BAM fields that can be requested using the `fields` parameter.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Field {
+    /// Read name (`QNAME`).
+    ReadName,
+    /// Flags (`FLAG`).
+    Flags,
+    /// Reference sequence name (`RNAME`).
+    ReferenceSequenceName,
+    /// Alignment start (`POS`).
+    AlignmentStart,
+    /// Mapping quality (`MAPQ`).
+    MappingQuality,
+    /// CIGAR operations (`CIGAR`).
+    Cigar,
+    /// Mate reference sequence name (`RNEXT`).
+    MateReferenceSequenceName,
+    /// Mate alignment start (`PNEXT`).
+    MateAlignmentStart,
+    /// Template length (`TLEN`).
+    TemplateLength,
+    /// Sequence (`SEQ`).
+    Sequence,
+    /// Quality scores (`QUAL`).
+    QualityScores,
+}
+
+impl AsRef<str> for Field {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::ReadName => "QNAME",
+            Self::Flags => "FLAG",
+            Self::ReferenceSequenceName => "RNAME",
+            Self::AlignmentStart => "POS",
+            Self::MappingQuality => "MAPQ",
+            Self::Cigar => "CIGAR",
+            Self::MateReferenceSequenceName => "RNEXT",
+            Self::MateAlignmentStart => "PNEXT",
+            Self::TemplateLength => "TLEN",
+            Self::Sequence => "SEQ",
+            Self::QualityScores => "QUAL",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_ref_str() {
+        assert_eq!(Field::ReadName.as_ref(), "QNAME");
+        assert_eq!(Field::Flags.as_ref(), "FLAG");
+        assert_eq!(Field::ReferenceSequenceName.as_ref(), "RNAME");
+        assert_eq!(Field::AlignmentStart.as_ref(), "POS");
+        assert_eq!(Field::MappingQuality.as_ref(), "MAPQ");
+        assert_eq!(Field::Cigar.as_ref(), "CIGAR");
+        assert_eq!(Field::MateReferenceSequenceName.as_ref(), "RNEXT");
+        assert_eq!(Field::MateAlignmentStart.as_ref(), "PNEXT");
+        assert_eq!(Field::TemplateLength.as_ref(), "TLEN");
+        assert_eq!(Field::Sequence.as_ref(), "SEQ");
+        assert_eq!(Field::QualityScores.as_ref(), "QUAL");
+    }
+}
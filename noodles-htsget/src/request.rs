@@ -3,8 +3,9 @@
 mod builder;
 mod class;
 mod kind;
+mod method;
 mod payload;
 
-pub use self::class::Class;
+pub use self::{class::Class, method::Method};
 
 pub(crate) use self::{builder::Builder, kind::Kind, payload::Payload};
@@ -1,15 +1,17 @@
 use noodles_core::Region;
+use reqwest::RequestBuilder;
 use serde::Deserialize;
 use url::Url;
 
-use super::{Class, Kind, Payload};
-use crate::{response::Ticket, Client, Error, Format, Response};
+use super::{Class, Kind, Method, Payload};
+use crate::{Client, Error, Format, Response, Ticket};
 
 /// A request builder.
 pub struct Builder {
     client: Client,
     kind: Kind,
     id: String,
+    method: Method,
     payload: Payload,
 }
 
@@ -22,10 +24,16 @@ impl Builder {
             client,
             kind,
             id: id.into(),
+            method: Method::default(),
             payload: Payload::from(kind),
         }
     }
 
+    pub fn set_method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
+
     pub fn set_format(mut self, format: Format) -> Self {
         *self.payload.format_mut() = format;
         self
@@ -41,46 +49,126 @@ impl Builder {
         self
     }
 
+    pub fn set_fields<I, F>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = F>,
+        F: AsRef<str>,
+    {
+        *self.payload.fields_mut() = fields.into_iter().map(|f| f.as_ref().into()).collect();
+        self
+    }
+
+    pub fn set_tags<I, T>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        *self.payload.tags_mut() = Some(tags.into_iter().map(|t| t.into()).collect());
+        self
+    }
+
+    pub fn set_notags<I, T>(mut self, notags: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        *self.payload.notags_mut() = notags.into_iter().map(|t| t.into()).collect();
+        self
+    }
+
     pub async fn send(self) -> crate::Result<Response> {
         let endpoint = build_endpoint(self.client.base_url(), self.kind, &self.id)?;
-        let mut request = self.client.http_client().post(endpoint);
 
-        request = match self.payload.class() {
+        let payload = match self.payload.class() {
             Some(Class::Header) => {
                 let mut payload = Payload::from(self.kind);
+                *payload.format_mut() = self.payload.format();
                 *payload.class_mut() = Some(Class::Header);
-                request.json(&payload)
+                payload
             }
-            None => request.json(&self.payload),
+            None => self.payload,
         };
 
+        let request = build_request(self.client.http_client(), endpoint, self.method, &payload)?;
+        let request = self.client.authenticate(request).await?;
         let response = request.send().await.map_err(Error::Request)?;
 
         if response.status().is_client_error() {
             let data: ErrorResponse = response.json().await.map_err(Error::Request)?;
             Err(Error::Response(data.htsget))
         } else {
-            let data: TicketResponse = response.json().await.map_err(Error::Request)?;
-            Ok(Response::new(self.client, self.id, data.htsget))
+            let ticket: Ticket = response.json().await.map_err(Error::Request)?;
+            Ok(Response::new(self.client, self.id, ticket))
         }
     }
 }
 
-#[derive(Deserialize)]
-pub struct TicketResponse {
-    htsget: Ticket,
-}
-
 #[derive(Deserialize)]
 pub struct ErrorResponse {
     htsget: crate::response::Error,
 }
 
+fn build_request(
+    http_client: &reqwest::Client,
+    endpoint: Url,
+    method: Method,
+    payload: &Payload,
+) -> crate::Result<RequestBuilder> {
+    match method {
+        Method::Get => {
+            // GET requests can only query at most one region.
+            if payload.regions().len() > 1 {
+                Err(Error::Input)
+            } else {
+                Ok(http_client.get(endpoint).query(&payload.to_query()))
+            }
+        }
+        Method::Post => Ok(http_client.post(endpoint).json(payload)),
+    }
+}
+
 fn build_endpoint(base_url: &Url, kind: Kind, id: &str) -> crate::Result<Url> {
-    let k = match kind {
-        Kind::Reads => "reads",
-        Kind::Variants => "variants",
-    };
+    base_url
+        .join(&format!("{}/{}", kind.as_str(), id))
+        .map_err(Error::Url)
+}
 
-    base_url.join(&format!("{}/{}", k, id)).map_err(Error::Url)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_request() -> Result<(), Box<dyn std::error::Error>> {
+        let http_client = reqwest::Client::new();
+        let endpoint: Url = "https://localhost/reads/NDLS0001".parse()?;
+
+        let mut payload = Payload::from(Kind::Reads);
+        payload.regions_mut().push("sq0:8-13".parse()?);
+
+        let request =
+            build_request(&http_client, endpoint.clone(), Method::Post, &payload)?.build()?;
+        assert_eq!(request.method(), reqwest::Method::POST);
+        assert_eq!(request.url(), &endpoint);
+        assert!(request.body().is_some());
+
+        let request =
+            build_request(&http_client, endpoint.clone(), Method::Get, &payload)?.build()?;
+        assert_eq!(request.method(), reqwest::Method::GET);
+        assert_eq!(
+            request.url().query(),
+            Some("format=BAM&referenceName=sq0&start=7&end=13")
+        );
+        assert!(request.body().is_none());
+
+        // GET requests cannot query more than one region.
+        payload.regions_mut().push("sq1".parse()?);
+
+        assert!(build_request(&http_client, endpoint.clone(), Method::Post, &payload).is_ok());
+        assert!(matches!(
+            build_request(&http_client, endpoint, Method::Get, &payload),
+            Err(Error::Input)
+        ));
+
+        Ok(())
+    }
 }
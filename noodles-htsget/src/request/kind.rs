@@ -3,3 +3,12 @@ pub enum Kind {
     Reads,
     Variants,
 }
+
+impl Kind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Reads => "reads",
+            Self::Variants => "variants",
+        }
+    }
+}
@@ -0,0 +1,20 @@
+/// An htsget request HTTP method.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Method {
+    /// A `GET` request.
+    ///
+    /// The request parameters are sent as a query string, which can only include at most one
+    /// region.
+    Get,
+    /// A `POST` request.
+    ///
+    /// The request parameters are sent as a JSON body. Servers are not required to support
+    /// `POST` requests.
+    Post,
+}
+
+impl Default for Method {
+    fn default() -> Self {
+        Self::Post
+    }
+}
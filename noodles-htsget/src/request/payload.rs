@@ -3,7 +3,7 @@ mod regions;
 use noodles_core::Region;
 use serde::Serialize;
 
-use self::regions::Regions;
+use self::regions::{resolve_interval, Regions};
 use super::{Class, Kind};
 use crate::Format;
 
@@ -14,11 +14,24 @@ pub struct Payload {
     #[serde(skip_serializing_if = "Option::is_none")]
     class: Option<Class>,
 
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fields: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    notags: Vec<String>,
+
     #[serde(skip_serializing_if = "Regions::is_empty")]
     regions: Regions,
 }
 
 impl Payload {
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
     pub fn format_mut(&mut self) -> &mut Format {
         &mut self.format
     }
@@ -31,9 +44,63 @@ impl Payload {
         &mut self.class
     }
 
+    pub fn fields_mut(&mut self) -> &mut Vec<String> {
+        &mut self.fields
+    }
+
+    pub fn tags_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.tags
+    }
+
+    pub fn notags_mut(&mut self) -> &mut Vec<String> {
+        &mut self.notags
+    }
+
+    pub fn regions(&self) -> &[Region] {
+        &self.regions.0
+    }
+
     pub fn regions_mut(&mut self) -> &mut Vec<Region> {
         &mut self.regions.0
     }
+
+    // A GET request can only express at most one region, so only the first is used.
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![("format", self.format.as_ref().into())];
+
+        if let Some(Class::Header) = self.class {
+            query.push(("class", "header".into()));
+        }
+
+        if let Some(region) = self.regions().first() {
+            query.push(("referenceName", region.name().into()));
+
+            let (resolved_start, resolved_end) = resolve_interval(region.interval());
+
+            if let Some(start) = resolved_start {
+                query.push(("start", start.to_string()));
+            }
+
+            if let Some(end) = resolved_end {
+                query.push(("end", end.to_string()));
+            }
+        }
+
+        if !self.fields.is_empty() {
+            query.push(("fields", self.fields.join(",")));
+        }
+
+        // An empty list of tags excludes all tags.
+        if let Some(tags) = &self.tags {
+            query.push(("tags", tags.join(",")));
+        }
+
+        if !self.notags.is_empty() {
+            query.push(("notags", self.notags.join(",")));
+        }
+
+        query
+    }
 }
 
 impl From<Kind> for Payload {
@@ -46,6 +113,9 @@ impl From<Kind> for Payload {
         Self {
             format,
             class: None,
+            fields: Vec::new(),
+            tags: None,
+            notags: Vec::new(),
             regions: Regions::default(),
         }
     }
@@ -127,5 +197,88 @@ mod tests {
                 Token::StructEnd,
             ],
         );
+
+        let mut payload = Payload::from(Kind::Reads);
+        *payload.fields_mut() = vec![String::from("QNAME"), String::from("FLAG")];
+        *payload.tags_mut() = Some(Vec::new());
+        *payload.notags_mut() = vec![String::from("OQ")];
+
+        assert_ser_tokens(
+            &payload,
+            &[
+                Token::Struct {
+                    name: "Payload",
+                    len: 4,
+                },
+                Token::Str("format"),
+                Token::UnitVariant {
+                    name: "Format",
+                    variant: "BAM",
+                },
+                Token::Str("fields"),
+                Token::Seq { len: Some(2) },
+                Token::Str("QNAME"),
+                Token::Str("FLAG"),
+                Token::SeqEnd,
+                Token::Str("tags"),
+                Token::Some,
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("notags"),
+                Token::Seq { len: Some(1) },
+                Token::Str("OQ"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_to_query() -> Result<(), noodles_core::position::TryFromIntError> {
+        use noodles_core::Position;
+
+        let payload = Payload::from(Kind::Variants);
+        assert_eq!(payload.to_query(), [("format", String::from("VCF"))]);
+
+        let mut payload = Payload::from(Kind::Reads);
+        *payload.class_mut() = Some(Class::Header);
+        assert_eq!(
+            payload.to_query(),
+            [
+                ("format", String::from("BAM")),
+                ("class", String::from("header"))
+            ]
+        );
+
+        let mut payload = Payload::from(Kind::Reads);
+        payload.regions_mut().push(Region::new(
+            "sq0",
+            Position::try_from(8)?..=Position::try_from(13)?,
+        ));
+        *payload.fields_mut() = vec![String::from("QNAME"), String::from("FLAG")];
+        *payload.tags_mut() = Some(vec![String::from("NM"), String::from("MD")]);
+        *payload.notags_mut() = vec![String::from("OQ")];
+
+        assert_eq!(
+            payload.to_query(),
+            [
+                ("format", String::from("BAM")),
+                ("referenceName", String::from("sq0")),
+                ("start", String::from("7")),
+                ("end", String::from("13")),
+                ("fields", String::from("QNAME,FLAG")),
+                ("tags", String::from("NM,MD")),
+                ("notags", String::from("OQ")),
+            ]
+        );
+
+        let mut payload = Payload::from(Kind::Reads);
+        *payload.tags_mut() = Some(Vec::new());
+        assert_eq!(
+            payload.to_query(),
+            [("format", String::from("BAM")), ("tags", String::new())]
+        );
+
+        Ok(())
     }
 }
@@ -55,7 +55,7 @@ impl<'a> Serialize for HtsgetRegion<'a> {
     }
 }
 
-fn resolve_interval<I>(interval: I) -> (Option<usize>, Option<usize>)
+pub(super) fn resolve_interval<I>(interval: I) -> (Option<usize>, Option<usize>)
 where
     I: Into<Interval>,
 {
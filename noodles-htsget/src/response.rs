@@ -1,13 +1,17 @@
 mod error;
-pub(crate) mod ticket;
+#[cfg(any(feature = "reads", feature = "variants"))]
+mod records;
 
 pub use self::error::Error;
-pub(crate) use self::ticket::Ticket;
+#[cfg(any(feature = "reads", feature = "variants"))]
+pub use self::records::Records;
 
 use bytes::Bytes;
-use futures::Stream;
+use futures::{Stream, TryStreamExt};
+use tokio::io::{self, AsyncBufRead};
+use tokio_util::io::StreamReader;
 
-use super::Client;
+use super::{blocks, Client, Format, Ticket};
 
 /// An htsget response.
 #[derive(Debug)]
@@ -27,9 +31,113 @@ impl Response {
         &self.id
     }
 
+    /// Returns the format of the data.
+    pub fn format(&self) -> Format {
+        self.ticket.format()
+    }
+
+    /// Returns the number of blocks in the ticket.
+    pub fn block_count(&self) -> usize {
+        self.ticket.urls().len()
+    }
+
+    /// Creates a builder for a stream of downloaded blocks.
+    ///
+    /// Unlike [`Self::chunks`], this supports concurrent downloads, retries, and resuming from a
+    /// given block.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// use noodles_htsget as htsget;
+    ///
+    /// let client = htsget::Client::new("https://localhost/".parse()?);
+    /// let response = client.reads("NDLS0001").send().await?;
+    ///
+    /// let mut blocks = response.blocks().set_concurrency(4).build();
+    ///
+    /// while let Some(block) = blocks.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn blocks(&self) -> blocks::Builder {
+        blocks::Builder::new(self.client.clone(), self.ticket.urls().to_vec())
+    }
+
     /// Returns the data from the ticket URLs.
     pub fn chunks(&self) -> impl Stream<Item = crate::Result<Bytes>> + '_ {
         use super::chunks::chunks;
-        chunks(&self.client, self.ticket.urls())
+        chunks(self.client.clone(), self.ticket.urls().to_vec())
+    }
+
+    /// Returns a reader of the concatenated data from the ticket URLs.
+    ///
+    /// Blocks are downloaded one at a time, and their bodies are streamed rather than buffered,
+    /// so only the data of the current block read is held in memory. Failed block requests are
+    /// retried using [`blocks::RetryPolicy::default`], but an error while reading a block body is
+    /// not.
+    ///
+    /// To download blocks concurrently or resume a download, use [`Self::blocks`] instead. Note
+    /// that it buffers each block in full.
+    ///
+    /// Errors from fetching the data are returned as I/O errors.
+    pub fn into_reader(self) -> impl AsyncBufRead + Unpin + Send {
+        let data = blocks::stream_data(
+            self.client,
+            self.ticket.urls().to_vec(),
+            blocks::RetryPolicy::default(),
+        )
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e));
+
+        StreamReader::new(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::mock_server::MockServer;
+    use tokio::io::AsyncReadExt;
+    use url::Url;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_into_reader() -> Result<(), Box<dyn std::error::Error>> {
+        let attempts = AtomicUsize::new(0);
+
+        // `/blocks/1` fails on its first request.
+        let server = MockServer::start(move |request| match request.target() {
+            "/blocks/0" => crate::mock_server::Response::ok("ndls"),
+            "/blocks/1" if attempts.fetch_add(1, Ordering::SeqCst) == 0 => {
+                crate::mock_server::Response::new(503, "")
+            }
+            "/blocks/1" => crate::mock_server::Response::ok("-"),
+            _ => crate::mock_server::Response::not_found(),
+        })?;
+
+        let base_url: Url = server.base_url().parse()?;
+
+        let ticket = serde_json::from_str(&format!(
+            r#"{{"htsget":{{"format":"BAM","urls":[{{"url":"{base}blocks/0"}},{{"url":"{base}blocks/1"}},{{"url":"data:;base64,bm9vZGxlcw=="}}]}}}}"#,
+            base = base_url
+        ))?;
+
+        let response = Response::new(Client::new(base_url), String::from("NDLS0001"), ticket);
+
+        let mut reader = response.into_reader();
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).await?;
+
+        assert_eq!(buf, b"ndls-noodles");
+        assert_eq!(server.targets(), ["/blocks/0", "/blocks/1", "/blocks/1"]);
+
+        Ok(())
     }
 }
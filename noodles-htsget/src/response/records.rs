@@ -0,0 +1,475 @@
+use std::pin::Pin;
+
+use futures::{stream, Stream};
+use tokio::io::{self, AsyncBufRead};
+
+use super::Response;
+use crate::{Error, Format};
+
+#[cfg(feature = "reads")]
+use {noodles_bam as bam, noodles_cram as cram, noodles_fasta as fasta, noodles_sam as sam};
+
+#[cfg(feature = "variants")]
+use {noodles_bcf as bcf, noodles_bgzf as bgzf, noodles_vcf as vcf};
+
+/// A stream of records decoded from an htsget response.
+pub type Records<T> = Pin<Box<dyn Stream<Item = io::Result<T>> + Send>>;
+
+impl Response {
+    /// Decodes the response data as alignment records.
+    ///
+    /// The data is decoded as BAM or CRAM, depending on the ticket format. The reference sequence
+    /// repository is only used for CRAM data.
+    ///
+    /// This returns the header and a stream of records. An error is returned if the response is
+    /// not reads data.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// use noodles_fasta as fasta;
+    /// use noodles_htsget as htsget;
+    ///
+    /// let client = htsget::Client::new("https://localhost/".parse()?);
+    /// let response = client.reads("NDLS0001").send().await?;
+    ///
+    /// let (header, mut records) = response
+    ///     .into_alignment_records(fasta::Repository::default())
+    ///     .await?;
+    ///
+    /// while let Some(record) = records.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "reads")]
+    pub async fn into_alignment_records(
+        self,
+        reference_sequence_repository: fasta::Repository,
+    ) -> crate::Result<(sam::Header, Records<sam::alignment::Record>)> {
+        match self.format() {
+            Format::Bam => {
+                let reader = bam::AsyncReader::new(self.into_reader());
+                bam_records(reader).await.map_err(Error::Io)
+            }
+            Format::Cram => {
+                let reader = cram::AsyncReader::new(self.into_reader());
+                cram_records(reader, reference_sequence_repository)
+                    .await
+                    .map_err(Error::Io)
+            }
+            Format::Vcf | Format::Bcf => Err(Error::Input),
+        }
+    }
+
+    /// Decodes the response data as variant records.
+    ///
+    /// The data is decoded as (bgzipped) VCF or BCF, depending on the ticket format. BCF records
+    /// are converted to VCF records.
+    ///
+    /// This returns the header and a stream of records. An error is returned if the response is
+    /// not variants data.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// use noodles_htsget as htsget;
+    ///
+    /// let client = htsget::Client::new("https://localhost/".parse()?);
+    /// let response = client.variants("NDLS0001").send().await?;
+    ///
+    /// let (header, mut records) = response.into_variant_records().await?;
+    ///
+    /// while let Some(record) = records.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "variants")]
+    pub async fn into_variant_records(self) -> crate::Result<(vcf::Header, Records<vcf::Record>)> {
+        match self.format() {
+            Format::Vcf => {
+                let reader = vcf::AsyncReader::new(bgzf::AsyncReader::new(self.into_reader()));
+                vcf_records(reader).await.map_err(Error::Io)
+            }
+            Format::Bcf => {
+                let reader = bcf::AsyncReader::new(self.into_reader());
+                bcf_records(reader).await.map_err(Error::Io)
+            }
+            Format::Bam | Format::Cram => Err(Error::Input),
+        }
+    }
+}
+
+fn invalid_data<E>(e: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+#[cfg(feature = "reads")]
+async fn bam_records<R>(
+    mut reader: bam::AsyncReader<R>,
+) -> io::Result<(sam::Header, Records<sam::alignment::Record>)>
+where
+    R: AsyncBufRead + Unpin + Send + 'static,
+{
+    let raw_header = reader.read_header().await?;
+    let reference_sequences = reader.read_reference_sequences().await?;
+
+    let header = if raw_header.is_empty() {
+        sam::Header::builder()
+            .set_reference_sequences(reference_sequences)
+            .build()
+    } else {
+        raw_header.parse().map_err(invalid_data)?
+    };
+
+    let records = Box::pin(stream::try_unfold(reader, |mut reader| async {
+        let mut record = sam::alignment::Record::default();
+
+        match reader.read_record(&mut record).await? {
+            0 => Ok(None),
+            _ => Ok(Some((record, reader))),
+        }
+    }));
+
+    Ok((header, records))
+}
+
+#[cfg(feature = "reads")]
+async fn cram_records<R>(
+    mut reader: cram::AsyncReader<R>,
+    reference_sequence_repository: fasta::Repository,
+) -> io::Result<(sam::Header, Records<sam::alignment::Record>)>
+where
+    R: AsyncBufRead + Unpin + Send + 'static,
+{
+    use std::vec;
+
+    reader.read_file_definition().await?;
+    let header: sam::Header = reader
+        .read_file_header()
+        .await?
+        .parse()
+        .map_err(invalid_data)?;
+
+    struct Context<R> {
+        reader: cram::AsyncReader<R>,
+        reference_sequence_repository: fasta::Repository,
+        header: sam::Header,
+        records: vec::IntoIter<sam::alignment::Record>,
+    }
+
+    let ctx = Context {
+        reader,
+        reference_sequence_repository,
+        header: header.clone(),
+        records: Vec::new().into_iter(),
+    };
+
+    let records = Box::pin(stream::try_unfold(ctx, |mut ctx| async {
+        loop {
+            if let Some(record) = ctx.records.next() {
+                return Ok(Some((record, ctx)));
+            }
+
+            let container = match ctx.reader.read_data_container().await? {
+                Some(container) => container,
+                None => return Ok(None),
+            };
+
+            let compression_header = container.compression_header();
+            let mut records = Vec::new();
+
+            for slice in container.slices() {
+                let mut slice_records = slice.records(compression_header)?;
+
+                slice.resolve_records(
+                    &ctx.reference_sequence_repository,
+                    &ctx.header,
+                    compression_header,
+                    &mut slice_records,
+                )?;
+
+                for record in slice_records {
+                    records.push(record.try_into_alignment_record(&ctx.header)?);
+                }
+            }
+
+            ctx.records = records.into_iter();
+        }
+    }));
+
+    Ok((header, records))
+}
+
+#[cfg(feature = "variants")]
+async fn vcf_records<R>(
+    mut reader: vcf::AsyncReader<R>,
+) -> io::Result<(vcf::Header, Records<vcf::Record>)>
+where
+    R: AsyncBufRead + Unpin + Send + 'static,
+{
+    let header: vcf::Header = reader.read_header().await?.parse().map_err(invalid_data)?;
+
+    let records = Box::pin(stream::try_unfold(
+        (reader, header.clone(), String::new()),
+        |(mut reader, header, mut buf)| async {
+            buf.clear();
+
+            match reader.read_record(&mut buf).await? {
+                0 => Ok(None),
+                _ => {
+                    let record = vcf::Record::try_from_str(&buf, &header).map_err(invalid_data)?;
+                    Ok(Some((record, (reader, header, buf))))
+                }
+            }
+        },
+    ));
+
+    Ok((header, records))
+}
+
+#[cfg(feature = "variants")]
+async fn bcf_records<R>(
+    mut reader: bcf::AsyncReader<R>,
+) -> io::Result<(vcf::Header, Records<vcf::Record>)>
+where
+    R: AsyncBufRead + Unpin + Send + 'static,
+{
+    reader.read_file_format().await?;
+
+    let raw_header = reader.read_header().await?;
+    let header: vcf::Header = raw_header.parse().map_err(invalid_data)?;
+    let string_maps: bcf::header::StringMaps = raw_header.parse().map_err(invalid_data)?;
+
+    let records = Box::pin(stream::try_unfold(
+        (reader, header.clone(), string_maps),
+        |(mut reader, header, string_maps)| async {
+            let mut record = bcf::Record::default();
+
+            match reader.read_record(&mut record).await? {
+                0 => Ok(None),
+                _ => {
+                    let record = record.try_into_vcf_record(&header, &string_maps)?;
+                    Ok(Some((record, (reader, header, string_maps))))
+                }
+            }
+        },
+    ));
+
+    Ok((header, records))
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::{response::Ticket, Client};
+
+    // Builds a response with the data split across two inline data blocks.
+    fn build_response(format: Format, data: &[u8]) -> Response {
+        let (a, b) = data.split_at(data.len() / 2);
+
+        let format = match format {
+            Format::Bam => "BAM",
+            Format::Cram => "CRAM",
+            Format::Vcf => "VCF",
+            Format::Bcf => "BCF",
+        };
+
+        let ticket: Ticket = serde_json::from_str(&format!(
+            r#"{{"htsget":{{"format":"{}","urls":[{{"url":"data:;base64,{}"}},{{"url":"data:;base64,{}"}}]}}}}"#,
+            format,
+            base64::encode(a),
+            base64::encode(b),
+        ))
+        .unwrap();
+
+        let client = Client::new("http://localhost/".parse().unwrap());
+
+        Response::new(client, String::from("NDLS0001"), ticket)
+    }
+
+    #[cfg(feature = "reads")]
+    fn build_alignment_records(
+    ) -> Result<(sam::Header, Vec<sam::alignment::Record>), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .set_header(Default::default())
+            .add_reference_sequence(sam::header::ReferenceSequence::new("sq0".parse()?, 8)?)
+            .build();
+
+        let records = ["r0", "r1", "r2"]
+            .iter()
+            .map(|name| {
+                sam::alignment::Record::builder()
+                    .set_read_name(name.parse().unwrap())
+                    .build()
+            })
+            .collect();
+
+        Ok((header, records))
+    }
+
+    #[cfg(feature = "reads")]
+    #[tokio::test]
+    async fn test_into_alignment_records_with_bam() -> Result<(), Box<dyn std::error::Error>> {
+        let (header, expected) = build_alignment_records()?;
+
+        let mut writer = bam::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        for record in &expected {
+            writer.write_record(&header, record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        let response = build_response(Format::Bam, &data);
+        let (actual_header, records) = response
+            .into_alignment_records(fasta::Repository::default())
+            .await?;
+        let actual: Vec<_> = records.try_collect().await?;
+
+        assert_eq!(actual_header, header);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "reads")]
+    #[tokio::test]
+    async fn test_into_alignment_records_with_cram() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, expected) = build_alignment_records()?;
+
+        // CRAM requires reference sequence MD5 checksums, but the records are unmapped.
+        let header = sam::Header::builder()
+            .set_header(Default::default())
+            .build();
+
+        let mut writer = cram::Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for record in &expected {
+            let cram_record = cram::Record::try_from_alignment_record(&header, record)?;
+            writer.write_record(&header, cram_record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let response = build_response(Format::Cram, writer.get_ref());
+        let (actual_header, records) = response
+            .into_alignment_records(fasta::Repository::default())
+            .await?;
+        let actual: Vec<_> = records.try_collect().await?;
+
+        assert_eq!(actual_header, header);
+        assert_eq!(
+            actual.iter().map(|r| r.read_name()).collect::<Vec<_>>(),
+            expected.iter().map(|r| r.read_name()).collect::<Vec<_>>(),
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "reads")]
+    #[tokio::test]
+    async fn test_into_alignment_records_with_variants_format() {
+        let response = build_response(Format::Vcf, &[]);
+
+        assert!(matches!(
+            response
+                .into_alignment_records(fasta::Repository::default())
+                .await,
+            Err(Error::Input)
+        ));
+    }
+
+    #[cfg(feature = "variants")]
+    fn build_variant_records() -> Result<(vcf::Header, Vec<vcf::Record>), Box<dyn std::error::Error>>
+    {
+        use vcf::{
+            header::record::value::{map::Contig, Map},
+            record::Position,
+        };
+
+        let header = vcf::Header::builder()
+            .add_contig(Map::<Contig>::new("sq0".parse()?))
+            .build();
+
+        let records = (1..=3)
+            .map(|i| {
+                vcf::Record::builder()
+                    .set_chromosome("sq0".parse()?)
+                    .set_position(Position::from(i))
+                    .set_reference_bases("A".parse()?)
+                    .build()
+                    .map_err(|e| e.into())
+            })
+            .collect::<Result<_, Box<dyn std::error::Error>>>()?;
+
+        Ok((header, records))
+    }
+
+    #[cfg(feature = "variants")]
+    #[tokio::test]
+    async fn test_into_variant_records_with_vcf() -> Result<(), Box<dyn std::error::Error>> {
+        let (header, expected) = build_variant_records()?;
+
+        let mut writer = vcf::Writer::new(bgzf::Writer::new(Vec::new()));
+        writer.write_header(&header)?;
+
+        for record in &expected {
+            writer.write_record(record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        let response = build_response(Format::Vcf, &data);
+        let (actual_header, records) = response.into_variant_records().await?;
+        let actual: Vec<_> = records.try_collect().await?;
+
+        assert_eq!(actual_header, header);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "variants")]
+    #[tokio::test]
+    async fn test_into_variant_records_with_bcf() -> Result<(), Box<dyn std::error::Error>> {
+        let (header, expected) = build_variant_records()?;
+        let string_maps = bcf::header::StringMaps::from(&header);
+
+        let mut writer = bcf::Writer::new(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        for record in &expected {
+            writer.write_vcf_record(&header, &string_maps, record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        let response = build_response(Format::Bcf, &data);
+        let (_, records) = response.into_variant_records().await?;
+        let actual: Vec<_> = records.try_collect().await?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
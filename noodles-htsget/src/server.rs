@@ -0,0 +1,11 @@
+//! Htsget ticket server.
+//!
+//! This generates htsget tickets for indexed BAM, CRAM, VCF, and BCF files using the noodles index
+//! readers. It is independent of any HTTP framework: a server maps an htsget request to a
+//! [`Builder`] and responds with the serialized [`Ticket`].
+
+mod builder;
+mod parts;
+
+pub use self::builder::Builder;
+pub use crate::ticket::{BlockUrl, Class, Ticket};
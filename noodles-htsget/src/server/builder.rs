@@ -0,0 +1,791 @@
+use std::{
+    collections::BTreeSet,
+    io::{self, Read, Seek, SeekFrom},
+};
+
+use noodles_bam::{self as bam, bai};
+use noodles_bcf as bcf;
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_cram::{self as cram, crai};
+use noodles_csi::{self as csi, binning_index::merge_chunks, BinningIndex};
+use noodles_sam as sam;
+use noodles_tabix as tabix;
+use noodles_vcf as vcf;
+use url::Url;
+
+use super::parts::Parts;
+use crate::{
+    ticket::{Class, Ticket},
+    Format,
+};
+
+// § 4.1.2 End-of-file marker (2020-12-03)
+static BGZF_EOF: [u8; 28] = [
+    0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43, 0x02, 0x00,
+    0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+
+// § 9 "End of file container" (2022-04-12)
+static CRAM_EOF: [u8; 38] = [
+    0x0f, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x0f, 0xe0, 0x45, 0x4f, 0x46, 0x00, 0x00, 0x00,
+    0x00, 0x01, 0x00, 0x05, 0xbd, 0xd9, 0x4f, 0x00, 0x01, 0x00, 0x06, 0x06, 0x01, 0x00, 0x01, 0x00,
+    0x01, 0x00, 0xee, 0x63, 0x01, 0x4b,
+];
+
+/// An htsget ticket builder.
+///
+/// The builder generates a ticket for a file served at a URL, which must support HTTP range
+/// requests. The ticket consists of a header block, the blocks of data that intersect the
+/// requested regions, and an end-of-file block. Byte ranges are merged, and pieces of data that
+/// cannot be expressed as a byte range of the file are inlined as data URLs.
+///
+/// This does not depend on any HTTP framework. The caller reads the file and its index, builds a
+/// ticket, and serializes it as the response body.
+#[derive(Debug)]
+pub struct Builder {
+    url: Url,
+    class: Option<Class>,
+    regions: Vec<Region>,
+}
+
+impl Builder {
+    /// Creates an htsget ticket builder for a file served at the given URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget::server;
+    /// let builder = server::Builder::new("https://localhost/sample.bam".parse()?);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn new(url: Url) -> Self {
+        Self {
+            url,
+            class: None,
+            regions: Vec::new(),
+        }
+    }
+
+    /// Sets the data stream filter.
+    ///
+    /// When set to [`Class::Header`], the ticket only includes the header. Setting this discards
+    /// all regions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget::server::{self, Class};
+    ///
+    /// let builder = server::Builder::new("https://localhost/sample.bam".parse()?)
+    ///     .set_class(Class::Header);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn set_class(mut self, class: Class) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    /// Adds a region to query.
+    ///
+    /// If no regions are added, the ticket includes all records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_htsget::server;
+    ///
+    /// let builder = server::Builder::new("https://localhost/sample.bam".parse()?)
+    ///     .add_region("sq0:8-13".parse()?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_region(mut self, region: Region) -> Self {
+        self.regions.push(region);
+        self
+    }
+
+    /// Builds a ticket for a BAM file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam::bai;
+    /// use noodles_htsget::server;
+    ///
+    /// let reader = File::open("sample.bam")?;
+    /// let index = bai::read("sample.bam.bai")?;
+    ///
+    /// let ticket = server::Builder::new("https://localhost/sample.bam".parse().unwrap())
+    ///     .add_region("sq0:8-13".parse().unwrap())
+    ///     .build_bam(reader, &index)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_bam<R>(self, reader: R, index: &bai::Index) -> io::Result<Ticket>
+    where
+        R: Read + Seek,
+    {
+        let mut reader = bam::Reader::new(reader);
+        reader.read_header()?;
+        let reference_sequences = reader.read_reference_sequences()?;
+        let header_end = reader.virtual_position();
+
+        self.build_bgzf(
+            Format::Bam,
+            reader.into_inner(),
+            header_end,
+            index,
+            |name| reference_sequences.get_index_of(name),
+        )
+    }
+
+    /// Builds a ticket for a CRAM file.
+    ///
+    /// Only CRAM 3 files are supported. Containers are the smallest unit of data, so the ticket
+    /// may include records outside of the requested regions.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram::crai;
+    /// use noodles_htsget::server;
+    ///
+    /// let reader = File::open("sample.cram")?;
+    /// let index = crai::read("sample.cram.crai")?;
+    ///
+    /// let ticket = server::Builder::new("https://localhost/sample.cram".parse().unwrap())
+    ///     .add_region("sq0:8-13".parse().unwrap())
+    ///     .build_cram(reader, &index)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_cram<R>(self, reader: R, index: &crai::Index) -> io::Result<Ticket>
+    where
+        R: Read + Seek,
+    {
+        let mut reader = cram::Reader::new(reader);
+
+        let file_definition = reader.read_file_definition()?;
+
+        if file_definition.version().major() != 3 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "unsupported CRAM version",
+            ));
+        }
+
+        let header: sam::Header = reader
+            .read_file_header()?
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let header_end = reader.position()?;
+
+        let mut parts = Parts::default();
+        parts.push_range(Class::Header, 0..header_end);
+
+        let eof_class = if self.class == Some(Class::Header) {
+            Class::Header
+        } else if self.regions.is_empty() {
+            let body_end = find_body_end(reader.get_mut(), &CRAM_EOF)?;
+            parts.push_range(Class::Body, header_end..body_end);
+            Class::Body
+        } else {
+            let mut offsets = BTreeSet::new();
+
+            for region in &self.regions {
+                let reference_sequence_id = resolve_region(region, |name| {
+                    header.reference_sequences().get_index_of(name)
+                })?;
+
+                let interval = region.interval();
+
+                for record in index {
+                    if record.reference_sequence_id() != Some(reference_sequence_id) {
+                        continue;
+                    }
+
+                    if let Some(start) = record.alignment_start() {
+                        let span = record.alignment_span().max(1);
+                        let end = start.checked_add(span - 1).unwrap_or(start);
+
+                        if interval.intersects((start..=end).into()) {
+                            offsets.insert(record.offset());
+                        }
+                    }
+                }
+            }
+
+            for offset in offsets {
+                reader.seek(SeekFrom::Start(offset))?;
+                reader.read_data_container()?;
+                let end = reader.position()?;
+                parts.push_range(Class::Body, offset..end);
+            }
+
+            Class::Body
+        };
+
+        parts.push_data(eof_class, CRAM_EOF.to_vec());
+
+        Ok(Ticket::new(Format::Cram, parts.into_block_urls(&self.url)))
+    }
+
+    /// Builds a ticket for a bgzipped VCF file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_htsget::server;
+    /// use noodles_tabix as tabix;
+    ///
+    /// let reader = File::open("sample.vcf.gz")?;
+    /// let index = tabix::read("sample.vcf.gz.tbi")?;
+    ///
+    /// let ticket = server::Builder::new("https://localhost/sample.vcf.gz".parse().unwrap())
+    ///     .add_region("sq0:8-13".parse().unwrap())
+    ///     .build_vcf(reader, &index)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_vcf<R>(self, reader: R, index: &tabix::Index) -> io::Result<Ticket>
+    where
+        R: Read + Seek,
+    {
+        let mut reader = vcf::Reader::new(bgzf::Reader::new(reader));
+        reader.read_header()?;
+        let header_end = reader.virtual_position();
+
+        let reference_sequence_names = index.header().reference_sequence_names();
+
+        self.build_bgzf(
+            Format::Vcf,
+            reader.into_inner(),
+            header_end,
+            index,
+            |name| reference_sequence_names.get_index_of(name),
+        )
+    }
+
+    /// Builds a ticket for a BCF file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_csi as csi;
+    /// use noodles_htsget::server;
+    ///
+    /// let reader = File::open("sample.bcf")?;
+    /// let index = csi::read("sample.bcf.csi")?;
+    ///
+    /// let ticket = server::Builder::new("https://localhost/sample.bcf".parse().unwrap())
+    ///     .add_region("sq0:8-13".parse().unwrap())
+    ///     .build_bcf(reader, &index)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_bcf<R>(self, reader: R, index: &csi::Index) -> io::Result<Ticket>
+    where
+        R: Read + Seek,
+    {
+        let mut reader = bcf::Reader::new(reader);
+        reader.read_file_format()?;
+
+        let string_maps: bcf::header::StringMaps = reader
+            .read_header()?
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let header_end = reader.virtual_position();
+
+        self.build_bgzf(
+            Format::Bcf,
+            reader.into_inner(),
+            header_end,
+            index,
+            |name| string_maps.contigs().get_index_of(name),
+        )
+    }
+
+    fn build_bgzf<R, I, F>(
+        self,
+        format: Format,
+        mut reader: bgzf::Reader<R>,
+        header_end: bgzf::VirtualPosition,
+        index: &I,
+        get_reference_sequence_id: F,
+    ) -> io::Result<Ticket>
+    where
+        R: Read + Seek,
+        I: BinningIndex,
+        F: Fn(&str) -> Option<usize>,
+    {
+        let mut parts = Parts::default();
+
+        parts.push_virtual_range(
+            &mut reader,
+            Class::Header,
+            bgzf::VirtualPosition::default(),
+            header_end,
+        )?;
+
+        let eof_class = if self.class == Some(Class::Header) {
+            Class::Header
+        } else if self.regions.is_empty() {
+            let body_end = find_body_end(reader.get_mut(), &BGZF_EOF)?;
+            let body_end = bgzf::VirtualPosition::try_from((body_end, 0))
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            parts.push_virtual_range(&mut reader, Class::Body, header_end, body_end)?;
+
+            Class::Body
+        } else {
+            let mut chunks = Vec::new();
+
+            for region in &self.regions {
+                let reference_sequence_id = resolve_region(region, &get_reference_sequence_id)?;
+                chunks.extend(index.query(reference_sequence_id, region.interval())?);
+            }
+
+            for chunk in merge_chunks(&chunks) {
+                // The start of a chunk is never expected to be in the header, but this guarantees
+                // the header is not duplicated.
+                let start = chunk.start().max(header_end);
+                parts.push_virtual_range(&mut reader, Class::Body, start, chunk.end())?;
+            }
+
+            Class::Body
+        };
+
+        parts.push_data(eof_class, BGZF_EOF.to_vec());
+
+        Ok(Ticket::new(format, parts.into_block_urls(&self.url)))
+    }
+}
+
+fn resolve_region<F>(region: &Region, get_reference_sequence_id: F) -> io::Result<usize>
+where
+    F: Fn(&str) -> Option<usize>,
+{
+    get_reference_sequence_id(region.name()).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "region reference sequence does not exist in reference sequences: {:?}",
+                region
+            ),
+        )
+    })
+}
+
+// Returns the end position of the data before the end-of-file marker, if any.
+fn find_body_end<R>(reader: &mut R, eof: &[u8]) -> io::Result<u64>
+where
+    R: Read + Seek,
+{
+    let len = reader.seek(SeekFrom::End(0))?;
+    let eof_len = eof.len() as u64;
+
+    if len < eof_len {
+        return Ok(len);
+    }
+
+    reader.seek(SeekFrom::Start(len - eof_len))?;
+
+    let mut buf = vec![0; eof.len()];
+    reader.read_exact(&mut buf)?;
+
+    if buf == eof {
+        Ok(len - eof_len)
+    } else {
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use noodles_core::Position;
+    use noodles_csi::index::{indexer::AlignmentContext, reference_sequence::bin::Chunk};
+    use noodles_fasta as fasta;
+
+    use super::*;
+    use crate::chunks::decode_data_url;
+
+    // Concatenates the data of each block in the ticket, reading byte ranges from `data`.
+    fn resolve(ticket: &Ticket, data: &[u8]) -> Vec<u8> {
+        let mut buf = Vec::new();
+
+        for block_url in ticket.urls() {
+            if block_url.url().scheme() == "data" {
+                buf.extend(decode_data_url(block_url.url()).unwrap());
+            } else {
+                let range = &block_url.headers()["Range"];
+                let (start, end) = range.trim_start_matches("bytes=").split_once('-').unwrap();
+                let (start, end): (usize, usize) = (start.parse().unwrap(), end.parse().unwrap());
+                buf.extend(&data[start..=end]);
+            }
+        }
+
+        buf
+    }
+
+    fn url() -> Url {
+        "https://localhost/sample".parse().unwrap()
+    }
+
+    fn build_bam() -> Result<(Vec<u8>, bai::Index), Box<dyn std::error::Error>> {
+        use sam::{
+            header::ReferenceSequence,
+            record::{Cigar, Flags},
+        };
+
+        let header = sam::Header::builder()
+            .set_header(Default::default())
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 1 << 20)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 1 << 20)?)
+            .build();
+
+        let cigar: Cigar = "4M".parse()?;
+
+        // The header shares a block with r0, and r1 and r2 are each written to their own blocks.
+        let records = [("r0", 0, 8), ("r1", 0, (1 << 17) + 8), ("r2", 1, 8)]
+            .into_iter()
+            .map(|(name, reference_sequence_id, start)| {
+                Ok(sam::alignment::Record::builder()
+                    .set_read_name(name.parse()?)
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(reference_sequence_id)
+                    .set_alignment_start(Position::try_from(start)?)
+                    .set_cigar(cigar.clone())
+                    .build())
+            })
+            .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+
+        let mut writer = bam::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        let mut builder = bai::Index::builder();
+
+        for record in &records {
+            let start = writer.get_ref().virtual_position();
+            writer.write_record(&header, record)?;
+            let end = writer.get_ref().virtual_position();
+
+            builder.add_record(record, Chunk::new(start, end))?;
+
+            writer.get_mut().flush()?;
+        }
+
+        let data = writer.into_inner().finish()?;
+        let index = builder.build(header.reference_sequences().len());
+
+        Ok((data, index))
+    }
+
+    fn read_bam_read_names(data: &[u8]) -> io::Result<Vec<String>> {
+        let mut reader = bam::Reader::new(data);
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        reader
+            .records()
+            .map(|result| {
+                result.map(|record| {
+                    record
+                        .read_name()
+                        .map(|name| name.to_string())
+                        .unwrap_or_default()
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_build_bam() -> Result<(), Box<dyn std::error::Error>> {
+        let (data, index) = build_bam()?;
+
+        let ticket = Builder::new(url()).build_bam(Cursor::new(&data), &index)?;
+        assert_eq!(ticket.format(), Format::Bam);
+        assert_eq!(
+            read_bam_read_names(&resolve(&ticket, &data))?,
+            ["r0", "r1", "r2"]
+        );
+
+        let ticket = Builder::new(url())
+            .add_region("sq1".parse()?)
+            .add_region("sq0:1-13".parse()?)
+            .build_bam(Cursor::new(&data), &index)?;
+
+        assert_eq!(ticket.urls()[0].class(), Some(Class::Header));
+        assert_eq!(read_bam_read_names(&resolve(&ticket, &data))?, ["r0", "r2"]);
+
+        let ticket = Builder::new(url())
+            .add_region("sq0:131080-131083".parse()?)
+            .build_bam(Cursor::new(&data), &index)?;
+
+        assert_eq!(read_bam_read_names(&resolve(&ticket, &data))?, ["r1"]);
+
+        let ticket = Builder::new(url())
+            .set_class(Class::Header)
+            .add_region("sq1".parse()?)
+            .build_bam(Cursor::new(&data), &index)?;
+
+        assert!(ticket
+            .urls()
+            .iter()
+            .all(|block_url| block_url.class() == Some(Class::Header)));
+        assert!(read_bam_read_names(&resolve(&ticket, &data))?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_bam_with_missing_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        let (data, index) = build_bam()?;
+
+        let result = Builder::new(url())
+            .add_region("sq2".parse()?)
+            .build_bam(Cursor::new(&data), &index);
+
+        assert!(matches!(
+            result,
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_cram() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::header::{reference_sequence::Md5Checksum, ReferenceSequence};
+
+        let header = sam::Header::builder()
+            .set_header(Default::default())
+            .add_reference_sequence(
+                ReferenceSequence::builder()
+                    .set_name("sq0".parse()?)
+                    .set_length(8)
+                    .set_md5_checksum(Md5Checksum::from([0; 16]))
+                    .build()?,
+            )
+            .build();
+
+        let mut writer = cram::Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for name in ["r0", "r1"] {
+            let record = sam::alignment::Record::builder()
+                .set_read_name(name.parse()?)
+                .build();
+            let cram_record = cram::Record::try_from_alignment_record(&header, &record)?;
+            writer.write_record(&header, cram_record)?;
+        }
+
+        writer.try_finish(&header)?;
+        let data = writer.get_ref().clone();
+
+        let mut reader = cram::Reader::new(Cursor::new(&data));
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+        let container_offset = reader.position()?;
+
+        // The records are unmapped, but the index places the container on sq0.
+        let index = vec![crai::Record::new(
+            Some(0),
+            Position::new(1),
+            8,
+            container_offset,
+            0,
+            0,
+        )];
+
+        let read_names = |ticket: &Ticket| -> io::Result<Vec<String>> {
+            let buf = resolve(ticket, &data);
+            let mut reader = cram::Reader::new(&buf[..]);
+            reader.read_file_definition()?;
+            reader.read_file_header()?;
+
+            let repository = fasta::Repository::default();
+
+            reader
+                .records(&repository, &header)
+                .map(|result| {
+                    result.map(|record| {
+                        record
+                            .read_name()
+                            .map(|name| name.to_string())
+                            .unwrap_or_default()
+                    })
+                })
+                .collect()
+        };
+
+        let ticket = Builder::new(url()).build_cram(Cursor::new(&data), &index)?;
+        assert_eq!(ticket.format(), Format::Cram);
+        assert_eq!(resolve(&ticket, &data), data);
+
+        let ticket = Builder::new(url())
+            .add_region("sq0:5-8".parse()?)
+            .build_cram(Cursor::new(&data), &index)?;
+        assert_eq!(read_names(&ticket)?, ["r0", "r1"]);
+
+        let ticket = Builder::new(url())
+            .set_class(Class::Header)
+            .build_cram(Cursor::new(&data), &index)?;
+        assert!(read_names(&ticket)?.is_empty());
+
+        Ok(())
+    }
+
+    fn build_vcf_header() -> Result<vcf::Header, Box<dyn std::error::Error>> {
+        use vcf::header::record::value::{map::Contig, Map};
+
+        Ok(vcf::Header::builder()
+            .add_contig(Map::<Contig>::new("sq0".parse()?))
+            .add_contig(Map::<Contig>::new("sq1".parse()?))
+            .build())
+    }
+
+    fn build_vcf_records() -> Result<Vec<vcf::Record>, Box<dyn std::error::Error>> {
+        [("sq0", 1), ("sq0", 1 << 17), ("sq1", 1)]
+            .into_iter()
+            .map(|(chromosome, position)| {
+                vcf::Record::builder()
+                    .set_chromosome(chromosome.parse()?)
+                    .set_position(vcf::record::Position::from(position))
+                    .set_reference_bases("A".parse()?)
+                    .build()
+                    .map_err(|e| e.into())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_build_vcf() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_vcf_header()?;
+        let records = build_vcf_records()?;
+
+        let mut writer = vcf::Writer::new(bgzf::Writer::new(Vec::new()));
+        writer.write_header(&header)?;
+
+        let mut indexer = tabix::Index::indexer();
+
+        for record in &records {
+            let start = writer.get_ref().virtual_position();
+            writer.write_record(record)?;
+            let end = writer.get_ref().virtual_position();
+
+            let position = Position::try_from(usize::from(record.position()))?;
+            indexer.add_record(
+                &record.chromosome().to_string(),
+                position,
+                position,
+                Chunk::new(start, end),
+            );
+        }
+
+        let data = writer.into_inner().finish()?;
+        let index = indexer.build();
+
+        let read_records = |ticket: &Ticket| -> io::Result<Vec<vcf::Record>> {
+            let buf = resolve(ticket, &data);
+            let mut reader = vcf::Reader::new(bgzf::Reader::new(&buf[..]));
+            reader.read_header()?;
+            reader.records(&header).collect()
+        };
+
+        let ticket = Builder::new(url()).build_vcf(Cursor::new(&data), &index)?;
+        assert_eq!(ticket.format(), Format::Vcf);
+        assert_eq!(read_records(&ticket)?, records);
+
+        let ticket = Builder::new(url())
+            .add_region("sq0:131072".parse()?)
+            .add_region("sq1".parse()?)
+            .build_vcf(Cursor::new(&data), &index)?;
+        assert_eq!(read_records(&ticket)?, records[1..]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_bcf() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_vcf_header()?;
+        let records = build_vcf_records()?;
+        let string_maps = bcf::header::StringMaps::from(&header);
+
+        let mut writer = bcf::Writer::new(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        let mut indexer = csi::Index::indexer();
+
+        for record in &records {
+            let start = writer.get_ref().virtual_position();
+            writer.write_vcf_record(&header, &string_maps, record)?;
+            let end = writer.get_ref().virtual_position();
+
+            let chromosome_id = header
+                .contigs()
+                .get_index_of(record.chromosome().to_string().as_str())
+                .unwrap();
+            let position = Position::try_from(usize::from(record.position()))?;
+
+            indexer.add_record(
+                Some(AlignmentContext::new(
+                    chromosome_id,
+                    position,
+                    position,
+                    true,
+                )),
+                Chunk::new(start, end),
+            )?;
+
+            writer.get_mut().flush()?;
+        }
+
+        let data = writer.into_inner().finish()?;
+        let index = indexer.build(header.contigs().len());
+
+        // Returns the (chromosome ID, position) of each record.
+        let read_records = |ticket: &Ticket| -> io::Result<Vec<(usize, usize)>> {
+            let buf = resolve(ticket, &data);
+            let mut reader = bcf::Reader::new(&buf[..]);
+            reader.read_file_format()?;
+            reader.read_header()?;
+            reader
+                .records()
+                .map(|result| {
+                    result.map(|record| (record.chromosome_id(), usize::from(record.position())))
+                })
+                .collect()
+        };
+
+        let expected = [(0, 1), (0, 1 << 17), (1, 1)];
+
+        let ticket = Builder::new(url()).build_bcf(Cursor::new(&data), &index)?;
+        assert_eq!(ticket.format(), Format::Bcf);
+        assert_eq!(read_records(&ticket)?, expected);
+
+        let ticket = Builder::new(url())
+            .add_region("sq0:131072".parse()?)
+            .add_region("sq1".parse()?)
+            .build_bcf(Cursor::new(&data), &index)?;
+        assert_eq!(read_records(&ticket)?, expected[1..]);
+
+        let ticket = Builder::new(url())
+            .add_region("sq0:1-8".parse()?)
+            .build_bcf(Cursor::new(&data), &index)?;
+        assert_eq!(read_records(&ticket)?, expected[..1]);
+
+        let ticket = Builder::new(url())
+            .set_class(Class::Header)
+            .build_bcf(Cursor::new(&data), &index)?;
+        assert!(read_records(&ticket)?.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    io::{self, BufRead, Read, Seek, Write},
+    ops::Range,
+};
+
+use noodles_bgzf as bgzf;
+use url::Url;
+
+use crate::ticket::{BlockUrl, Class};
+
+enum Part {
+    Range(Range<u64>),
+    Data(Vec<u8>),
+}
+
+/// An ordered list of byte ranges and inline data that make up a ticket.
+#[derive(Default)]
+pub(super) struct Parts {
+    parts: Vec<(Class, Part)>,
+}
+
+impl Parts {
+    /// Adds a byte range of the file.
+    ///
+    /// The range is merged with the previous one if they are contiguous and of the same class.
+    pub fn push_range(&mut self, class: Class, range: Range<u64>) {
+        if range.is_empty() {
+            return;
+        }
+
+        if let Some((last_class, Part::Range(last_range))) = self.parts.last_mut() {
+            if *last_class == class && last_range.end == range.start {
+                last_range.end = range.end;
+                return;
+            }
+        }
+
+        self.parts.push((class, Part::Range(range)));
+    }
+
+    /// Adds inline data.
+    pub fn push_data(&mut self, class: Class, data: Vec<u8>) {
+        if !data.is_empty() {
+            self.parts.push((class, Part::Data(data)));
+        }
+    }
+
+    /// Adds the data between two virtual positions of a BGZF-compressed file.
+    ///
+    /// Whole blocks are added as byte ranges. When the start or end is inside a block, the
+    /// partial data of that block is recompressed and added as inline data. This keeps the
+    /// resulting stream exact, i.e., it never includes data outside of `[start, end)`.
+    pub fn push_virtual_range<R>(
+        &mut self,
+        reader: &mut bgzf::Reader<R>,
+        class: Class,
+        start: bgzf::VirtualPosition,
+        end: bgzf::VirtualPosition,
+    ) -> io::Result<()>
+    where
+        R: Read + Seek,
+    {
+        let mut pos = start;
+
+        while pos < end {
+            let (cpos, upos) = (pos.compressed(), pos.uncompressed());
+
+            if upos == 0 && cpos < end.compressed() {
+                self.push_range(class, cpos..end.compressed());
+                pos = block_start(end.compressed())?;
+                continue;
+            }
+
+            reader.seek(pos)?;
+            let next_cpos = reader.position();
+
+            if reader.fill_buf()?.is_empty() {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+            }
+
+            // The position was at the end of a block, and the next block was read instead.
+            if reader.position() != next_cpos {
+                pos = block_start(next_cpos)?;
+                continue;
+            }
+
+            let mut buf = reader.fill_buf()?;
+
+            if cpos == end.compressed() {
+                let len = usize::from(end.uncompressed() - upos);
+
+                buf = buf.get(..len).ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "invalid virtual position")
+                })?;
+
+                pos = end;
+            } else {
+                pos = block_start(next_cpos)?;
+            }
+
+            let data = compress(buf)?;
+            self.push_data(class, data);
+        }
+
+        Ok(())
+    }
+
+    /// Converts the parts to block URLs, where byte ranges refer to the given URL.
+    pub fn into_block_urls(self, url: &Url) -> Vec<BlockUrl> {
+        self.parts
+            .into_iter()
+            .map(|(class, part)| match part {
+                Part::Range(range) => BlockUrl::from_range(url, range, class),
+                Part::Data(data) => BlockUrl::from_data(&data, class),
+            })
+            .collect()
+    }
+}
+
+fn block_start(cpos: u64) -> io::Result<bgzf::VirtualPosition> {
+    bgzf::VirtualPosition::try_from((cpos, 0))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn compress(buf: &[u8]) -> io::Result<Vec<u8>> {
+    let mut writer = bgzf::Writer::new(Vec::new());
+    writer.write_all(buf)?;
+    writer.flush()?;
+    Ok(writer.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn build_urls(parts: Parts) -> Vec<BlockUrl> {
+        parts.into_block_urls(&"https://localhost/sample.gz".parse().unwrap())
+    }
+
+    #[test]
+    fn test_push_range() {
+        let mut parts = Parts::default();
+        parts.push_range(Class::Header, 0..8);
+        parts.push_range(Class::Body, 8..13);
+        parts.push_range(Class::Body, 13..21);
+        parts.push_range(Class::Body, 21..21);
+        parts.push_range(Class::Body, 34..55);
+
+        let actual: Vec<_> = build_urls(parts)
+            .iter()
+            .map(|block_url| block_url.headers()["Range"].clone())
+            .collect();
+
+        assert_eq!(actual, ["bytes=0-7", "bytes=8-20", "bytes=34-54"]);
+    }
+
+    #[test]
+    fn test_push_virtual_range() -> io::Result<()> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let block_1_start = writer.position();
+        writer.write_all(b"-bgzf")?;
+        writer.flush()?;
+        let block_2_start = writer.position();
+        writer.write_all(b"-htsget")?;
+        let data = writer.finish()?;
+
+        let mut reader = bgzf::Reader::new(Cursor::new(data));
+
+        let mut parts = Parts::default();
+
+        parts.push_virtual_range(
+            &mut reader,
+            Class::Body,
+            bgzf::VirtualPosition::try_from((0, 3)).unwrap(),
+            bgzf::VirtualPosition::try_from((block_2_start, 4)).unwrap(),
+        )?;
+
+        let block_urls = build_urls(parts);
+        assert_eq!(block_urls.len(), 3);
+
+        assert_eq!(
+            block_urls[1].headers()["Range"],
+            format!("bytes={}-{}", block_1_start, block_2_start - 1)
+        );
+
+        let mut buf = Vec::new();
+
+        for block_url in [&block_urls[0], &block_urls[2]] {
+            let data = crate::chunks::decode_data_url(block_url.url()).unwrap();
+            bgzf::Reader::new(&data[..]).read_to_end(&mut buf)?;
+        }
+
+        assert_eq!(buf, b"dles-hts");
+
+        Ok(())
+    }
+}
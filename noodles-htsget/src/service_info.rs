@@ -0,0 +1,210 @@
+//! Htsget service info.
+
+use serde::Deserialize;
+use url::Url;
+
+use crate::Format;
+
+/// An htsget service info response.
+///
+/// This is a GA4GH service info response with htsget-specific capabilities.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceInfo {
+    id: String,
+    name: String,
+    #[serde(rename = "type")]
+    ty: Type,
+    description: Option<String>,
+    organization: Organization,
+    contact_url: Option<String>,
+    documentation_url: Option<Url>,
+    version: String,
+    htsget: Option<Htsget>,
+}
+
+impl ServiceInfo {
+    /// Returns the service ID.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the service name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the service type.
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+
+    /// Returns the service description.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the organization providing the service.
+    pub fn organization(&self) -> &Organization {
+        &self.organization
+    }
+
+    /// Returns the contact URL.
+    pub fn contact_url(&self) -> Option<&str> {
+        self.contact_url.as_deref()
+    }
+
+    /// Returns the documentation URL.
+    pub fn documentation_url(&self) -> Option<&Url> {
+        self.documentation_url.as_ref()
+    }
+
+    /// Returns the service version.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns the htsget capabilities of the service.
+    pub fn htsget(&self) -> Option<&Htsget> {
+        self.htsget.as_ref()
+    }
+}
+
+/// A GA4GH service type.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Type {
+    group: String,
+    artifact: String,
+    version: String,
+}
+
+impl Type {
+    /// Returns the namespace of the service type, e.g., `org.ga4gh`.
+    pub fn group(&self) -> &str {
+        &self.group
+    }
+
+    /// Returns the name of the API specification, e.g., `htsget`.
+    pub fn artifact(&self) -> &str {
+        &self.artifact
+    }
+
+    /// Returns the version of the API specification.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+}
+
+/// An organization providing a service.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Organization {
+    name: String,
+    url: Url,
+}
+
+impl Organization {
+    /// Returns the organization name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the organization URL.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+}
+
+/// A data type served by an htsget endpoint.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DataType {
+    /// Reads (BAM/CRAM).
+    Reads,
+    /// Variants (VCF/BCF).
+    Variants,
+}
+
+/// The htsget capabilities of a service.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Htsget {
+    datatype: Option<DataType>,
+    #[serde(default)]
+    formats: Vec<Format>,
+    #[serde(default)]
+    fields_parameters_effective: bool,
+    #[serde(default)]
+    tags_parameters_effective: bool,
+    max_regions: Option<usize>,
+}
+
+impl Htsget {
+    /// Returns the data type served by the endpoint.
+    pub fn datatype(&self) -> Option<DataType> {
+        self.datatype
+    }
+
+    /// Returns the supported data formats.
+    ///
+    /// If empty, the supported formats are not advertised.
+    pub fn formats(&self) -> &[Format] {
+        &self.formats
+    }
+
+    /// Returns whether the server filters data by the `fields` parameter.
+    pub fn fields_parameters_effective(&self) -> bool {
+        self.fields_parameters_effective
+    }
+
+    /// Returns whether the server filters data by the `tags` and `notags` parameters.
+    pub fn tags_parameters_effective(&self) -> bool {
+        self.tags_parameters_effective
+    }
+
+    /// Returns the max number of regions allowed in a single request.
+    ///
+    /// This is read from the nonstandard `maxRegions` property, which some servers use to
+    /// advertise a limit.
+    pub fn max_regions(&self) -> Option<usize> {
+        self.max_regions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() -> Result<(), Box<dyn std::error::Error>> {
+        let data = r#"{
+            "id": "org.example.htsget",
+            "name": "noodles htsget",
+            "type": { "group": "org.ga4gh", "artifact": "htsget", "version": "1.3.0" },
+            "organization": { "name": "noodles", "url": "https://example.com/" },
+            "version": "0.1.0",
+            "htsget": {
+                "datatype": "reads",
+                "formats": ["BAM", "CRAM"],
+                "fieldsParametersEffective": true,
+                "maxRegions": 8
+            }
+        }"#;
+
+        let service_info: ServiceInfo = serde_json::from_str(data)?;
+
+        assert_eq!(service_info.id(), "org.example.htsget");
+        assert_eq!(service_info.ty().artifact(), "htsget");
+        assert_eq!(service_info.ty().version(), "1.3.0");
+        assert_eq!(service_info.organization().name(), "noodles");
+        assert!(service_info.description().is_none());
+
+        let htsget = service_info.htsget().expect("missing htsget");
+        assert_eq!(htsget.datatype(), Some(DataType::Reads));
+        assert_eq!(htsget.formats(), [Format::Bam, Format::Cram]);
+        assert!(htsget.fields_parameters_effective());
+        assert!(!htsget.tags_parameters_effective());
+        assert_eq!(htsget.max_regions(), Some(8));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+//! Htsget ticket.
+
+use std::collections::HashMap;
+#[cfg(feature = "server")]
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::Format;
+
+/// The class of data a block URL refers to.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Class {
+    /// The header of the file.
+    Header,
+    /// Records (and the end-of-file marker) of the file.
+    Body,
+}
+
+/// A block URL in a ticket.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct BlockUrl {
+    url: Url,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    headers: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    class: Option<Class>,
+}
+
+impl BlockUrl {
+    #[cfg(feature = "server")]
+    pub(crate) fn from_range(url: &Url, range: Range<u64>, class: Class) -> Self {
+        // HTTP byte ranges are inclusive.
+        let value = format!("bytes={}-{}", range.start, range.end - 1);
+
+        Self {
+            url: url.clone(),
+            headers: [(String::from("Range"), value)].into_iter().collect(),
+            class: Some(class),
+        }
+    }
+
+    #[cfg(feature = "server")]
+    pub(crate) fn from_data(data: &[u8], class: Class) -> Self {
+        let url = format!("data:;base64,{}", base64::encode(data));
+
+        Self {
+            // A base64-encoded data URL is always a valid URL.
+            url: url.parse().unwrap(),
+            headers: HashMap::new(),
+            class: Some(class),
+        }
+    }
+
+    /// Returns the URL.
+    ///
+    /// This is either the URL of the file or an inline data URL.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the headers to send with the request, e.g., `Range`.
+    pub fn headers(&self) -> &HashMap<String, String> {
+        &self.headers
+    }
+
+    /// Returns the class of data the block refers to, if set.
+    pub fn class(&self) -> Option<Class> {
+        self.class
+    }
+}
+
+/// An htsget ticket.
+///
+/// This (de)serializes as a full htsget response body, i.e., the ticket is wrapped in an `htsget`
+/// object.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(from = "Envelope", into = "Envelope")]
+pub struct Ticket {
+    format: Format,
+    urls: Vec<BlockUrl>,
+    md5: Option<String>,
+}
+
+impl Ticket {
+    #[cfg(feature = "server")]
+    pub(crate) fn new(format: Format, urls: Vec<BlockUrl>) -> Self {
+        Self {
+            format,
+            urls,
+            md5: None,
+        }
+    }
+
+    /// Returns the data format.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Returns the block URLs.
+    ///
+    /// Concatenating the data of each block, in order, results in a well-formed file.
+    pub fn urls(&self) -> &[BlockUrl] {
+        &self.urls
+    }
+
+    /// Returns the MD5 checksum of the concatenated data, if set.
+    pub fn md5(&self) -> Option<&str> {
+        self.md5.as_deref()
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct Envelope {
+    htsget: Fields,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Fields {
+    format: Format,
+    urls: Vec<BlockUrl>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    md5: Option<String>,
+}
+
+impl From<Envelope> for Ticket {
+    fn from(envelope: Envelope) -> Self {
+        let Fields { format, urls, md5 } = envelope.htsget;
+        Self { format, urls, md5 }
+    }
+}
+
+impl From<Ticket> for Envelope {
+    fn from(ticket: Ticket) -> Self {
+        Self {
+            htsget: Fields {
+                format: ticket.format,
+                urls: ticket.urls,
+                md5: ticket.md5,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() -> Result<(), Box<dyn std::error::Error>> {
+        let actual: Ticket = serde_json::from_str(
+            r#"{
+                "htsget": {
+                    "format": "BAM",
+                    "urls": [
+                        {
+                            "url": "https://localhost/sample.bam",
+                            "headers": { "Range": "bytes=0-7" },
+                            "class": "header"
+                        },
+                        { "url": "data:;base64,bmRscw==" }
+                    ],
+                    "md5": "d41d8cd98f00b204e9800998ecf8427e"
+                }
+            }"#,
+        )?;
+
+        assert_eq!(actual.format(), Format::Bam);
+        assert_eq!(actual.md5(), Some("d41d8cd98f00b204e9800998ecf8427e"));
+
+        let urls = actual.urls();
+        assert_eq!(urls.len(), 2);
+        assert_eq!(urls[0].headers()["Range"], "bytes=0-7");
+        assert_eq!(urls[0].class(), Some(Class::Header));
+        assert!(urls[1].headers().is_empty());
+        assert!(urls[1].class().is_none());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn test_serialize() -> Result<(), Box<dyn std::error::Error>> {
+        let url = "https://localhost/sample.bam".parse()?;
+
+        let ticket = Ticket::new(
+            Format::Bam,
+            vec![
+                BlockUrl::from_range(&url, 0..8, Class::Header),
+                BlockUrl::from_data(b"ndls", Class::Body),
+            ],
+        );
+
+        let actual = serde_json::to_value(&ticket)?;
+
+        let expected = serde_json::json!({
+            "htsget": {
+                "format": "BAM",
+                "urls": [
+                    {
+                        "url": "https://localhost/sample.bam",
+                        "headers": { "Range": "bytes=0-7" },
+                        "class": "header",
+                    },
+                    {
+                        "url": "data:;base64,bmRscw==",
+                        "class": "body",
+                    },
+                ],
+            },
+        });
+
+        assert_eq!(actual, expected);
+
+        let ticket_from_json: Ticket = serde_json::from_value(actual)?;
+        assert_eq!(ticket_from_json, ticket);
+
+        Ok(())
+    }
+}
@@ -1,9 +1,9 @@
 use noodles_core::Region;
 
-use super::Format;
+use super::{Field, Format};
 use crate::{
     request::{self, Class},
-    Response,
+    Method, Response,
 };
 
 /// A variants endpoint builder.
@@ -16,6 +16,15 @@ impl Builder {
         Self { inner }
     }
 
+    /// Sets the HTTP request method.
+    ///
+    /// By default, requests are sent using `POST`. A `GET` request can only query at most one
+    /// region; sending one with more regions fails with [`crate::Error::Input`].
+    pub fn set_method(mut self, method: Method) -> Self {
+        self.inner = self.inner.set_method(method);
+        self
+    }
+
     /// Sets the data format.
     pub fn set_format(mut self, format: Format) -> Self {
         self.inner = self.inner.set_format(format.into());
@@ -36,6 +45,17 @@ impl Builder {
         self
     }
 
+    /// Sets the fields to include.
+    ///
+    /// By default, all fields are included.
+    pub fn set_fields<I>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = Field>,
+    {
+        self.inner = self.inner.set_fields(fields);
+        self
+    }
+
     /// Sends the request.
     pub async fn send(self) -> crate::Result<Response> {
         self.inner.send().await
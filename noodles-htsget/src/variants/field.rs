@@ -0,0 +1,58 @@
+/// A variants field.
+///
+/// These are the VCF fields that can be requested using the `fields` parameter.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Field {
+    /// Chromosome (`CHROM`).
+    Chromosome,
+    /// Position (`POS`).
+    Position,
+    /// IDs (`ID`).
+    Ids,
+    /// Reference bases (`REF`).
+    ReferenceBases,
+    /// Alternate bases (`ALT`).
+    AlternateBases,
+    /// Quality score (`QUAL`).
+    QualityScore,
+    /// Filters (`FILTER`).
+    Filters,
+    /// Information (`INFO`).
+    Info,
+    /// Genotypes (`FORMAT` and samples).
+    Genotypes,
+}
+
+impl AsRef<str> for Field {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Chromosome => "CHROM",
+            Self::Position => "POS",
+            Self::Ids => "ID",
+            Self::ReferenceBases => "REF",
+            Self::AlternateBases => "ALT",
+            Self::QualityScore => "QUAL",
+            Self::Filters => "FILTER",
+            Self::Info => "INFO",
+            Self::Genotypes => "FORMAT",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_ref_str() {
+        assert_eq!(Field::Chromosome.as_ref(), "CHROM");
+        assert_eq!(Field::Position.as_ref(), "POS");
+        assert_eq!(Field::Ids.as_ref(), "ID");
+        assert_eq!(Field::ReferenceBases.as_ref(), "REF");
+        assert_eq!(Field::AlternateBases.as_ref(), "ALT");
+        assert_eq!(Field::QualityScore.as_ref(), "QUAL");
+        assert_eq!(Field::Filters.as_ref(), "FILTER");
+        assert_eq!(Field::Info.as_ref(), "INFO");
+        assert_eq!(Field::Genotypes.as_ref(), "FORMAT");
+    }
+}
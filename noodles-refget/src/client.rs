@@ -1,6 +1,13 @@
+use serde::de::DeserializeOwned;
 use url::Url;
 
-use super::sequence;
+use super::{
+    collection::{Collection, Comparison},
+    sequence::{self, metadata::MetadataResponse},
+    sequences,
+    service_info::ServiceInfoResponse,
+    Error, Metadata, ServiceInfo,
+};
 
 /// A refget client.
 #[derive(Clone, Debug)]
@@ -50,4 +57,298 @@ impl Client {
     {
         sequence::Builder::new(self.clone(), id)
     }
+
+    /// Creates a batched sequence request for the given IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let sequences_builder = client.sequences([
+    ///     "cc0af3a4fedb18378b4b57b98068e69f",
+    ///     "d7eba311421bbc9d3ada44709dd61534",
+    /// ]);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn sequences<I, J>(&self, ids: I) -> sequences::Builder
+    where
+        I: IntoIterator<Item = J>,
+        J: Into<String>,
+    {
+        sequences::Builder::new(self.clone(), ids.into_iter().map(|id| id.into()).collect())
+    }
+
+    /// Returns the metadata of a sequence.
+    ///
+    /// The ID can be a checksum or a namespaced alias, e.g., `insdc:NC_000001.11`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let metadata = client.metadata("insdc:NC_000001.11").await?;
+    /// println!("{}", metadata.md5());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn metadata(&self, id: &str) -> crate::Result<Metadata> {
+        let endpoint = self.join(&format!("sequence/{}/metadata", id))?;
+
+        self.get_json(endpoint)
+            .await
+            .map(|response: MetadataResponse| response.metadata)
+    }
+
+    /// Returns the refget service info.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let service_info = client.service_info().await?;
+    /// println!("{:?}", service_info.subsequence_limit());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn service_info(&self) -> crate::Result<ServiceInfo> {
+        let endpoint = self.join("sequence/service-info")?;
+
+        self.get_json(endpoint)
+            .await
+            .map(|response: ServiceInfoResponse| response.into())
+    }
+
+    /// Returns the sequence collection with the given digest.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let collection = client.collection("3mTg0tAA3PS-R1TzelLVWJ2ilUzoWfVq").await?;
+    /// println!("{:?}", collection.names());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn collection(&self, digest: &str) -> crate::Result<Collection> {
+        let mut endpoint = self.join(&format!("collection/{}", digest))?;
+        endpoint.query_pairs_mut().append_pair("level", "2");
+        self.get_json(endpoint).await
+    }
+
+    /// Compares two sequence collections on the server.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    ///
+    /// let comparison = client
+    ///     .compare(
+    ///         "3mTg0tAA3PS-R1TzelLVWJ2ilUzoWfVq",
+    ///         "XZlrcEGi6mlopZ2uD8ObHkQB1d0oDwKk",
+    ///     )
+    ///     .await?;
+    ///
+    /// println!("{}", comparison.is_identical("names"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn compare(&self, a: &str, b: &str) -> crate::Result<Comparison> {
+        let endpoint = self.join(&format!("comparison/{}/{}", a, b))?;
+        self.get_json(endpoint).await
+    }
+
+    /// Compares a sequence collection on the server (`a`) to a local collection (`b`).
+    ///
+    /// This can be used to validate that a SAM header describes a known assembly.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use noodles_refget::{self as refget, collection::Collection};
+    ///
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let collection = Collection::new(vec![String::from("sq0")], vec![8]);
+    ///
+    /// let comparison = client
+    ///     .compare_with("3mTg0tAA3PS-R1TzelLVWJ2ilUzoWfVq", &collection)
+    ///     .await?;
+    ///
+    /// let is_valid = comparison.is_identical("names") && comparison.is_identical("lengths");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn compare_with(&self, a: &str, b: &Collection) -> crate::Result<Comparison> {
+        let endpoint = self.join(&format!("comparison/{}", a))?;
+
+        let response = self
+            .http_client
+            .post(endpoint)
+            .json(b)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(Error::Request)?;
+
+        response.json().await.map_err(Error::Request)
+    }
+
+    fn join(&self, path: &str) -> crate::Result<Url> {
+        self.base_url.join(path).map_err(Error::Url)
+    }
+
+    async fn get_json<T>(&self, endpoint: Url) -> crate::Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self
+            .http_client
+            .get(endpoint)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(Error::Request)?;
+
+        response.json().await.map_err(Error::Request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock_server::{MockServer, Response};
+
+    use super::*;
+
+    const SQ0_MD5: &str = "cc0af3a4fedb18378b4b57b98068e69f";
+    const SQ1_MD5: &str = "d7eba311421bbc9d3ada44709dd61534";
+
+    const COMPARISON: &str = r#"{
+        "digests": { "a": "ndls", "b": null },
+        "attributes": { "a_only": [], "b_only": [], "a_and_b": ["lengths", "names"] },
+        "array_elements": {
+            "a_count": { "lengths": 1, "names": 1 },
+            "b_count": { "lengths": 1, "names": 1 },
+            "a_and_b_count": { "lengths": 1, "names": 1 },
+            "a_and_b_same_order": { "lengths": null, "names": null }
+        }
+    }"#;
+
+    // Starts a mock refget and seqcol server.
+    fn start_server() -> std::io::Result<(Client, MockServer)> {
+        let server = MockServer::start(|request| {
+            let body: String = match (request.method(), request.target()) {
+                ("GET", "/sequence/service-info") => {
+                    r#"{"refget":{"circular_supported":false,"subsequence_limit":8}}"#.into()
+                }
+                ("GET", "/sequence/insdc:sq0/metadata") => format!(
+                    r#"{{"metadata":{{"md5":"{}","length":8,"aliases":[{{"alias":"sq0","naming_authority":"insdc"}}]}}}}"#,
+                    SQ0_MD5
+                ),
+                ("GET", path) if path == format!("/sequence/{}", SQ0_MD5) => "ACGTACGT".into(),
+                ("GET", path) if path == format!("/sequence/{}", SQ1_MD5) => "NNNN".into(),
+                ("GET", "/collection/ndls?level=2") => r#"{"names":["sq0"],"lengths":[8]}"#.into(),
+                ("GET", "/comparison/ndls/ndls") | ("POST", "/comparison/ndls") => {
+                    COMPARISON.into()
+                }
+                _ => return Response::not_found(),
+            };
+
+            Response::ok(body)
+        })?;
+
+        let base_url = server.base_url().parse().unwrap();
+
+        Ok((Client::new(base_url), server))
+    }
+
+    #[tokio::test]
+    async fn test_service_info() -> Result<(), Box<dyn std::error::Error>> {
+        let (client, _) = start_server()?;
+
+        let service_info = client.service_info().await?;
+        assert!(!service_info.circular_supported());
+        assert_eq!(service_info.subsequence_limit(), Some(8));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_metadata() -> Result<(), Box<dyn std::error::Error>> {
+        let (client, _) = start_server()?;
+
+        let metadata = client.metadata("insdc:sq0").await?;
+        assert_eq!(metadata.md5(), SQ0_MD5);
+        assert_eq!(metadata.length(), 8);
+        assert_eq!(metadata.aliases()[0].naming_authority(), "insdc");
+
+        assert!(matches!(
+            client.metadata("insdc:sq1").await,
+            Err(Error::Request(_))
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sequences() -> Result<(), Box<dyn std::error::Error>> {
+        let (client, server) = start_server()?;
+
+        let sequences = client
+            .sequences([SQ1_MD5, SQ0_MD5])
+            .set_concurrency(2)
+            .send()
+            .await?;
+
+        let actual: Vec<_> = sequences.iter().map(|s| s.sequence()).collect();
+        assert_eq!(actual, [&b"NNNN"[..], &b"ACGTACGT"[..]]);
+        assert_eq!(server.requests().len(), 2);
+
+        assert!(client.sequences([SQ0_MD5, "ndls"]).send().await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_collection() -> Result<(), Box<dyn std::error::Error>> {
+        let (client, server) = start_server()?;
+
+        let collection = client.collection("ndls").await?;
+        assert_eq!(
+            collection,
+            Collection::new(vec![String::from("sq0")], vec![8])
+        );
+
+        let comparison = client.compare("ndls", "ndls").await?;
+        assert!(comparison.is_identical("names"));
+
+        let comparison = client.compare_with("ndls", &collection).await?;
+        assert!(comparison.is_identical("lengths"));
+
+        let requests = server.requests();
+        let request = requests.last().unwrap();
+        assert_eq!(request.method(), "POST");
+        assert_eq!(request.body(), br#"{"names":["sq0"],"lengths":[8]}"#);
+
+        Ok(())
+    }
 }
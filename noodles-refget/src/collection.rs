@@ -0,0 +1,124 @@
+//! Sequence collections (seqcol) endpoints.
+
+pub mod comparison;
+
+pub use self::comparison::Comparison;
+
+use serde::{Deserialize, Serialize};
+
+/// A sequence collection.
+///
+/// This is the expanded (level 2) representation of a collection, where each attribute is an
+/// array with one element per sequence.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Collection {
+    names: Vec<String>,
+    lengths: Vec<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sequences: Option<Vec<String>>,
+}
+
+impl Collection {
+    /// Creates a sequence collection from names and lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget::collection::Collection;
+    ///
+    /// let collection = Collection::new(
+    ///     vec![String::from("sq0"), String::from("sq1")],
+    ///     vec![8, 13],
+    /// );
+    ///
+    /// assert_eq!(collection.names(), ["sq0", "sq1"]);
+    /// assert_eq!(collection.lengths(), [8, 13]);
+    /// ```
+    pub fn new(names: Vec<String>, lengths: Vec<u64>) -> Self {
+        Self {
+            names,
+            lengths,
+            sequences: None,
+        }
+    }
+
+    /// Returns the sequence names.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns the sequence lengths.
+    pub fn lengths(&self) -> &[u64] {
+        &self.lengths
+    }
+
+    /// Returns the sequence digests, if known.
+    pub fn sequences(&self) -> Option<&[String]> {
+        self.sequences.as_deref()
+    }
+}
+
+#[cfg(feature = "sam")]
+impl From<&noodles_sam::header::ReferenceSequences> for Collection {
+    /// Creates a sequence collection from SAM header reference sequences (`@SQ`).
+    ///
+    /// Only names and lengths are set. The sequence digests are not known, as `M5` is an MD5
+    /// checksum rather than a seqcol sequence digest.
+    fn from(reference_sequences: &noodles_sam::header::ReferenceSequences) -> Self {
+        let (names, lengths) = reference_sequences
+            .iter()
+            .map(|(name, reference_sequence)| {
+                (
+                    name.to_string(),
+                    usize::from(reference_sequence.len()) as u64,
+                )
+            })
+            .unzip();
+
+        Self::new(names, lengths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() -> Result<(), serde_json::Error> {
+        let data = r#"{
+            "lengths": [8, 13],
+            "names": ["sq0", "sq1"],
+            "sequences": ["SQ.a", "SQ.b"],
+            "sorted_name_length_pairs": ["x", "y"]
+        }"#;
+
+        let actual: Collection = serde_json::from_str(data)?;
+
+        assert_eq!(actual.names(), ["sq0", "sq1"]);
+        assert_eq!(actual.lengths(), [8, 13]);
+        assert_eq!(
+            actual.sequences(),
+            Some(&[String::from("SQ.a"), String::from("SQ.b")][..])
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "sam")]
+    #[test]
+    fn test_from_reference_sequences() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::header::ReferenceSequence;
+
+        let header = noodles_sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 13)?)
+            .build();
+
+        let actual = Collection::from(header.reference_sequences());
+        let expected = Collection::new(vec![String::from("sq0"), String::from("sq1")], vec![8, 13]);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+//! Sequence collection comparison.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// A comparison of two sequence collections.
+///
+/// The collections are named `a` and `b`. Attributes are compared by their array elements, e.g.,
+/// `names` or `lengths`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Comparison {
+    digests: Digests,
+    attributes: Attributes,
+    array_elements: ArrayElements,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+struct Digests {
+    a: Option<String>,
+    b: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+struct Attributes {
+    a_only: Vec<String>,
+    b_only: Vec<String>,
+    a_and_b: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+struct ArrayElements {
+    #[serde(alias = "a")]
+    a_count: HashMap<String, usize>,
+    #[serde(alias = "b")]
+    b_count: HashMap<String, usize>,
+    #[serde(alias = "a_and_b")]
+    a_and_b_count: HashMap<String, usize>,
+    a_and_b_same_order: HashMap<String, Option<bool>>,
+}
+
+impl Comparison {
+    /// Returns the digest of collection `a`.
+    pub fn a_digest(&self) -> Option<&str> {
+        self.digests.a.as_deref()
+    }
+
+    /// Returns the digest of collection `b`.
+    ///
+    /// This is `None` when `b` is a local collection, i.e., it was sent in the request.
+    pub fn b_digest(&self) -> Option<&str> {
+        self.digests.b.as_deref()
+    }
+
+    /// Returns the attributes only in collection `a`.
+    pub fn a_only(&self) -> &[String] {
+        &self.attributes.a_only
+    }
+
+    /// Returns the attributes only in collection `b`.
+    pub fn b_only(&self) -> &[String] {
+        &self.attributes.b_only
+    }
+
+    /// Returns the attributes in both collections.
+    pub fn a_and_b(&self) -> &[String] {
+        &self.attributes.a_and_b
+    }
+
+    /// Returns the number of elements of an attribute in collection `a`.
+    pub fn a_count(&self, attribute: &str) -> Option<usize> {
+        self.array_elements.a_count.get(attribute).copied()
+    }
+
+    /// Returns the number of elements of an attribute in collection `b`.
+    pub fn b_count(&self, attribute: &str) -> Option<usize> {
+        self.array_elements.b_count.get(attribute).copied()
+    }
+
+    /// Returns the number of elements of an attribute in both collections.
+    pub fn a_and_b_count(&self, attribute: &str) -> Option<usize> {
+        self.array_elements.a_and_b_count.get(attribute).copied()
+    }
+
+    /// Returns whether the shared elements of an attribute are in the same order in both
+    /// collections.
+    ///
+    /// This is `None` if the order cannot be determined, e.g., when there are too few shared
+    /// elements.
+    pub fn a_and_b_same_order(&self, attribute: &str) -> Option<bool> {
+        self.array_elements
+            .a_and_b_same_order
+            .get(attribute)
+            .copied()
+            .flatten()
+    }
+
+    /// Returns whether an attribute is identical in both collections.
+    ///
+    /// This is true when both collections have the same elements in the same order.
+    ///
+    /// Comparing the `names` and `lengths` of a collection created from SAM header reference
+    /// sequences to a known assembly validates that the header describes that assembly.
+    pub fn is_identical(&self, attribute: &str) -> bool {
+        let a_count = self.a_count(attribute);
+
+        a_count.is_some()
+            && a_count == self.b_count(attribute)
+            && a_count == self.a_and_b_count(attribute)
+            && self.a_and_b_same_order(attribute) != Some(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() -> Result<(), serde_json::Error> {
+        let data = r#"{
+            "digests": { "a": "ndls0", "b": null },
+            "attributes": {
+                "a_only": ["sequences"],
+                "b_only": [],
+                "a_and_b": ["lengths", "names"]
+            },
+            "array_elements": {
+                "a_count": { "lengths": 2, "names": 2, "sequences": 2 },
+                "b_count": { "lengths": 2, "names": 2 },
+                "a_and_b_count": { "lengths": 2, "names": 1 },
+                "a_and_b_same_order": { "lengths": true, "names": null }
+            }
+        }"#;
+
+        let actual: Comparison = serde_json::from_str(data)?;
+
+        assert_eq!(actual.a_digest(), Some("ndls0"));
+        assert!(actual.b_digest().is_none());
+        assert_eq!(actual.a_only(), ["sequences"]);
+        assert!(actual.b_only().is_empty());
+        assert_eq!(actual.a_and_b(), ["lengths", "names"]);
+
+        assert_eq!(actual.a_count("sequences"), Some(2));
+        assert!(actual.b_count("sequences").is_none());
+        assert_eq!(actual.a_and_b_same_order("lengths"), Some(true));
+        assert!(actual.a_and_b_same_order("names").is_none());
+
+        assert!(actual.is_identical("lengths"));
+        assert!(!actual.is_identical("names"));
+        assert!(!actual.is_identical("sequences"));
+
+        Ok(())
+    }
+}
@@ -3,9 +3,21 @@
 //! **noodles-refget** is a refget client.
 
 mod client;
+pub mod collection;
+#[cfg(test)]
+#[path = "../tests/common/mock_server.rs"]
+mod mock_server;
+#[cfg(feature = "repository")]
+pub mod repository;
 mod sequence;
-
-pub use self::{client::Client, sequence::Sequence};
+pub mod sequences;
+pub mod service_info;
+
+pub use self::{
+    client::Client,
+    sequence::{Metadata, Sequence},
+    service_info::ServiceInfo,
+};
 
 use std::{error, fmt};
 
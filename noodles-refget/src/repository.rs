@@ -0,0 +1,470 @@
+//! refget-backed FASTA sequence repository adapter.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    collections::HashMap,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use md5::{Digest, Md5};
+use noodles_core::Region;
+use noodles_fasta::{
+    self as fasta,
+    record::{Definition, Sequence},
+};
+use noodles_sam::header::reference_sequence::Md5Checksum;
+use tokio::runtime::Runtime;
+
+use crate::{Client, Error};
+
+/// A refget-backed FASTA sequence repository adapter.
+///
+/// Reference sequence names are resolved to their MD5 checksums (`M5`) using the SAM header
+/// reference sequences given to the builder. The checksums are then used as sequence IDs to
+/// request sequences from a refget server.
+///
+/// If a cache directory is set, full sequences are read from and written to it using the same
+/// layout as htslib's `REF_CACHE`, i.e., `<cache-dir>/%2s/%2s/%s`. Downloaded sequences are
+/// verified against their checksums before being cached.
+///
+/// Requests are made on an internal runtime, so this adapter must not be used from within an
+/// asynchronous context.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_fasta as fasta;
+/// use noodles_refget as refget;
+/// use noodles_sam as sam;
+///
+/// let header = sam::Header::default();
+/// let client = refget::Client::new("https://localhost/".parse().unwrap());
+///
+/// let adapter = refget::repository::Adapter::builder(client)
+///     .set_reference_sequences(header.reference_sequences())
+///     .set_cache_dir("cache")
+///     .build()?;
+///
+/// let repository = fasta::Repository::new(adapter);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Adapter {
+    client: Client,
+    runtime: Runtime,
+    checksums: HashMap<String, Option<Md5Checksum>>,
+    cache_dir: Option<PathBuf>,
+}
+
+impl Adapter {
+    /// Creates a refget repository adapter builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget as refget;
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let builder = refget::repository::Adapter::builder(client);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn builder(client: Client) -> Builder {
+        Builder::new(client)
+    }
+
+    fn checksum(&self, name: &str) -> Option<io::Result<Md5Checksum>> {
+        let result = self.checksums.get(name)?.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("missing reference sequence MD5 checksum (M5): {}", name),
+            )
+        });
+
+        Some(result)
+    }
+
+    fn fetch(
+        &self,
+        checksum: Md5Checksum,
+        region: Option<&Region>,
+    ) -> io::Result<Option<Sequence>> {
+        let mut request = self.client.sequence(checksum.to_string());
+
+        if let Some(region) = region {
+            request = request.set_interval(region.interval());
+        }
+
+        match self.runtime.block_on(request.send()) {
+            Ok(response) => Ok(Some(Sequence::from(response.sequence()))),
+            Err(Error::Request(e)) if e.status() == Some(reqwest::StatusCode::NOT_FOUND) => {
+                Ok(None)
+            }
+            Err(e) => Err(io::Error::new(io::ErrorKind::Other, e)),
+        }
+    }
+
+    fn get_sequence(&self, checksum: Md5Checksum) -> io::Result<Option<Sequence>> {
+        let cache_path = self
+            .cache_dir
+            .as_ref()
+            .map(|dir| build_cache_path(dir, checksum));
+
+        if let Some(path) = &cache_path {
+            match fs::read(path) {
+                Ok(buf) => return Ok(Some(Sequence::from(buf))),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        let sequence = match self.fetch(checksum, None)? {
+            Some(sequence) => sequence,
+            None => return Ok(None),
+        };
+
+        let actual = Md5::digest(sequence.as_ref());
+
+        if actual[..] != checksum[..] {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("sequence checksum mismatch: expected {}", checksum),
+            ));
+        }
+
+        if let Some(path) = &cache_path {
+            write_cache_file(path, sequence.as_ref())?;
+        }
+
+        Ok(Some(sequence))
+    }
+}
+
+impl fasta::repository::Adapter for Adapter {
+    fn get(&mut self, name: &str) -> Option<io::Result<fasta::Record>> {
+        let checksum = match self.checksum(name)? {
+            Ok(checksum) => checksum,
+            Err(e) => return Some(Err(e)),
+        };
+
+        self.get_sequence(checksum).transpose().map(|result| {
+            result.map(|sequence| fasta::Record::new(Definition::new(name, None), sequence))
+        })
+    }
+
+    fn get_region(&mut self, region: &Region) -> Option<io::Result<fasta::Record>> {
+        let checksum = match self.checksum(region.name())? {
+            Ok(checksum) => checksum,
+            Err(e) => return Some(Err(e)),
+        };
+
+        // Full sequences are cached, so only request the region when there is no cache.
+        let result = if self.cache_dir.is_some() {
+            self.get_sequence(checksum).and_then(|sequence| {
+                sequence
+                    .map(|sequence| {
+                        sequence.slice(region.interval()).ok_or_else(|| {
+                            io::Error::new(
+                                io::ErrorKind::InvalidInput,
+                                format!("invalid region interval: {}", region),
+                            )
+                        })
+                    })
+                    .transpose()
+            })
+        } else {
+            self.fetch(checksum, Some(region))
+        };
+
+        result.transpose().map(|result| {
+            result.map(|sequence| {
+                fasta::Record::new(Definition::new(region.to_string(), None), sequence)
+            })
+        })
+    }
+}
+
+fn build_cache_path(cache_dir: &Path, checksum: Md5Checksum) -> PathBuf {
+    let id = checksum.to_string();
+    cache_dir.join(&id[0..2]).join(&id[2..4]).join(&id[4..])
+}
+
+fn write_cache_file(path: &Path, buf: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // Write to a temporary file in the same directory first so that concurrent readers never see
+    // a partial sequence. Each writer uses its own temporary file, and the last rename wins.
+    let (tmp_path, mut file) = create_tmp_file(path)?;
+
+    let result = file
+        .write_all(buf)
+        .and_then(|_| file.sync_all())
+        .and_then(|_| fs::rename(&tmp_path, path));
+
+    if result.is_err() {
+        fs::remove_file(&tmp_path).ok();
+    }
+
+    result
+}
+
+fn create_tmp_file(path: &Path) -> io::Result<(PathBuf, File)> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let pid = process::id();
+
+    loop {
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let tmp_path = path.with_file_name(format!(".{}.{}.{}.tmp", file_name, pid, n));
+
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&tmp_path)
+        {
+            Ok(file) => return Ok((tmp_path, file)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, thread};
+
+    use crate::mock_server::{MockServer, Response};
+    use fasta::repository::Adapter as _;
+    use noodles_sam as sam;
+
+    use super::*;
+
+    const SQ0_MD5: &str = "cc0af3a4fedb18378b4b57b98068e69f";
+    const SQ1_MD5: &str = "d7eba311421bbc9d3ada44709dd61534";
+
+    // Starts a mock refget server that serves `ACGTACGT` for `SQ0_MD5`, `NNNN` (a checksum
+    // mismatch) for `SQ1_MD5`, and 404 otherwise.
+    fn start_server() -> io::Result<(Client, MockServer)> {
+        let server = MockServer::start(|request| {
+            match (
+                request.path().trim_start_matches("/sequence/"),
+                request.query(),
+            ) {
+                (SQ0_MD5, Some("start=1&end=5")) => Response::ok("CGTA"),
+                (SQ0_MD5, _) => Response::ok("ACGTACGT"),
+                (SQ1_MD5, _) => Response::ok("NNNN"),
+                _ => Response::not_found(),
+            }
+        })?;
+
+        let base_url = server.base_url().parse().unwrap();
+
+        Ok((Client::new(base_url), server))
+    }
+
+    fn build_reference_sequences(
+    ) -> Result<sam::header::ReferenceSequences, Box<dyn std::error::Error>> {
+        use sam::header::ReferenceSequence;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                ReferenceSequence::builder()
+                    .set_name("sq0".parse()?)
+                    .set_length(8)
+                    .set_md5_checksum(SQ0_MD5.parse()?)
+                    .build()?,
+            )
+            .add_reference_sequence(
+                ReferenceSequence::builder()
+                    .set_name("sq1".parse()?)
+                    .set_length(4)
+                    .set_md5_checksum(SQ1_MD5.parse()?)
+                    .build()?,
+            )
+            .add_reference_sequence(ReferenceSequence::new("sq2".parse()?, 4)?)
+            .add_reference_sequence(
+                ReferenceSequence::builder()
+                    .set_name("sq3".parse()?)
+                    .set_length(4)
+                    .set_md5_checksum("0123456789abcdef0123456789abcdef".parse()?)
+                    .build()?,
+            )
+            .build();
+
+        Ok(header.reference_sequences().clone())
+    }
+
+    fn build_cache_dir(name: &str) -> io::Result<PathBuf> {
+        let dir = env::temp_dir().join(format!("noodles-refget-{}-{}", name, std::process::id()));
+
+        match fs::remove_dir_all(&dir) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+
+        Ok(dir)
+    }
+
+    #[test]
+    fn test_get() -> Result<(), Box<dyn std::error::Error>> {
+        let (client, server) = start_server()?;
+        let reference_sequences = build_reference_sequences()?;
+
+        let mut adapter = Adapter::builder(client)
+            .set_reference_sequences(&reference_sequences)
+            .build()?;
+
+        let record = adapter.get("sq0").transpose()?;
+        assert_eq!(
+            record,
+            Some(fasta::Record::new(
+                Definition::new("sq0", None),
+                Sequence::from(b"ACGTACGT".to_vec())
+            ))
+        );
+
+        assert!(matches!(
+            adapter.get("sq1"),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(matches!(
+            adapter.get("sq2"),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(adapter.get("sq3").is_none());
+        assert!(adapter.get("sq4").is_none());
+
+        assert_eq!(
+            server.targets(),
+            [
+                format!("/sequence/{}", SQ0_MD5),
+                format!("/sequence/{}", SQ1_MD5),
+                String::from("/sequence/0123456789abcdef0123456789abcdef"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_with_cache_dir() -> Result<(), Box<dyn std::error::Error>> {
+        let (client, server) = start_server()?;
+        let reference_sequences = build_reference_sequences()?;
+        let cache_dir = build_cache_dir("test_get_with_cache_dir")?;
+
+        let mut adapter = Adapter::builder(client)
+            .set_reference_sequences(&reference_sequences)
+            .set_cache_dir(&cache_dir)
+            .build()?;
+
+        let expected = Sequence::from(b"ACGTACGT".to_vec());
+
+        for _ in 0..2 {
+            let record = adapter.get("sq0").transpose()?;
+            assert_eq!(record.map(|r| r.sequence().clone()), Some(expected.clone()));
+        }
+
+        assert_eq!(server.requests().len(), 1);
+
+        let cache_path = cache_dir
+            .join("cc")
+            .join("0a")
+            .join("f3a4fedb18378b4b57b98068e69f");
+        assert_eq!(fs::read(cache_path)?, b"ACGTACGT");
+
+        // Sequences with mismatched checksums are not cached.
+        assert!(adapter.get("sq1").transpose().is_err());
+        assert!(!build_cache_path(&cache_dir, SQ1_MD5.parse()?).exists());
+
+        let record = adapter.get_region(&"sq0:2-5".parse()?).transpose()?;
+        assert_eq!(
+            record.map(|r| r.sequence().clone()),
+            Some(Sequence::from(b"CGTA".to_vec()))
+        );
+
+        assert_eq!(server.requests().len(), 2);
+
+        fs::remove_dir_all(cache_dir)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_region() -> Result<(), Box<dyn std::error::Error>> {
+        let (client, server) = start_server()?;
+        let reference_sequences = build_reference_sequences()?;
+
+        let mut adapter = Adapter::builder(client)
+            .set_reference_sequences(&reference_sequences)
+            .build()?;
+
+        let record = adapter.get_region(&"sq0:2-5".parse()?).transpose()?;
+        assert_eq!(
+            record,
+            Some(fasta::Record::new(
+                Definition::new("sq0:2-5", None),
+                Sequence::from(b"CGTA".to_vec())
+            ))
+        );
+
+        assert_eq!(
+            server.targets(),
+            [format!("/sequence/{}?start=1&end=5", SQ0_MD5)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_cache_file() -> io::Result<()> {
+        let cache_dir = build_cache_dir("test_write_cache_file")?;
+        let path = cache_dir.join("cc").join("0a").join("f3a4");
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let path = path.clone();
+                thread::spawn(move || write_cache_file(&path, b"ACGTACGT"))
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap()?;
+        }
+
+        assert_eq!(fs::read(&path)?, b"ACGTACGT");
+
+        // No temporary files are left behind.
+        let entries: Vec<_> = fs::read_dir(path.parent().unwrap())?
+            .map(|result| result.map(|entry| entry.file_name()))
+            .collect::<io::Result<_>>()?;
+        assert_eq!(entries, ["f3a4"]);
+
+        fs::remove_dir_all(cache_dir)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_cache_path(
+    ) -> Result<(), noodles_sam::header::reference_sequence::md5_checksum::ParseError> {
+        let checksum = SQ0_MD5.parse()?;
+
+        assert_eq!(
+            build_cache_path(Path::new("cache"), checksum),
+            Path::new("cache/cc/0a/f3a4fedb18378b4b57b98068e69f")
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use std::{collections::HashMap, io, path::PathBuf};
+
+use noodles_sam::header::{reference_sequence::Md5Checksum, ReferenceSequences};
+
+use super::Adapter;
+use crate::Client;
+
+/// A refget repository adapter builder.
+pub struct Builder {
+    client: Client,
+    checksums: HashMap<String, Option<Md5Checksum>>,
+    cache_dir: Option<PathBuf>,
+}
+
+impl Builder {
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            checksums: HashMap::new(),
+            cache_dir: None,
+        }
+    }
+
+    /// Sets the reference sequences used to resolve names to MD5 checksums.
+    ///
+    /// Only the reference sequence names and MD5 checksums (`M5`) are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget as refget;
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    ///
+    /// let builder = refget::repository::Adapter::builder(client)
+    ///     .set_reference_sequences(header.reference_sequences());
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn set_reference_sequences(mut self, reference_sequences: &ReferenceSequences) -> Self {
+        self.checksums = reference_sequences
+            .iter()
+            .map(|(name, reference_sequence)| (name.clone(), reference_sequence.md5_checksum()))
+            .collect();
+
+        self
+    }
+
+    /// Sets the cache directory.
+    ///
+    /// Sequences are stored using the same layout as htslib's `REF_CACHE`, i.e.,
+    /// `<cache-dir>/%2s/%2s/%s`. By default, there is no cache directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let builder = refget::repository::Adapter::builder(client).set_cache_dir("cache");
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn set_cache_dir<P>(mut self, cache_dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.cache_dir = Some(cache_dir.into());
+        self
+    }
+
+    /// Builds a refget repository adapter.
+    ///
+    /// This fails if the runtime used to make requests cannot be created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_refget as refget;
+    /// let client = refget::Client::new("https://localhost/".parse().unwrap());
+    /// let adapter = refget::repository::Adapter::builder(client).build()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build(self) -> io::Result<Adapter> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Adapter {
+            client: self.client,
+            runtime,
+            checksums: self.checksums,
+            cache_dir: self.cache_dir,
+        })
+    }
+}
@@ -31,6 +31,10 @@ impl Builder {
     }
 
     /// Sends the request.
+    ///
+    /// This returns an [`Error::Request`] if the server responds with an error status, e.g., `404
+    /// Not Found` when the sequence does not exist. Otherwise, the body of an error response would
+    /// be returned as the sequence.
     pub async fn send(self) -> crate::Result<Sequence> {
         let endpoint = self
             .client
@@ -56,7 +60,11 @@ impl Builder {
             request = request.query(&query);
         }
 
-        let response = request.send().await.map_err(Error::Request)?;
+        let response = request
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(Error::Request)?;
         let sequence = response.bytes().await.map_err(Error::Request)?;
 
         Ok(Sequence::new(self.client, self.id, sequence))
@@ -34,3 +34,8 @@ impl Metadata {
         &self.aliases
     }
 }
+
+#[derive(Deserialize)]
+pub(crate) struct MetadataResponse {
+    pub(crate) metadata: Metadata,
+}
@@ -0,0 +1,65 @@
+//! Batched sequence endpoint.
+
+use futures::{stream, StreamExt, TryStreamExt};
+
+use crate::{Client, Sequence};
+
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// A batched sequence endpoint builder.
+///
+/// Refget does not have a batch endpoint, so each sequence is requested separately. Requests are
+/// sent concurrently, and the sequences are returned in the order of the given IDs.
+pub struct Builder {
+    client: Client,
+    ids: Vec<String>,
+    concurrency: usize,
+}
+
+impl Builder {
+    pub(crate) fn new(client: Client, ids: Vec<String>) -> Self {
+        Self {
+            client,
+            ids,
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Sets the max number of concurrent requests.
+    ///
+    /// By default, at most 4 requests are in flight at a time.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `concurrency` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_refget as refget;
+    ///
+    /// let client = refget::Client::new("https://localhost/".parse()?);
+    /// let builder = client
+    ///     .sequences(["d7eba311421bbc9d3ada44709dd61534"])
+    ///     .set_concurrency(8);
+    /// # Ok::<_, url::ParseError>(())
+    /// ```
+    pub fn set_concurrency(mut self, concurrency: usize) -> Self {
+        assert!(concurrency > 0, "concurrency must be > 0");
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Sends the requests.
+    ///
+    /// This fails on the first request that fails.
+    pub async fn send(self) -> crate::Result<Vec<Sequence>> {
+        let client = self.client;
+
+        stream::iter(self.ids)
+            .map(|id| client.sequence(id).send())
+            .buffered(self.concurrency)
+            .try_collect()
+            .await
+    }
+}
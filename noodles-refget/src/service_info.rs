@@ -0,0 +1,118 @@
+//! Refget service info.
+
+use serde::Deserialize;
+
+/// Refget service info.
+///
+/// This is the refget section of the service info response. Both the refget v1 (`service`) and
+/// v2 (`refget`) response layouts are supported.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct ServiceInfo {
+    circular_supported: bool,
+    #[serde(default)]
+    algorithms: Vec<String>,
+    #[serde(default)]
+    identifier_types: Vec<String>,
+    subsequence_limit: Option<u64>,
+    #[serde(default)]
+    supported_api_versions: Vec<String>,
+}
+
+impl ServiceInfo {
+    /// Returns whether circular sequences are supported.
+    ///
+    /// If supported, an interval with a start greater than its end wraps around the origin of a
+    /// circular sequence.
+    pub fn circular_supported(&self) -> bool {
+        self.circular_supported
+    }
+
+    /// Returns the supported checksum algorithms, e.g., `md5`, `trunc512`, and `ga4gh`.
+    pub fn algorithms(&self) -> &[String] {
+        &self.algorithms
+    }
+
+    /// Returns the supported identifier types, e.g., `insdc`.
+    ///
+    /// This is only set by refget v2 servers.
+    pub fn identifier_types(&self) -> &[String] {
+        &self.identifier_types
+    }
+
+    /// Returns the max length of a subsequence that can be requested.
+    ///
+    /// If `None`, there is no limit.
+    pub fn subsequence_limit(&self) -> Option<u64> {
+        self.subsequence_limit
+    }
+
+    /// Returns the supported API versions.
+    ///
+    /// This is only set by refget v1 servers.
+    pub fn supported_api_versions(&self) -> &[String] {
+        &self.supported_api_versions
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum ServiceInfoResponse {
+    V1 { service: ServiceInfo },
+    V2 { refget: ServiceInfo },
+}
+
+impl From<ServiceInfoResponse> for ServiceInfo {
+    fn from(response: ServiceInfoResponse) -> Self {
+        match response {
+            ServiceInfoResponse::V1 { service } => service,
+            ServiceInfoResponse::V2 { refget } => refget,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() -> Result<(), serde_json::Error> {
+        let data = r#"{
+            "service": {
+                "circular_supported": true,
+                "algorithms": ["md5", "trunc512"],
+                "subsequence_limit": 4000000,
+                "supported_api_versions": ["1.0"]
+            }
+        }"#;
+
+        let actual = ServiceInfo::from(serde_json::from_str::<ServiceInfoResponse>(data)?);
+
+        assert!(actual.circular_supported());
+        assert_eq!(actual.algorithms(), ["md5", "trunc512"]);
+        assert!(actual.identifier_types().is_empty());
+        assert_eq!(actual.subsequence_limit(), Some(4000000));
+        assert_eq!(actual.supported_api_versions(), ["1.0"]);
+
+        let data = r#"{
+            "id": "org.example.refget",
+            "name": "noodles refget",
+            "type": { "group": "org.ga4gh", "artifact": "refget", "version": "2.0.0" },
+            "refget": {
+                "circular_supported": false,
+                "algorithms": ["md5", "ga4gh"],
+                "identifier_types": ["insdc"],
+                "subsequence_limit": null
+            }
+        }"#;
+
+        let actual = ServiceInfo::from(serde_json::from_str::<ServiceInfoResponse>(data)?);
+
+        assert!(!actual.circular_supported());
+        assert_eq!(actual.algorithms(), ["md5", "ga4gh"]);
+        assert_eq!(actual.identifier_types(), ["insdc"]);
+        assert!(actual.subsequence_limit().is_none());
+        assert!(actual.supported_api_versions().is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,247 @@
+//! A minimal HTTP/1.1 server for testing HTTP clients.
+//!
+//! Each connection handles a single request, which is recorded and then passed to a handler that
+//! builds the response.
+//!
+//! This is included in the unit tests using `#[path]`, so not every item is used by every crate.
+
+#![allow(dead_code)]
+
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+type Handler = dyn Fn(&Request) -> Response + Send + Sync;
+
+/// A recorded HTTP request.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Request {
+    method: String,
+    target: String,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl Request {
+    /// Returns the request method, e.g., `GET`.
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /// Returns the request target, i.e., the path and query.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Returns the path of the request target.
+    pub fn path(&self) -> &str {
+        self.target
+            .split_once('?')
+            .map(|(path, _)| path)
+            .unwrap_or(&self.target)
+    }
+
+    /// Returns the query of the request target, if any.
+    pub fn query(&self) -> Option<&str> {
+        self.target.split_once('?').map(|(_, query)| query)
+    }
+
+    /// Returns the value of the header with the given name, if any.
+    ///
+    /// Header names are case-insensitive.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the request body.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+}
+
+/// An HTTP response.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Response {
+    status: u16,
+    body: Vec<u8>,
+}
+
+impl Response {
+    /// Creates a response with the given status code and body.
+    pub fn new<B>(status: u16, body: B) -> Self
+    where
+        B: Into<Vec<u8>>,
+    {
+        Self {
+            status,
+            body: body.into(),
+        }
+    }
+
+    /// Creates a `200 OK` response with the given body.
+    pub fn ok<B>(body: B) -> Self
+    where
+        B: Into<Vec<u8>>,
+    {
+        Self::new(200, body)
+    }
+
+    /// Creates an empty `404 Not Found` response.
+    pub fn not_found() -> Self {
+        Self::new(404, Vec::new())
+    }
+
+    fn write<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        write!(
+            writer,
+            "HTTP/1.1 {} {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            self.status,
+            reason_phrase(self.status),
+            self.body.len()
+        )?;
+
+        writer.write_all(&self.body)
+    }
+}
+
+/// A mock HTTP server.
+///
+/// The server runs on a background thread until the process exits. Each connection is handled
+/// on its own thread, so handlers can block without delaying other requests.
+pub struct MockServer {
+    base_url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    /// Starts a mock server on an ephemeral local port.
+    ///
+    /// Every request is recorded before it is passed to the handler.
+    pub fn start<F>(handler: F) -> io::Result<Self>
+    where
+        F: Fn(&Request) -> Response + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let base_url = format!("http://{}/", listener.local_addr()?);
+
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let server_requests = Arc::clone(&requests);
+        let handler: Arc<Handler> = Arc::new(handler);
+
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let requests = Arc::clone(&server_requests);
+                let handler = Arc::clone(&handler);
+                thread::spawn(move || handle(stream, &requests, &*handler));
+            }
+        });
+
+        Ok(Self { base_url, requests })
+    }
+
+    /// Returns the base URL of the server, e.g., `http://127.0.0.1:8000/`.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the recorded requests, in the order they were received.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Returns the recorded request targets, in the order they were received.
+    pub fn targets(&self) -> Vec<String> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|request| request.target.clone())
+            .collect()
+    }
+
+    /// Clears the recorded requests.
+    pub fn clear_requests(&self) {
+        self.requests.lock().unwrap().clear();
+    }
+}
+
+fn handle(mut stream: TcpStream, requests: &Mutex<Vec<Request>>, handler: &Handler) {
+    let request = match read_request(&mut stream) {
+        Ok(request) => request,
+        Err(_) => return,
+    };
+
+    requests.lock().unwrap().push(request.clone());
+
+    let response = handler(&request);
+    response.write(&mut stream).ok();
+}
+
+fn read_request(stream: &mut TcpStream) -> io::Result<Request> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    let mut fields = request_line.split_whitespace();
+    let method = fields.next().unwrap_or_default().into();
+    let target = fields.next().unwrap_or_default().into();
+
+    let mut headers: Vec<(String, String)> = Vec::new();
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some((key, value)) = line.split_once(':') {
+            headers.push((key.trim().into(), value.trim().into()));
+        }
+    }
+
+    let content_length = headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.parse().ok())
+        .unwrap_or(0);
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Request {
+        method,
+        target,
+        headers,
+        body,
+    })
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
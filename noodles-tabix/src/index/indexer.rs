@@ -1,7 +1,7 @@
 use noodles_core::Position;
 use noodles_csi::index::reference_sequence::bin::Chunk;
 
-use super::{reference_sequence, Header, Index, ReferenceSequence, ReferenceSequenceNames};
+use super::{header, reference_sequence, Header, Index, ReferenceSequence, ReferenceSequenceNames};
 
 /// A tabix indexer.
 #[derive(Debug, Default)]
@@ -13,6 +13,52 @@ pub struct Indexer {
 }
 
 impl Indexer {
+    /// Creates a tabix indexer with a BED index header.
+    ///
+    /// Start positions added to the indexer are 1-based, i.e., `chromStart + 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    /// let indexer = tabix::index::Indexer::bed();
+    /// ```
+    pub fn bed() -> Self {
+        Self::with_header(header::Builder::bed().build())
+    }
+
+    /// Creates a tabix indexer with a GFF index header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    /// let indexer = tabix::index::Indexer::gff();
+    /// ```
+    pub fn gff() -> Self {
+        Self::with_header(header::Builder::gff().build())
+    }
+
+    /// Creates a tabix indexer with a GTF index header.
+    ///
+    /// GTF and GFF share the same column layout, so this uses the GFF index header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    /// let indexer = tabix::index::Indexer::gtf();
+    /// ```
+    pub fn gtf() -> Self {
+        Self::gff()
+    }
+
+    fn with_header(header: Header) -> Self {
+        let mut indexer = Self::default();
+        indexer.set_header(header);
+        indexer
+    }
+
     /// Sets an index header.
     ///
     /// # Examples
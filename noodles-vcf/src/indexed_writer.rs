@@ -0,0 +1,245 @@
+use std::io::{self, Write};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::index::reference_sequence::bin::Chunk;
+use noodles_tabix as tabix;
+
+use super::{record::Chromosome, Header, Record, Writer};
+
+// The max position a tabix index can address, i.e., 2^29 - 1.
+const MAX_POSITION: usize = (1 << 29) - 1;
+
+/// A bgzipped VCF writer that builds a tabix index from the records it writes.
+///
+/// Records must be written in coordinate-sorted order. This is similar to
+/// `bcftools view --write-index=tbi`.
+///
+/// A tabix index can only address positions up to 2^29 - 1. Records that end after this position
+/// are rejected.
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::Writer<W>>,
+    indexer: tabix::index::Indexer,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed VCF writer with a default compression level.
+    ///
+    /// The given stream is wrapped in a BGZF encoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::IndexedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self::from(Writer::new(bgzf::Writer::new(inner)))
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::IndexedWriter::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        self.inner.get_ref().get_ref()
+    }
+
+    /// Writes a VCF header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = vcf::IndexedWriter::new(Vec::new());
+    ///
+    /// let header = vcf::Header::default();
+    /// writer.write_header(&header)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
+        self.inner.write_header(header)
+    }
+
+    /// Writes a VCF record and adds it to the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::Position};
+    ///
+    /// let mut writer = vcf::IndexedWriter::new(Vec::new());
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// writer.write_record(&record)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        let reference_sequence_name = match record.chromosome() {
+            Chromosome::Name(name) => name.clone(),
+            Chromosome::Symbol(symbol) => symbol.to_string(),
+        };
+
+        // The positions are 1-based and can be 0, i.e., a telomere.
+        let start = Position::new(usize::from(record.position())).unwrap_or(Position::MIN);
+
+        let end = record
+            .end()
+            .map(usize::from)
+            .map(|n| Position::new(n).unwrap_or(Position::MIN))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        if usize::from(end) > MAX_POSITION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "record end position ({}) exceeds the max tabix position ({})",
+                    end, MAX_POSITION
+                ),
+            ));
+        }
+
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_record(record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        self.indexer.add_record(
+            &reference_sequence_name,
+            start,
+            end.max(start),
+            Chunk::new(start_position, end_position),
+        );
+
+        Ok(())
+    }
+
+    /// Finishes the output stream and returns the underlying writer and the built index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = vcf::IndexedWriter::new(Vec::new());
+    ///
+    /// let header = vcf::Header::default();
+    /// writer.write_header(&header)?;
+    ///
+    /// let (_, index) = writer.finish()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn finish(self) -> io::Result<(W, tabix::Index)> {
+        let inner = self.inner.into_inner().finish()?;
+        Ok((inner, self.indexer.build()))
+    }
+}
+
+impl<W> From<Writer<bgzf::Writer<W>>> for IndexedWriter<W>
+where
+    W: Write,
+{
+    fn from(inner: Writer<bgzf::Writer<W>>) -> Self {
+        let mut indexer = tabix::Index::indexer();
+        indexer.set_header(tabix::index::header::Builder::vcf().build());
+
+        Self { inner, indexer }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_csi::BinningIndex;
+
+    use super::*;
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::Position;
+
+        let mut writer = IndexedWriter::new(Vec::new());
+
+        let header = Header::default();
+        writer.write_header(&header)?;
+
+        for (chromosome, position) in [("sq0", 8), ("sq0", 13), ("sq1", 5)] {
+            let record = Record::builder()
+                .set_chromosome(chromosome.parse()?)
+                .set_position(Position::from(position))
+                .set_reference_bases("A".parse()?)
+                .build()?;
+
+            writer.write_record(&record)?;
+        }
+
+        let (buf, index) = writer.finish()?;
+
+        assert_eq!(
+            index
+                .header()
+                .reference_sequence_names()
+                .iter()
+                .collect::<Vec<_>>(),
+            ["sq0", "sq1"]
+        );
+
+        let reference_sequences = index.reference_sequences();
+        assert_eq!(reference_sequences.len(), 2);
+
+        let mut reader = crate::Reader::new(bgzf::Reader::new(&buf[..]));
+        reader.read_header()?;
+
+        let start_position = reader.get_ref().virtual_position();
+
+        let chunks = index.query(0, ..)?;
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].start(), start_position);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_position_out_of_range() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::Position;
+
+        let mut writer = IndexedWriter::new(Vec::new());
+
+        let header = Header::default();
+        writer.write_header(&header)?;
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(1 << 29))
+            .set_reference_bases("A".parse()?)
+            .build()?;
+
+        let position = writer.inner.get_ref().virtual_position();
+
+        assert!(matches!(
+            writer.write_record(&record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert_eq!(writer.inner.get_ref().virtual_position(), position);
+
+        Ok(())
+    }
+}
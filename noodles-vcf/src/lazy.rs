@@ -0,0 +1,5 @@
+//! Lazily-evaluated VCF record and fields.
+
+pub mod record;
+
+pub use self::record::Record;
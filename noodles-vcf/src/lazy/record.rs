@@ -0,0 +1,342 @@
+//! Lazily-evaluated VCF record.
+
+mod genotypes;
+mod info;
+
+pub use self::{
+    genotypes::{Genotypes, Sample},
+    info::Info,
+};
+
+use std::{
+    fmt, io,
+    ops::{Range, RangeFrom},
+    str::FromStr,
+};
+
+use crate::{
+    record::{
+        AlternateBases, Chromosome, Filters, Ids, Position, QualityScore, ReferenceBases,
+        MISSING_FIELD,
+    },
+    Header,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Bounds {
+    pub(crate) chromosome_end: usize,
+    pub(crate) position_end: usize,
+    pub(crate) ids_end: usize,
+    pub(crate) reference_bases_end: usize,
+    pub(crate) alternate_bases_end: usize,
+    pub(crate) quality_score_end: usize,
+    pub(crate) filters_end: usize,
+    pub(crate) info_end: usize,
+}
+
+impl Bounds {
+    fn chromosome_range(&self) -> Range<usize> {
+        0..self.chromosome_end
+    }
+
+    fn position_range(&self) -> Range<usize> {
+        self.chromosome_end..self.position_end
+    }
+
+    fn ids_range(&self) -> Range<usize> {
+        self.position_end..self.ids_end
+    }
+
+    fn reference_bases_range(&self) -> Range<usize> {
+        self.ids_end..self.reference_bases_end
+    }
+
+    fn alternate_bases_range(&self) -> Range<usize> {
+        self.reference_bases_end..self.alternate_bases_end
+    }
+
+    fn quality_score_range(&self) -> Range<usize> {
+        self.alternate_bases_end..self.quality_score_end
+    }
+
+    fn filters_range(&self) -> Range<usize> {
+        self.quality_score_end..self.filters_end
+    }
+
+    fn info_range(&self) -> Range<usize> {
+        self.filters_end..self.info_end
+    }
+
+    fn genotypes_range(&self) -> RangeFrom<usize> {
+        self.info_end..
+    }
+}
+
+/// An immutable, lazily-evalulated VCF record.
+///
+/// The fields are _not_ memoized.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Record {
+    pub(crate) buf: String,
+    pub(crate) bounds: Bounds,
+}
+
+impl Record {
+    /// Returns the chromosome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::Chromosome};
+    /// let record = vcf::lazy::Record::default();
+    /// assert_eq!(record.chromosome()?, Chromosome::Name(String::from("sq0")));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn chromosome(&self) -> io::Result<Chromosome> {
+        parse(&self.buf[self.bounds.chromosome_range()])
+    }
+
+    /// Returns the position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let record = vcf::lazy::Record::default();
+    /// assert_eq!(usize::from(record.position()?), 1);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn position(&self) -> io::Result<Position> {
+        parse(&self.buf[self.bounds.position_range()])
+    }
+
+    /// Returns the IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let record = vcf::lazy::Record::default();
+    /// assert!(record.ids()?.is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn ids(&self) -> io::Result<Ids> {
+        parse(&self.buf[self.bounds.ids_range()])
+    }
+
+    /// Returns the reference bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::reference_bases::Base};
+    /// let record = vcf::lazy::Record::default();
+    /// assert_eq!(&record.reference_bases()?[..], [Base::A]);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn reference_bases(&self) -> io::Result<ReferenceBases> {
+        parse(&self.buf[self.bounds.reference_bases_range()])
+    }
+
+    /// Returns the alternate bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let record = vcf::lazy::Record::default();
+    /// assert!(record.alternate_bases()?.is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn alternate_bases(&self) -> io::Result<AlternateBases> {
+        parse(&self.buf[self.bounds.alternate_bases_range()])
+    }
+
+    /// Returns the quality score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let record = vcf::lazy::Record::default();
+    /// assert!(record.quality_score()?.is_none());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn quality_score(&self) -> io::Result<Option<QualityScore>> {
+        parse_optional(&self.buf[self.bounds.quality_score_range()])
+    }
+
+    /// Returns the filters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let record = vcf::lazy::Record::default();
+    /// assert!(record.filters()?.is_none());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn filters(&self) -> io::Result<Option<Filters>> {
+        parse_optional(&self.buf[self.bounds.filters_range()])
+    }
+
+    /// Returns the info.
+    ///
+    /// The info fields are not parsed until they are accessed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let record = vcf::lazy::Record::default();
+    /// assert!(record.info().is_empty());
+    /// ```
+    pub fn info(&self) -> Info<'_> {
+        Info::new(&self.buf[self.bounds.info_range()])
+    }
+
+    /// Returns the genotypes.
+    ///
+    /// This includes the format (`FORMAT`) and samples. The samples are not parsed until they are
+    /// accessed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let record = vcf::lazy::Record::default();
+    /// assert!(record.genotypes().is_empty());
+    /// ```
+    pub fn genotypes(&self) -> Genotypes<'_> {
+        Genotypes::new(&self.buf[self.bounds.genotypes_range()])
+    }
+
+    /// Converts a lazy VCF record to a VCF record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::Position};
+    ///
+    /// let header = vcf::Header::default();
+    /// let record = vcf::lazy::Record::default();
+    ///
+    /// let actual = record.try_into_vcf_record(&header)?;
+    /// let expected = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(actual, expected);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_into_vcf_record(&self, header: &Header) -> io::Result<crate::Record> {
+        let info = crate::record::Info::try_from_str(self.info().as_ref(), header.infos())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let genotypes = match self.genotypes().as_ref() {
+            "" => crate::record::Genotypes::default(),
+            s => crate::record::Genotypes::parse(s, header)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        };
+
+        let mut builder = crate::Record::builder()
+            .set_chromosome(self.chromosome()?)
+            .set_position(self.position()?)
+            .set_ids(self.ids()?)
+            .set_reference_bases(self.reference_bases()?)
+            .set_alternate_bases(self.alternate_bases()?)
+            .set_info(info)
+            .set_genotypes(genotypes);
+
+        if let Some(quality_score) = self.quality_score()? {
+            builder = builder.set_quality_score(quality_score);
+        }
+
+        if let Some(filters) = self.filters()? {
+            builder = builder.set_filters(filters);
+        }
+
+        builder
+            .build()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+impl fmt::Debug for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Record")
+            .field("chromosome", &self.chromosome())
+            .field("position", &self.position())
+            .field("ids", &self.ids())
+            .field("reference_bases", &self.reference_bases())
+            .field("alternate_bases", &self.alternate_bases())
+            .field("quality_score", &self.quality_score())
+            .field("filters", &self.filters())
+            .field("info", &self.info())
+            .field("genotypes", &self.genotypes())
+            .finish()
+    }
+}
+
+impl Default for Record {
+    fn default() -> Self {
+        let buf = String::from("sq01.A....");
+
+        let bounds = Bounds {
+            chromosome_end: 3,
+            position_end: 4,
+            ids_end: 5,
+            reference_bases_end: 6,
+            alternate_bases_end: 7,
+            quality_score_end: 8,
+            filters_end: 9,
+            info_end: 10,
+        };
+
+        Self { buf, bounds }
+    }
+}
+
+fn parse<T>(s: &str) -> io::Result<T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    s.parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn parse_optional<T>(s: &str) -> io::Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    match s {
+        MISSING_FIELD => Ok(None),
+        _ => parse(s).map(Some),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fields() -> io::Result<()> {
+        let record = Record::default();
+
+        assert_eq!(record.chromosome()?, Chromosome::Name(String::from("sq0")));
+        assert_eq!(record.position()?, Position::from(1));
+        assert!(record.ids()?.is_empty());
+        assert_eq!(record.reference_bases()?.len(), 1);
+        assert!(record.alternate_bases()?.is_empty());
+        assert!(record.quality_score()?.is_none());
+        assert!(record.filters()?.is_none());
+        assert!(record.info().is_empty());
+        assert!(record.genotypes().is_empty());
+
+        Ok(())
+    }
+}
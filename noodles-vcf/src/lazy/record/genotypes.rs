@@ -0,0 +1,169 @@
+use std::fmt;
+
+use crate::record::{FIELD_DELIMITER, MISSING_FIELD};
+
+const DELIMITER: char = ':';
+
+/// Raw VCF record genotypes.
+///
+/// This includes the format (`FORMAT`) and samples. The samples are split on demand, and values
+/// are not parsed.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct Genotypes<'a>(&'a str);
+
+impl<'a> Genotypes<'a> {
+    pub(super) fn new(s: &'a str) -> Self {
+        Self(s)
+    }
+
+    /// Returns whether there are any samples.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of samples.
+    pub fn len(&self) -> usize {
+        self.0.matches(FIELD_DELIMITER).count()
+    }
+
+    /// Returns an iterator over the format keys.
+    pub fn keys(&self) -> impl Iterator<Item = &'a str> {
+        self.format().split(DELIMITER).filter(|s| !s.is_empty())
+    }
+
+    /// Returns the sample at the given index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = b"sq0\t1\t.\tA\t.\t.\tPASS\t.\tGT:GQ\t0|0:13\t0/1\n";
+    /// let mut reader = vcf::Reader::new(&data[..]);
+    ///
+    /// let mut record = vcf::lazy::Record::default();
+    /// reader.read_lazy_record(&mut record)?;
+    ///
+    /// let genotypes = record.genotypes();
+    /// assert_eq!(genotypes.len(), 2);
+    /// assert_eq!(genotypes.keys().collect::<Vec<_>>(), ["GT", "GQ"]);
+    ///
+    /// let sample = genotypes.get(1).expect("missing sample");
+    /// assert_eq!(sample.get("GT"), Some(Some("0/1")));
+    /// assert_eq!(sample.get("GQ"), Some(None));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn get(&self, i: usize) -> Option<Sample<'a>> {
+        self.samples().nth(i)
+    }
+
+    /// Returns an iterator over the samples.
+    pub fn samples(&self) -> impl Iterator<Item = Sample<'a>> {
+        let format = self.format();
+
+        self.0
+            .split(FIELD_DELIMITER)
+            .skip(1)
+            .map(move |s| Sample::new(format, s))
+    }
+
+    fn format(&self) -> &'a str {
+        self.0.split(FIELD_DELIMITER).next().unwrap_or_default()
+    }
+}
+
+impl<'a> AsRef<str> for Genotypes<'a> {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl<'a> fmt::Debug for Genotypes<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.samples()).finish()
+    }
+}
+
+/// A raw VCF record genotypes sample.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct Sample<'a> {
+    format: &'a str,
+    src: &'a str,
+}
+
+impl<'a> Sample<'a> {
+    fn new(format: &'a str, src: &'a str) -> Self {
+        Self { format, src }
+    }
+
+    /// Returns the raw value of the field with the given format key.
+    ///
+    /// This returns `None` if the key is not in the format and `Some(None)` if the value is
+    /// missing.
+    pub fn get(&self, key: &str) -> Option<Option<&'a str>> {
+        let i = self.format.split(DELIMITER).position(|k| k == key)?;
+        Some(self.values().nth(i).flatten())
+    }
+
+    /// Returns an iterator over the raw values.
+    ///
+    /// Trailing fields that are dropped from the sample are not included.
+    pub fn values(&self) -> impl Iterator<Item = Option<&'a str>> {
+        self.src.split(DELIMITER).map(|s| match s {
+            MISSING_FIELD => None,
+            _ => Some(s),
+        })
+    }
+}
+
+impl<'a> AsRef<str> for Sample<'a> {
+    fn as_ref(&self) -> &str {
+        self.src
+    }
+}
+
+impl<'a> fmt::Debug for Sample<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.format.split(DELIMITER).zip(self.values()))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples() {
+        let genotypes = Genotypes::new("GT:GQ:DP\t0|0:13:.\t.\t1/1:8:5");
+
+        assert_eq!(genotypes.len(), 3);
+        assert_eq!(genotypes.keys().collect::<Vec<_>>(), ["GT", "GQ", "DP"]);
+
+        let samples: Vec<Vec<_>> = genotypes
+            .samples()
+            .map(|sample| sample.values().collect())
+            .collect();
+
+        assert_eq!(
+            samples,
+            [
+                vec![Some("0|0"), Some("13"), None],
+                vec![None],
+                vec![Some("1/1"), Some("8"), Some("5")],
+            ]
+        );
+
+        let sample = genotypes.get(2).expect("missing sample");
+        assert_eq!(sample.get("DP"), Some(Some("5")));
+        assert!(sample.get("AD").is_none());
+
+        assert!(genotypes.get(3).is_none());
+
+        let genotypes = Genotypes::new("");
+        assert!(genotypes.is_empty());
+        assert_eq!(genotypes.keys().count(), 0);
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+
+use crate::record::MISSING_FIELD;
+
+const DELIMITER: char = ';';
+const SEPARATOR: char = '=';
+
+/// Raw VCF record info.
+///
+/// Fields are split on demand, and values are not parsed.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct Info<'a>(&'a str);
+
+impl<'a> Info<'a> {
+    pub(super) fn new(s: &'a str) -> Self {
+        Self(s)
+    }
+
+    /// Returns whether there are any info fields.
+    pub fn is_empty(&self) -> bool {
+        matches!(self.0, "" | MISSING_FIELD)
+    }
+
+    /// Returns the number of info fields.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns the raw value of the field with the given key.
+    ///
+    /// This returns `None` if the key is not present and `Some(None)` if the field has no value,
+    /// i.e., it is a flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = b"sq0\t1\t.\tA\t.\t.\tPASS\tNS=2;DB\n";
+    /// let mut reader = vcf::Reader::new(&data[..]);
+    ///
+    /// let mut record = vcf::lazy::Record::default();
+    /// reader.read_lazy_record(&mut record)?;
+    ///
+    /// let info = record.info();
+    /// assert_eq!(info.get("NS"), Some(Some("2")));
+    /// assert_eq!(info.get("DB"), Some(None));
+    /// assert!(info.get("DP").is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn get(&self, key: &str) -> Option<Option<&'a str>> {
+        self.iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    /// Returns an iterator over the keys.
+    pub fn keys(&self) -> impl Iterator<Item = &'a str> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the raw key-value pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, Option<&'a str>)> {
+        let src = if self.is_empty() { "" } else { self.0 };
+
+        src.split(DELIMITER)
+            .filter(|s| !s.is_empty())
+            .map(|s| match s.split_once(SEPARATOR) {
+                Some((key, value)) => (key, Some(value)),
+                None => (s, None),
+            })
+    }
+}
+
+impl<'a> AsRef<str> for Info<'a> {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl<'a> fmt::Debug for Info<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iter() {
+        let info = Info::new("NS=2;DB;AF=0.333,0.667");
+
+        assert_eq!(
+            info.iter().collect::<Vec<_>>(),
+            [("NS", Some("2")), ("DB", None), ("AF", Some("0.333,0.667"))]
+        );
+
+        assert_eq!(info.len(), 3);
+        assert_eq!(info.keys().collect::<Vec<_>>(), ["NS", "DB", "AF"]);
+
+        let info = Info::new(".");
+        assert!(info.is_empty());
+        assert_eq!(info.len(), 0);
+    }
+}
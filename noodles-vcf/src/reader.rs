@@ -13,7 +13,7 @@ use noodles_core::Region;
 use noodles_csi::BinningIndex;
 use noodles_tabix as tabix;
 
-use super::Header;
+use super::{lazy, Header};
 
 /// A VCF reader.
 ///
@@ -180,6 +180,41 @@ where
         read_line(&mut self.inner, buf)
     }
 
+    /// Reads a single record without eagerly parsing its fields.
+    ///
+    /// This reads VCF fields from the underlying stream into the given record's buffer until a
+    /// newline is reached. No fields are parsed, meaning the record is not necessarily valid.
+    /// However, the structure of the line is guaranteed to be record-like.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
+    /// stream reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::Reader::new(&data[..]);
+    /// reader.read_header()?;
+    ///
+    /// let mut record = vcf::lazy::Record::default();
+    /// reader.read_lazy_record(&mut record)?;
+    ///
+    /// assert_eq!(usize::from(record.position()?), 1);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_lazy_record(&mut self, record: &mut lazy::Record) -> io::Result<usize> {
+        read_lazy_record(&mut self.inner, record)
+    }
+
     /// Returns an iterator over records starting from the current stream position.
     ///
     /// The stream is expected to be directly after the header or at the start of another record.
@@ -307,6 +342,54 @@ where
     }
 }
 
+impl<R> Reader<bgzf::MultithreadedReader<R>>
+where
+    R: Read,
+{
+    /// Returns the current virtual position of the underlying multithreaded BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = Vec::new();
+    /// let reader = vcf::Reader::new(bgzf::MultithreadedReader::new(&data[..]));
+    /// assert_eq!(reader.virtual_position(), bgzf::VirtualPosition::default());
+    /// ```
+    pub fn virtual_position(&self) -> bgzf::VirtualPosition {
+        self.inner.virtual_position()
+    }
+}
+
+impl<R> Reader<bgzf::MultithreadedReader<R>>
+where
+    R: Read + Seek,
+{
+    /// Seeks the underlying multithreaded BGZF reader to the given virtual position.
+    ///
+    /// Virtual positions typically come from an associated index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = Cursor::new(Vec::new());
+    /// let mut reader = vcf::Reader::new(bgzf::MultithreadedReader::new(data));
+    ///
+    /// let virtual_position = bgzf::VirtualPosition::default();
+    /// reader.seek(virtual_position)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn seek(&mut self, pos: bgzf::VirtualPosition) -> io::Result<bgzf::VirtualPosition> {
+        self.inner.seek(pos)
+    }
+}
+
 fn read_header<R>(reader: &mut R) -> io::Result<String>
 where
     R: BufRead,
@@ -367,6 +450,106 @@ where
     }
 }
 
+fn read_lazy_record<R>(reader: &mut R, record: &mut lazy::Record) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    use std::mem;
+
+    const DELIMITER: u8 = b'\t';
+    // CHROM, POS, ID, REF, ALT, QUAL, FILTER, INFO
+    const FIELD_COUNT: usize = 8;
+
+    let mut buf = mem::take(&mut record.buf).into_bytes();
+    buf.clear();
+
+    let len = read_raw_line(reader, &mut buf)?;
+
+    if len == 0 {
+        *record = lazy::Record::default();
+        return Ok(0);
+    }
+
+    // The field delimiters are dropped, so each field end is also the start of the next field.
+    // Delimiters after the info field (i.e., between samples) are kept.
+    let mut ends = [0; FIELD_COUNT];
+    let mut field_count = 0;
+    let mut dst = 0;
+
+    for src in 0..buf.len() {
+        if buf[src] == DELIMITER && field_count < FIELD_COUNT {
+            ends[field_count] = dst;
+            field_count += 1;
+        } else {
+            buf[dst] = buf[src];
+            dst += 1;
+        }
+    }
+
+    buf.truncate(dst);
+
+    match field_count {
+        // The info field is the last field when there are no genotypes.
+        n if n == FIELD_COUNT - 1 => ends[n] = dst,
+        FIELD_COUNT => {}
+        _ => {
+            *record = lazy::Record::default();
+
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid record: missing fields",
+            ));
+        }
+    }
+
+    // Bounds are only set once the buffer is known to be valid UTF-8. Otherwise, they could
+    // index out of range or into the middle of a character.
+    record.buf = match String::from_utf8(buf) {
+        Ok(s) => s,
+        Err(e) => {
+            *record = lazy::Record::default();
+            return Err(io::Error::new(io::ErrorKind::InvalidData, e));
+        }
+    };
+
+    record.bounds.chromosome_end = ends[0];
+    record.bounds.position_end = ends[1];
+    record.bounds.ids_end = ends[2];
+    record.bounds.reference_bases_end = ends[3];
+    record.bounds.alternate_bases_end = ends[4];
+    record.bounds.quality_score_end = ends[5];
+    record.bounds.filters_end = ends[6];
+    record.bounds.info_end = ends[7];
+
+    Ok(len)
+}
+
+// Reads all bytes until a line feed ('\n') or EOF is reached.
+//
+// The buffer will not include the trailing newline ('\n' or '\r\n').
+fn read_raw_line<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    const LINE_FEED: u8 = b'\n';
+    const CARRIAGE_RETURN: u8 = b'\r';
+
+    match reader.read_until(LINE_FEED, buf)? {
+        0 => Ok(0),
+        n => {
+            if buf.ends_with(&[LINE_FEED]) {
+                buf.pop();
+
+                if buf.ends_with(&[CARRIAGE_RETURN]) {
+                    buf.pop();
+                }
+            }
+
+            Ok(n)
+        }
+    }
+}
+
 pub(crate) fn resolve_region(index: &tabix::Index, region: &Region) -> io::Result<(usize, String)> {
     let i = index
         .header()
@@ -477,6 +660,65 @@ sq0\t13
         Ok(())
     }
 
+    #[test]
+    fn test_read_lazy_record() -> io::Result<()> {
+        let data = b"sq0\t1\t.\tA\t.\t.\tPASS\tNS=2\tGT:GQ\t0|0:13\t0/1:.\r
+sq0\t8\tr0\tC\tG\t5\t.\t.
+sq0
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let mut record = lazy::Record::default();
+
+        let bytes_read = reader.read_lazy_record(&mut record)?;
+        assert_eq!(bytes_read, 44);
+        assert_eq!(usize::from(record.position()?), 1);
+        assert_eq!(record.info().get("NS"), Some(Some("2")));
+        assert_eq!(record.genotypes().as_ref(), "GT:GQ\t0|0:13\t0/1:.");
+
+        let bytes_read = reader.read_lazy_record(&mut record)?;
+        assert_eq!(bytes_read, 19);
+        assert_eq!(usize::from(record.position()?), 8);
+        assert_eq!(record.quality_score()?.map(f32::from), Some(5.0));
+        assert!(record.info().is_empty());
+        assert!(record.genotypes().is_empty());
+
+        assert!(matches!(
+            reader.read_lazy_record(&mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert_eq!(reader.read_lazy_record(&mut record)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_lazy_record_with_invalid_utf8() -> io::Result<()> {
+        let data = b"sq0\t1\t.\tA\t.\t.\tPASS\tNS=2
+sq0\t8\t.\tC\t.\t.\tPASS\tNS=\xff
+sq0\t13\t.\tG\t.\t.\tPASS\t.
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let mut record = lazy::Record::default();
+
+        reader.read_lazy_record(&mut record)?;
+        assert_eq!(usize::from(record.position()?), 1);
+
+        assert!(matches!(
+            reader.read_lazy_record(&mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert_eq!(record, lazy::Record::default());
+
+        reader.read_lazy_record(&mut record)?;
+        assert_eq!(usize::from(record.position()?), 13);
+        assert!(record.info().is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();